@@ -0,0 +1,83 @@
+//! Compares loading a large catalog from JSON with loading it from an archive, and with
+//! looking its messages up in place.
+//!
+//! Run with `cargo bench --features archive,json --bench archive`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use binja::{ArchivedCatalog, Translator};
+
+const KEYS: usize = 20_000;
+const ITERATIONS: u32 = 5;
+
+fn translator() -> Translator {
+    let mut tr = Translator::new(["en", "pt", "it"]);
+
+    for index in 0..KEYS {
+        tr.add_text(
+            format!("key-{index}"),
+            ["NAME", "PLACE", "COUNT"],
+            [
+                (
+                    "en",
+                    format!("Message {index}: NAME went to PLACE COUNT times"),
+                ),
+                (
+                    "pt",
+                    format!("Mensagem {index}: NAME foi a PLACE COUNT vezes"),
+                ),
+                (
+                    "it",
+                    format!("Messaggio {index}: NAME è andato a PLACE COUNT volte"),
+                ),
+            ],
+        )
+        .unwrap();
+    }
+
+    tr
+}
+
+fn measure(label: &str, mut f: impl FnMut()) -> Duration {
+    let mut elapsed = Duration::ZERO;
+
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        f();
+        elapsed += start.elapsed();
+    }
+
+    println!("{label:<24} {:>10.1?}/load", elapsed / ITERATIONS);
+
+    elapsed
+}
+
+fn main() {
+    let tr = translator();
+    let json = tr.to_json();
+    let archive = tr.to_archive();
+    println!(
+        "json: {} bytes, archive: {} bytes",
+        json.len(),
+        archive.len()
+    );
+
+    let from_json = measure("from_json", || {
+        black_box(Translator::from_json(&json).unwrap());
+    });
+
+    let from_archive = measure("from_archive", || {
+        black_box(Translator::from_archive(&archive).unwrap());
+    });
+
+    measure("ArchivedCatalog lookup", || {
+        let catalog = ArchivedCatalog::new(&archive).unwrap();
+        black_box(catalog.message("key-12345", "pt").unwrap());
+    });
+
+    println!(
+        "speedup: {:.2}x",
+        from_json.as_secs_f64() / from_archive.as_secs_f64()
+    );
+}
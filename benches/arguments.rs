@@ -0,0 +1,86 @@
+//! Compares finding the arguments of a key with 20 of them by name through a map,
+//! as `translate` does, with scanning every argument for each value given, as it
+//! used to do.
+//!
+//! Run with `cargo bench --bench arguments`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use binja::Translator;
+
+const ARGUMENTS: usize = 20;
+const ITERATIONS: u32 = 100_000;
+
+fn measure(label: &str, mut f: impl FnMut()) -> Duration {
+    // Warm up
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{label:<24} {:>10.1?} total, {:>8.1?}/iter",
+        elapsed,
+        elapsed / ITERATIONS
+    );
+
+    elapsed
+}
+
+fn main() {
+    let names: Vec<String> = (0..ARGUMENTS)
+        .map(|index| format!("ARG_{index:02}"))
+        .collect();
+    let message: String = names.iter().map(|name| format!("{name} ")).collect();
+
+    let mut tr = Translator::new(["en"]);
+    tr.add_text(
+        "many",
+        names.iter().map(String::as_str),
+        [("en", message.as_str())],
+    )
+    .unwrap();
+
+    // Given in reverse, the worst case for a linear scan
+    let args: Vec<(&str, &str)> = names
+        .iter()
+        .rev()
+        .map(|name| (name.as_str(), "x"))
+        .collect();
+
+    measure("translate", || {
+        black_box(
+            tr.translate_cow(black_box("many"), black_box("en"), args.iter().copied())
+                .unwrap(),
+        );
+    });
+
+    let by_name: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (name.as_str(), index))
+        .collect();
+    let mapped = measure("map lookup", || {
+        for (name, _) in &args {
+            black_box(by_name[black_box(name)]);
+        }
+    });
+
+    let scanned = measure("linear scan", || {
+        for (name, _) in &args {
+            black_box(names.iter().position(|known| known == black_box(name)));
+        }
+    });
+
+    println!(
+        "speedup: {:.2}x",
+        scanned.as_secs_f64() / mapped.as_secs_f64()
+    );
+}
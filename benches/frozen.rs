@@ -0,0 +1,99 @@
+//! Compares key lookups in a `Translator` against its `FrozenTranslator`.
+//!
+//! Run with `cargo bench --bench frozen`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use binja::Translator;
+
+const KEYS: usize = 1_000;
+const ITERATIONS: u32 = 100;
+
+fn measure(label: &str, mut f: impl FnMut()) -> Duration {
+    // Warm up
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{label:<24} {:>10.1?} total, {:>8.1?}/iter",
+        elapsed,
+        elapsed / ITERATIONS
+    );
+
+    elapsed
+}
+
+fn build() -> Translator {
+    let mut tr = Translator::new(["pt", "en"]);
+    for index in 0..KEYS {
+        tr.add_text(
+            format!("key-{index}"),
+            ["NAME"],
+            [
+                ("en", format!("Message {index} for NAME")),
+                ("pt", format!("Mensagem {index} para NAME")),
+            ],
+        )
+        .unwrap();
+    }
+    tr
+}
+
+fn main() {
+    let tr = build();
+    let frozen = build().freeze();
+    let keys: Vec<_> = (0..KEYS).map(|index| format!("key-{index}")).collect();
+
+    println!("lookups:");
+    let mutable = measure("translator", || {
+        for key in &keys {
+            black_box(tr.contains_key(black_box(key)));
+        }
+    });
+    let immutable = measure("frozen translator", || {
+        for key in &keys {
+            black_box(frozen.contains_key(black_box(key)));
+        }
+    });
+    println!(
+        "speedup: {:.2}x",
+        mutable.as_secs_f64() / immutable.as_secs_f64()
+    );
+
+    // Without arguments, messages are borrowed as is, so looking keys up is most of the work
+    for args in [&[][..], &[("NAME", "Julian")][..]] {
+        println!("{} arguments:", args.len());
+
+        let mutable = measure("translator", || {
+            for key in &keys {
+                black_box(
+                    tr.translate_cow(black_box(key), black_box("pt"), args.iter().copied())
+                        .unwrap(),
+                );
+            }
+        });
+
+        let frozen = measure("frozen translator", || {
+            for key in &keys {
+                black_box(
+                    frozen
+                        .translate_cow(black_box(key), black_box("pt"), args.iter().copied())
+                        .unwrap(),
+                );
+            }
+        });
+
+        println!(
+            "speedup: {:.2}x",
+            mutable.as_secs_f64() / frozen.as_secs_f64()
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Compares building a large catalog serially with building it on every thread.
+//!
+//! Run with `cargo bench --features rayon --bench load`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use binja::{Entry, Translator};
+
+const KEYS: usize = 20_000;
+const ITERATIONS: u32 = 5;
+
+fn entries() -> Vec<Entry<String, &'static str, String>> {
+    (0..KEYS)
+        .map(|index| {
+            (
+                format!("key-{index}"),
+                vec!["NAME", "PLACE", "COUNT"],
+                vec![
+                    (
+                        "en",
+                        format!("Message {index}: NAME went to PLACE COUNT times"),
+                    ),
+                    (
+                        "pt",
+                        format!("Mensagem {index}: NAME foi a PLACE COUNT vezes"),
+                    ),
+                    (
+                        "it",
+                        format!("Messaggio {index}: NAME è andato a PLACE COUNT volte"),
+                    ),
+                ],
+            )
+        })
+        .collect()
+}
+
+fn measure(label: &str, mut f: impl FnMut()) -> Duration {
+    let mut elapsed = Duration::ZERO;
+
+    for _ in 0..ITERATIONS {
+        let start = Instant::now();
+        f();
+        elapsed += start.elapsed();
+    }
+
+    println!("{label:<24} {:>10.1?}/build", elapsed / ITERATIONS);
+
+    elapsed
+}
+
+fn main() {
+    let languages = ["en", "pt", "it"];
+
+    let serial = measure("serial", || {
+        black_box(Translator::from_entries(languages, entries()).unwrap());
+    });
+
+    let parallel = measure("parallel", || {
+        black_box(Translator::from_entries_par(languages, entries()).unwrap());
+    });
+
+    println!(
+        "speedup: {:.2}x",
+        serial.as_secs_f64() / parallel.as_secs_f64()
+    );
+}
@@ -0,0 +1,74 @@
+//! Compares repeated translations against the cached automaton with
+//! rebuilding an `AhoCorasick` on every call, as `translate` used to do.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aho_corasick::AhoCorasick;
+use binja::Translator;
+
+const ITERATIONS: u32 = 100_000;
+
+fn measure(label: &str, mut f: impl FnMut()) -> Duration {
+    // Warm up
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{label:<24} {:>10.1?} total, {:>8.1?}/iter",
+        elapsed,
+        elapsed / ITERATIONS
+    );
+
+    elapsed
+}
+
+fn main() {
+    let mut tr = Translator::new(["pt", "en", "it"]);
+    tr.add_text(
+        "greetings",
+        ["NAME", "PLACE"],
+        [
+            ("en", "Good morning, NAME! Welcome to PLACE."),
+            ("pt", "Bom dia, NAME! Bem-vindo a PLACE."),
+            ("it", "Buongiorno, NAME! Benvenuto a PLACE."),
+        ],
+    )
+    .unwrap();
+
+    let cached = measure("cached automaton", || {
+        black_box(
+            tr.translate(
+                black_box("greetings"),
+                black_box("pt"),
+                [("NAME", "Julian"), ("PLACE", "Lisboa")],
+            )
+            .unwrap(),
+        );
+    });
+
+    let rebuilt = measure("rebuilt automaton", || {
+        let ac = AhoCorasick::new(black_box(["NAME", "PLACE"])).unwrap();
+        black_box(
+            ac.try_replace_all(
+                black_box("Bom dia, NAME! Bem-vindo a PLACE."),
+                &["Julian", "Lisboa"],
+            )
+            .unwrap(),
+        );
+    });
+
+    println!(
+        "speedup: {:.2}x",
+        rebuilt.as_secs_f64() / cached.as_secs_f64()
+    );
+}
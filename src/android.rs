@@ -0,0 +1,196 @@
+//! Loading of Android `strings.xml` resources.
+
+use std::io::BufRead;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::printf::{format_specifiers, number_specifiers};
+use crate::xml::{attribute, decode_entities};
+use crate::{Error, Translator};
+
+impl Translator {
+    /// Reads the `<string name="key">message</string>` entries of an Android `strings.xml`
+    /// resource holding messages for `language`.
+    ///
+    /// Messages are unescaped as Android does, e.g. `\'` becomes `'` and `\n` a newline.
+    /// Format specifiers become arguments spelled in full, such as `%1$s`, where the
+    /// unnumbered ones like `%s` are numbered by their position first. Other resources,
+    /// such as `<plurals>`, and strings marked `translatable="false"` are skipped.
+    ///
+    /// Just like [`Translator::load_po`], keys are only registered once every known
+    /// language has been loaded.
+    pub fn load_android_xml<R: BufRead>(
+        &mut self,
+        language: &str,
+        mut reader: R,
+    ) -> Result<(), Error> {
+        let language_id = self.find_language(language)?;
+
+        let mut xml = String::new();
+        reader
+            .read_to_string(&mut xml)
+            .map_err(|err| Error::Io(err.to_string()))?;
+
+        let entries = parse(&xml)?;
+
+        self.load_catalog(
+            |translator| &mut translator.android_catalog,
+            language_id,
+            entries,
+            format_specifiers,
+        )
+    }
+}
+
+/// Reads the translatable `<string>` entries of `xml`.
+fn parse(xml: &str) -> Result<Vec<(SmallStr, SmallStr)>, Error> {
+    let mut entries = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            let end = comment
+                .find("-->")
+                .ok_or_else(|| Error::Parse("unterminated comment".into()))?;
+            rest = &comment[end + "-->".len()..];
+            continue;
+        }
+
+        let tag_end = rest
+            .find('>')
+            .ok_or_else(|| Error::Parse("unterminated tag".into()))?;
+        let tag = &rest[1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let Some(attributes) = tag.strip_prefix("string").filter(|attributes| {
+            attributes.starts_with(char::is_whitespace) && !attributes.ends_with('/')
+        }) else {
+            continue;
+        };
+
+        let content_end = rest
+            .find("</string>")
+            .ok_or_else(|| Error::Parse(format!("unterminated <{tag}>")))?;
+        let content = &rest[..content_end];
+        rest = &rest[content_end + "</string>".len()..];
+
+        if attribute(attributes, "translatable") == Some("false") {
+            continue;
+        }
+        let name = attribute(attributes, "name")
+            .ok_or_else(|| Error::Parse(format!("<{tag}> has no name")))?;
+
+        entries.push((
+            decode_entities(name)?,
+            number_specifiers(&unescape(content)?),
+        ));
+    }
+
+    Ok(entries)
+}
+
+/// Resolves the entities and Android escape sequences of a `<string>`'s content.
+///
+/// Whitespace runs collapse into a single space, except within double quotes, which
+/// are themselves dropped.
+fn unescape(content: &str) -> Result<SmallStr, Error> {
+    let content = match content.trim().strip_prefix("<![CDATA[") {
+        Some(data) => data.strip_suffix("]]>").unwrap_or(data).into(),
+        None => decode_entities(content.trim())?,
+    };
+
+    let mut unescaped = SmallStr::with_capacity(content.len());
+    let mut chars = content.chars();
+    let mut quoted = false;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                let escaped = chars
+                    .next()
+                    .ok_or_else(|| Error::Parse(format!("dangling escape in `{content}`")))?;
+                unescaped.push(match escaped {
+                    'n' => '\n',
+                    't' => '\t',
+                    other => other,
+                });
+            }
+            '"' => quoted = !quoted,
+            ch if ch.is_whitespace() && !quoted => {
+                if !unescaped.ends_with(' ') {
+                    unescaped.push(' ');
+                }
+            }
+            ch => unescaped.push(ch),
+        }
+    }
+
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    const EN: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<resources>
+    <!-- Shown on the home screen -->
+    <string name="app_name" translatable="false">Binja</string>
+    <string name="greetings">Good morning, %1$s!</string>
+    <string name="unread">%1$s, you have %2$d unread messages</string>
+    <string name="quote">Don\'t say \"%s\"\nto &lt;them&gt;</string>
+    <plurals name="files">
+        <item quantity="one">%d file</item>
+    </plurals>
+</resources>
+"#;
+
+    const PT: &str = r#"<resources>
+    <string name="greetings">Bom dia, %1$s!</string>
+    <string name="unread">"%1$s,  tens %2$d mensagens por ler"</string>
+    <string name="quote">Não digas \"%s\"\na &lt;eles&gt;</string>
+</resources>
+"#;
+
+    #[test]
+    fn load_android_xml() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.load_android_xml("en", EN.as_bytes())?;
+        assert!(!tr.contains_key("greetings"));
+        tr.load_android_xml("pt", PT.as_bytes())?;
+
+        assert!(!tr.contains_key("app_name"));
+        assert!(!tr.contains_key("files"));
+        assert_eq!(
+            tr.translate("greetings", "pt", [("%1$s", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(
+            tr.translate("unread", "en", [("%1$s", "Julian"), ("%2$d", "3")])?,
+            "Julian, you have 3 unread messages"
+        );
+        assert_eq!(
+            tr.translate("unread", "pt", [("%1$s", "Julian"), ("%2$d", "3")])?,
+            "Julian,  tens 3 mensagens por ler"
+        );
+        assert_eq!(
+            tr.translate("quote", "en", [("%1$s", "hi")])?,
+            "Don't say \"hi\"\nto <them>"
+        );
+
+        let mut tr = Translator::new(["en"]);
+        assert!(matches!(
+            tr.load_android_xml("en", "<string name=\"a\">unterminated".as_bytes()),
+            Err(Error::Parse(_))
+        ));
+        assert_eq!(
+            tr.load_android_xml("cz", EN.as_bytes()),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+//! Loading of Apple `.strings` files.
+
+use std::io::BufRead;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::printf::{format_specifiers, number_specifiers};
+use crate::{Error, Translator};
+
+impl Translator {
+    /// Reads the `"key" = "message";` entries of an Apple `.strings` file, such as
+    /// `Localizable.strings`, holding messages for `language`.
+    ///
+    /// `//` and `/* */` comments are skipped. Format specifiers become arguments spelled
+    /// in full, such as `%1$@`, where the unnumbered ones like `%@` are numbered by their
+    /// position first.
+    ///
+    /// Just like [`Translator::load_po`], keys are only registered once every known
+    /// language has been loaded.
+    pub fn load_apple_strings<R: BufRead>(
+        &mut self,
+        language: &str,
+        mut reader: R,
+    ) -> Result<(), Error> {
+        let language_id = self.find_language(language)?;
+
+        let mut strings = String::new();
+        reader
+            .read_to_string(&mut strings)
+            .map_err(|err| Error::Io(err.to_string()))?;
+
+        let entries = parse(&strings)?;
+
+        self.load_catalog(
+            |translator| &mut translator.apple_catalog,
+            language_id,
+            entries,
+            format_specifiers,
+        )
+    }
+}
+
+/// Reads `.strings` entries, keeping track of the line being read for errors.
+struct Parser<'a> {
+    rest: &'a str,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error::Parse(format!("line {}: {message}", self.line))
+    }
+
+    fn advance(&mut self, len: usize) {
+        self.line += self.rest[..len].matches('\n').count();
+        self.rest = &self.rest[len..];
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_blank(&mut self) -> Result<(), Error> {
+        loop {
+            let blank = self.rest.len() - self.rest.trim_start().len();
+            self.advance(blank);
+
+            if self.rest.starts_with("//") {
+                let end = self.rest.find('\n').unwrap_or(self.rest.len());
+                self.advance(end);
+            } else if self.rest.starts_with("/*") {
+                let end = self
+                    .rest
+                    .find("*/")
+                    .ok_or_else(|| self.error("unterminated comment"))?;
+                self.advance(end + "*/".len());
+            } else {
+                return Ok(());
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        self.skip_blank()?;
+
+        match self.rest.starts_with(expected) {
+            true => {
+                self.advance(expected.len_utf8());
+                Ok(())
+            }
+            false => Err(self.error(&format!("expected `{expected}`"))),
+        }
+    }
+
+    /// Reads a quoted string, resolving its escape sequences.
+    fn string(&mut self) -> Result<SmallStr, Error> {
+        self.expect('"')?;
+
+        let mut string = SmallStr::default();
+        let mut chars = self.rest.char_indices();
+
+        while let Some((index, ch)) = chars.next() {
+            let ch = match ch {
+                '"' => {
+                    self.advance(index + 1);
+                    return Ok(string);
+                }
+                '\\' => match chars.next().map(|(_, escaped)| escaped) {
+                    Some('n') => '\n',
+                    Some('t') => '\t',
+                    Some('r') => '\r',
+                    Some('U' | 'u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, ch)| ch).collect();
+                        u32::from_str_radix(&hex, 16)
+                            .ok()
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| self.error(&format!("invalid escape `\\U{hex}`")))?
+                    }
+                    Some(escaped) => escaped,
+                    None => break,
+                },
+                ch => ch,
+            };
+            string.push(ch);
+        }
+
+        Err(self.error("unterminated string"))
+    }
+}
+
+/// Reads the entries of a `.strings` file.
+fn parse(strings: &str) -> Result<Vec<(SmallStr, SmallStr)>, Error> {
+    let mut parser = Parser {
+        rest: strings,
+        line: 1,
+    };
+    let mut entries = Vec::new();
+
+    parser.skip_blank()?;
+    while !parser.rest.is_empty() {
+        let key = parser.string()?;
+        parser.expect('=')?;
+        let message = parser.string()?;
+        parser.expect(';')?;
+
+        entries.push((key, number_specifiers(&message)));
+        parser.skip_blank()?;
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    const EN: &str = r#"/* Shown on the home screen */
+"greetings" = "Good morning, %@!";
+// Quotes inside of a message
+"quote" = "She said \"hi\"";
+"unread" = "%1$@, you have %2$d unread messages";
+"#;
+
+    const PT: &str = r#"
+"greetings" = "Bom dia, %@!"; // Trailing comment
+"quote" = "Ela disse \"olá\"";
+/* Multi-line
+   comment */
+"unread" =
+    "%1$@, tens %2$d mensagens por ler";
+"#;
+
+    #[test]
+    fn load_apple_strings() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.load_apple_strings("en", EN.as_bytes())?;
+        assert!(!tr.contains_key("greetings"));
+        tr.load_apple_strings("pt", PT.as_bytes())?;
+
+        assert_eq!(
+            tr.translate("greetings", "pt", [("%1$@", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(
+            tr.translate("quote", "en", [("", ""); 0])?,
+            "She said \"hi\""
+        );
+        assert_eq!(
+            tr.translate("unread", "pt", [("%1$@", "Julian"), ("%2$d", "3")])?,
+            "Julian, tens 3 mensagens por ler"
+        );
+
+        let mut tr = Translator::new(["en"]);
+        assert_eq!(
+            tr.load_apple_strings("en", "\"a\" = \"b\"\n\"c\" = \"d\";".as_bytes()),
+            Err(Error::Parse("line 2: expected `;`".into()))
+        );
+        assert_eq!(
+            tr.load_apple_strings("en", "\"a\" = \"b".as_bytes()),
+            Err(Error::Parse("line 1: unterminated string".into()))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Loading of Flutter `.arb` files.
+
+use compact_str::{format_compact, CompactString as SmallStr};
+
+use serde_json::Value;
+
+use crate::json::{as_object, as_str, parse};
+use crate::placeholder::placeholders;
+use crate::{Error, Translator};
+
+impl Translator {
+    /// Reads the messages of a Flutter `.arb` document holding messages for `language`.
+    ///
+    /// Every `"key": "message"` entry becomes a key, while entries starting with `@`
+    /// hold metadata and are not translated. ICU placeholders such as `{name}` become
+    /// arguments spelled in full (i.e. `{name}`), along with every placeholder declared
+    /// in the `placeholders` of a key's metadata. Other ICU constructs, such as
+    /// `{count, plural, ...}`, are kept as-is.
+    ///
+    /// Just like [`Translator::load_po`], keys are only registered once every known
+    /// language has been loaded.
+    pub fn load_arb(&mut self, language: &str, json: &str) -> Result<(), Error> {
+        let language_id = self.find_language(language)?;
+
+        let document = parse(json)?;
+        let entries = as_object(&document, "the document")?;
+
+        let mut messages = Vec::new();
+        for (key, value) in entries {
+            match key.strip_prefix('@') {
+                Some(key) => {
+                    let declared = declared_placeholders(key, value)?;
+                    let arguments = self.arb_catalog.arguments.entry(key.into()).or_default();
+                    arguments.extend(declared);
+                }
+                None => messages.push((key.into(), as_str(value, &format!("key `{key}`"))?.into())),
+            }
+        }
+
+        self.load_catalog(
+            |translator| &mut translator.arb_catalog,
+            language_id,
+            messages,
+            |message| placeholders(message, "{", "}").collect(),
+        )
+    }
+}
+
+/// The placeholders declared in the `metadata` of `key`, spelled in full.
+fn declared_placeholders(key: &str, metadata: &Value) -> Result<Vec<SmallStr>, Error> {
+    // `@@locale` and similar document-wide entries hold no placeholders
+    let Some(placeholders) = metadata.get("placeholders") else {
+        return Ok(Vec::new());
+    };
+
+    let placeholders = as_object(placeholders, &format!("the placeholders of `{key}`"))?
+        .keys()
+        .map(|name| format_compact!("{{{name}}}"))
+        .collect();
+
+    Ok(placeholders)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    const EN: &str = r#"{
+        "@@locale": "en",
+        "greetings": "Good morning, {name}!",
+        "@greetings": {
+            "description": "Shown on the home screen",
+            "placeholders": {
+                "name": { "type": "String", "example": "Julian" }
+            }
+        },
+        "bye": "Bye!"
+    }"#;
+
+    const PT: &str = r#"{
+        "@@locale": "pt",
+        "greetings": "Bom dia, {name}!",
+        "bye": "Tchau!"
+    }"#;
+
+    #[test]
+    fn load_arb() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.load_arb("en", EN)?;
+        assert!(!tr.contains_key("greetings"));
+        tr.load_arb("pt", PT)?;
+
+        assert_eq!(
+            tr.arguments_for("greetings")?.collect::<Vec<_>>(),
+            ["{name}"]
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt", [("{name}", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(tr.translate("bye", "en", [("", ""); 0])?, "Bye!");
+
+        let mut tr = Translator::new(["en"]);
+        assert!(matches!(
+            tr.load_arb("en", r#"{ "greetings": 1 }"#),
+            Err(Error::Parse(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+//! A compact binary catalog format, readable in place without deserializing it.
+//!
+//! Every number is a little-endian `u32`, and every string is its length followed by its
+//! UTF-8 bytes. An archive is laid out as
+//!
+//! - the magic bytes `BINJA` followed by the format version, `1`;
+//! - whether delimiters are set, as `0` or `1`, followed by the opening and closing ones;
+//! - the number of languages, followed by each of their codes, in sorted order;
+//! - the number of keys, followed by the offset of each key's entry from the start of the
+//!   archive, in lexicographic order of the keys;
+//! - the entry of each key: the key, the number of its arguments followed by each of them,
+//!   as declared, then the number of its messages followed by the language index and message
+//!   of each.
+
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translator};
+
+const MAGIC: &[u8] = b"BINJA";
+const VERSION: u8 = 1;
+
+fn write_u32(archive: &mut Vec<u8>, value: usize) {
+    let value = u32::try_from(value).expect("archives are limited to 4 GiB");
+    archive.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_str(archive: &mut Vec<u8>, string: &str) {
+    write_u32(archive, string.len());
+    archive.extend_from_slice(string.as_bytes());
+}
+
+/// Reads the numbers and strings of an archive from a given position, borrowing the strings.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn at(bytes: &'a [u8], position: usize) -> Self {
+        Self { bytes, position }
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::Parse(alloc::format!(
+            "invalid archive at byte {}: {message}",
+            self.position
+        ))
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let taken = self
+            .position
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.position..end))
+            .ok_or_else(|| self.error("unexpected end"))?;
+        self.position += len;
+
+        Ok(taken)
+    }
+
+    fn u32(&mut self) -> Result<usize, Error> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+    }
+
+    fn str(&mut self) -> Result<&'a str, Error> {
+        let len = self.u32()?;
+        let bytes = self.take(len)?;
+
+        core::str::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8"))
+    }
+}
+
+/// A catalog written by [`Translator::to_archive`], read in place: keys and messages are
+/// borrowed from the archive as they're looked up, without deserializing the rest of it.
+///
+/// To translate its messages, build a [`Translator`] through [`Translator::from_archive`].
+///
+/// ```
+/// use binja::{ArchivedCatalog, Translator};
+///
+/// let mut tr = Translator::new(["en", "pt"]);
+/// tr.add_text("greetings", ["NAME"], [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")])?;
+/// let archive = tr.to_archive();
+///
+/// let catalog = ArchivedCatalog::new(&archive)?;
+/// assert_eq!(catalog.message("greetings", "pt")?, "Olá, NAME!");
+/// # Ok::<(), binja::Error>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArchivedCatalog<'a> {
+    bytes: &'a [u8],
+    delimiters: Option<(&'a str, &'a str)>,
+    languages: Vec<&'a str>,
+    key_count: usize,
+    /// Where the offsets of the entries start.
+    offsets: usize,
+}
+
+/// The entry of a key, see the [module docs](self).
+struct ArchivedEntry<'a> {
+    key: &'a str,
+    arguments: Vec<&'a str>,
+    messages: Vec<(usize, &'a str)>,
+}
+
+impl<'a> ArchivedCatalog<'a> {
+    /// Reads the header of `bytes`, failing with [`Error::Parse`] if it isn't an archive.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, Error> {
+        let mut reader = Reader::at(bytes, 0);
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(reader.error("not an archive"));
+        }
+        if reader.take(1)? != [VERSION] {
+            return Err(reader.error("unsupported version"));
+        }
+
+        let delimiters = match reader.take(1)? {
+            [0] => None,
+            [1] => Some((reader.str()?, reader.str()?)),
+            _ => return Err(reader.error("invalid delimiters")),
+        };
+
+        let language_count = reader.u32()?;
+        let languages = (0..language_count)
+            .map(|_| reader.str())
+            .collect::<Result<_, _>>()?;
+
+        let key_count = reader.u32()?;
+        let offsets = reader.position;
+        reader.take(
+            key_count
+                .checked_mul(4)
+                .ok_or_else(|| reader.error("too many keys"))?,
+        )?;
+
+        Ok(Self {
+            bytes,
+            delimiters,
+            languages,
+            key_count,
+            offsets,
+        })
+    }
+
+    /// The languages of the catalog, in sorted order.
+    pub fn languages(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.languages.iter().copied()
+    }
+
+    /// The number of keys in the catalog.
+    pub fn len(&self) -> usize {
+        self.key_count
+    }
+
+    /// Whether the catalog has no keys.
+    pub fn is_empty(&self) -> bool {
+        self.key_count == 0
+    }
+
+    /// The message of `key` in `language`, with its placeholders untouched, found by a
+    /// binary search over the keys.
+    pub fn message(&self, key: &str, language: &str) -> Result<&'a str, Error> {
+        let language_id = self
+            .languages
+            .binary_search(&language)
+            .map_err(|_| Error::UnknownLanguage(language.into()))?;
+
+        let mut low = 0;
+        let mut high = self.key_count;
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let mut reader = Reader::at(self.bytes, self.entry_offset(middle)?);
+
+            match reader.str()?.cmp(key) {
+                core::cmp::Ordering::Less => low = middle + 1,
+                core::cmp::Ordering::Greater => high = middle,
+                core::cmp::Ordering::Equal => {
+                    let entry = self.entry_at(middle)?;
+                    return entry
+                        .messages
+                        .iter()
+                        .find(|(id, _)| *id == language_id)
+                        .map(|(_, message)| *message)
+                        .ok_or_else(|| {
+                            Error::UntranslatedForLanguage(key.into(), language.into())
+                        });
+                }
+            }
+        }
+
+        Err(Error::MissingKey(key.into()))
+    }
+
+    fn entry_offset(&self, index: usize) -> Result<usize, Error> {
+        Reader::at(self.bytes, self.offsets + index * 4).u32()
+    }
+
+    fn entry_at(&self, index: usize) -> Result<ArchivedEntry<'a>, Error> {
+        let mut reader = Reader::at(self.bytes, self.entry_offset(index)?);
+
+        let key = reader.str()?;
+        let argument_count = reader.u32()?;
+        let arguments = (0..argument_count)
+            .map(|_| reader.str())
+            .collect::<Result<_, _>>()?;
+
+        let message_count = reader.u32()?;
+        let messages = (0..message_count)
+            .map(|_| {
+                let language_id = reader.u32()?;
+                if language_id >= self.languages.len() {
+                    return Err(reader.error("unknown language"));
+                }
+                Ok((language_id, reader.str()?))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(ArchivedEntry {
+            key,
+            arguments,
+            messages,
+        })
+    }
+}
+
+impl Translator {
+    /// Serializes the languages, delimiters and keys of this translator into the binary
+    /// format read by [`ArchivedCatalog`] and [`Translator::from_archive`].
+    ///
+    /// Just like [`Translator::to_json`], only keys added through [`Translator::add_text`]
+    /// and friends are written, in lexicographic order.
+    pub fn to_archive(&self) -> Vec<u8> {
+        let mut archive = Vec::from(MAGIC);
+        archive.push(VERSION);
+
+        match &self.delimiters {
+            Some((open, close)) => {
+                archive.push(1);
+                write_str(&mut archive, open);
+                write_str(&mut archive, close);
+            }
+            None => archive.push(0),
+        }
+
+        write_u32(&mut archive, self.languages.len());
+        for language in self.languages.iter() {
+            write_str(&mut archive, language);
+        }
+
+        let mut keys: Vec<_> = self.translations.iter().collect();
+        keys.sort_unstable_by_key(|(key, _)| *key);
+
+        write_u32(&mut archive, keys.len());
+        // Filled in once each entry is written
+        let offsets = archive.len();
+        archive.resize(offsets + keys.len() * 4, 0);
+
+        for (index, (key, translation)) in keys.into_iter().enumerate() {
+            let offset = u32::try_from(archive.len()).expect("archives are limited to 4 GiB");
+            archive[offsets + index * 4..][..4].copy_from_slice(&offset.to_le_bytes());
+
+            write_str(&mut archive, key);
+
+            let declared = translation.declared_arguments(&self.argument_table);
+            write_u32(&mut archive, declared.len());
+            for argument in declared {
+                write_str(&mut archive, &argument);
+            }
+
+            let mut messages: Vec<_> = translation.translations.iter().collect();
+            messages.sort_unstable_by_key(|(language_id, _)| **language_id);
+            write_u32(&mut archive, messages.len());
+            for (&language_id, message) in messages {
+                write_u32(&mut archive, language_id);
+                write_str(&mut archive, message);
+            }
+        }
+
+        archive
+    }
+
+    /// Builds a translator from an archive written by [`Translator::to_archive`].
+    ///
+    /// Every key goes through [`Translator::add_text_partial`], so the usual validation
+    /// applies, but no text is parsed or unescaped along the way.
+    pub fn from_archive(bytes: &[u8]) -> Result<Translator, Error> {
+        let catalog = ArchivedCatalog::new(bytes)?;
+
+        let mut translator = Translator::new(catalog.languages());
+        if let Some((open, close)) = catalog.delimiters {
+            translator = translator.with_delimiters(open, close);
+        }
+
+        for index in 0..catalog.len() {
+            let entry = catalog.entry_at(index)?;
+            let messages = entry
+                .messages
+                .into_iter()
+                .map(|(language_id, message)| (catalog.languages[language_id], message));
+
+            translator.add_text_partial(SmallStr::from(entry.key), entry.arguments, messages)?;
+        }
+
+        Ok(translator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArchivedCatalog;
+    use crate::{Error, Translator};
+
+    #[test]
+    fn archive_round_trip() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]).with_delimiters("{", "}");
+        tr.add_text(
+            "greetings",
+            ["name", "count:number", "title?"],
+            [
+                ("en", "Hi, {title}{name}! {count:number} new"),
+                ("pt", "Olá, {title}{name}! {count:number} novas"),
+                ("it", "Ciao, {title}{name}! {count:number} nuove"),
+            ],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!"), ("pt", "Tchau!")])?;
+
+        let archive = tr.to_archive();
+        let loaded = Translator::from_archive(&archive)?;
+
+        assert_eq!(loaded.to_archive(), archive);
+        assert_eq!(
+            loaded.translate("greetings", "pt", [("name", "Julian"), ("count", "1200")])?,
+            "Olá, Julian! 1.200 novas"
+        );
+        assert_eq!(
+            loaded.translate("greetings", "en", [("name", "Julian"), ("title", "Dr. ")])?,
+            "Hi, Dr. Julian! {count:number} new"
+        );
+        assert_eq!(
+            loaded.translate("bye", "it", [("", ""); 0]),
+            Err(Error::UntranslatedForLanguage("bye".into(), "it".into()))
+        );
+
+        let catalog = ArchivedCatalog::new(&archive)?;
+        assert_eq!(catalog.len(), 2);
+        assert_eq!(catalog.languages().collect::<Vec<_>>(), ["en", "it", "pt"]);
+        assert_eq!(catalog.message("bye", "pt")?, "Tchau!");
+        assert_eq!(
+            catalog.message("greetings", "it")?,
+            "Ciao, {title}{name}! {count:number} nuove"
+        );
+        assert_eq!(
+            catalog.message("bye", "it"),
+            Err(Error::UntranslatedForLanguage("bye".into(), "it".into()))
+        );
+        assert_eq!(
+            catalog.message("farewell", "en"),
+            Err(Error::MissingKey("farewell".into()))
+        );
+        assert_eq!(
+            catalog.message("bye", "cz"),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_archives() {
+        assert!(matches!(
+            ArchivedCatalog::new(b"{\"languages\": []}"),
+            Err(Error::Parse(_))
+        ));
+
+        let mut tr = Translator::new(["en"]);
+        tr.add_text("bye", [""; 0], [("en", "Bye!")]).unwrap();
+        let archive = tr.to_archive();
+
+        for len in 0..archive.len() {
+            assert!(matches!(
+                Translator::from_archive(&archive[..len]),
+                Err(Error::Parse(_))
+            ));
+        }
+    }
+}
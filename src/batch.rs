@@ -0,0 +1,124 @@
+//! Translating many keys into the same language at once.
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::pseudo::Pseudo;
+use crate::{Error, LanguageId, Translator};
+
+impl Translator {
+    /// Translates every `(key, args)` of `requests` into `language`, in order.
+    ///
+    /// `language` is only looked up once for the whole batch. The first request that
+    /// fails stops the batch with [`Error::BatchFailed`], naming its key.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_text("greetings", ["NAME"], [("en", "Hi, NAME!")])?;
+    /// tr.add_text("bye", [""; 0], [("en", "Bye!")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate_batch("en", &[("greetings", &[("NAME", "Ana")]), ("bye", &[])])?,
+    ///     ["Hi, Ana!", "Bye!"]
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn translate_batch(
+        &self,
+        language: &str,
+        requests: &[(&str, &[(&str, &str)])],
+    ) -> Result<Vec<String>, Error> {
+        let (language_id, pseudo) = self.find_target(language)?;
+
+        requests
+            .iter()
+            .map(|&(key, args)| {
+                self.translate_for_id(key, language, language_id, pseudo, args)
+                    .map_err(|err| Error::BatchFailed(key.into(), Box::new(err)))
+            })
+            .collect()
+    }
+
+    /// Like [`Translator::translate`], with `language` already resolved to `language_id`
+    /// and how the text of its messages is altered.
+    fn translate_for_id(
+        &self,
+        key: &str,
+        language: &str,
+        language_id: LanguageId,
+        pseudo: Pseudo,
+        args: &[(&str, &str)],
+    ) -> Result<String, Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let message = self
+            .resolve_message(translation, language_id)
+            .ok_or_else(|| Error::UntranslatedForLanguage(key.into(), language.into()))?;
+
+        if self.has_references(message) {
+            let mut output = String::new();
+            self.translate_with_references(&mut output, key, language, args.iter().copied())?;
+            return Ok(output);
+        }
+
+        let format = self.value_format(language_id, pseudo);
+        translation
+            .replace(&self.argument_table, format, message, args.iter().copied())
+            .map(Cow::into_owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_batch() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+        tr.add_text(
+            "welcome",
+            ["PLACE"],
+            [("en", "Welcome to PLACE."), ("pt", "Bem-vindo a PLACE.")],
+        )?;
+        tr.add_text("bye", [""; 0], [("en", "Bye!"), ("pt", "Tchau!")])?;
+
+        let requests: [(&str, &[(&str, &str)]); 3] = [
+            ("greetings", &[("NAME", "Julian")]),
+            ("welcome", &[("PLACE", "Lisboa")]),
+            ("bye", &[]),
+        ];
+
+        let individually = requests
+            .iter()
+            .map(|&(key, args)| tr.translate(key, "pt", args.iter().copied()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(tr.translate_batch("pt", &requests)?, individually);
+
+        assert_eq!(
+            tr.translate_batch("pt", &[("bye", &[]), ("welcome", &[("NAME", "Julian")])]),
+            Err(Error::BatchFailed(
+                "welcome".into(),
+                Box::new(Error::UnknownArgument("NAME".into()))
+            ))
+        );
+        assert_eq!(
+            tr.translate_batch("cz", &requests),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+}
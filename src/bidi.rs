@@ -0,0 +1,78 @@
+//! Keeping argument values from reordering the right-to-left messages they are replaced into.
+
+use compact_str::CompactString as SmallStr;
+
+use crate::plural::primary_subtag;
+
+/// Starts a left-to-right isolate.
+const LRI: char = '\u{2066}';
+/// Starts a right-to-left isolate.
+const RLI: char = '\u{2067}';
+/// Starts an isolate whose direction is that of its first strong character.
+const FSI: char = '\u{2068}';
+/// Ends the innermost isolate.
+const PDI: char = '\u{2069}';
+
+/// Whether `language` is written right-to-left, e.g. `ar` or `he-IL`.
+pub(crate) fn is_rtl(language: &str) -> bool {
+    matches!(
+        primary_subtag(language),
+        "ar" | "he" | "fa" | "ur" | "yi" | "ps" | "sd" | "ug" | "dv" | "ckb"
+    )
+}
+
+/// Whether `ch` belongs to one of the right-to-left scripts, i.e. Hebrew, Arabic,
+/// Syriac, Thaana, N'Ko and their presentation forms.
+fn is_rtl_char(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}'
+    )
+}
+
+/// Wraps `value` in the isolate matching the direction of its first letter.
+pub(crate) fn isolate(value: &str) -> SmallStr {
+    let opening = match value.chars().find(|ch| ch.is_alphabetic()) {
+        Some(ch) if is_rtl_char(ch) => RLI,
+        Some(_) => LRI,
+        None => FSI,
+    };
+
+    let mut isolated = SmallStr::with_capacity(value.len() + 6);
+    isolated.push(opening);
+    isolated.push_str(value);
+    isolated.push(PDI);
+
+    isolated
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn isolate_rtl() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "ar"]).isolate_rtl(true);
+        tr.add_text(
+            "greetings",
+            ["NAME", "COUNT"],
+            [("en", "Hello, NAME! COUNT"), ("ar", "مرحبا، NAME! COUNT")],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "ar", [("NAME", "Julian"), ("COUNT", "3")])?,
+            "مرحبا، \u{2066}Julian\u{2069}! \u{2068}3\u{2069}"
+        );
+        assert_eq!(
+            tr.translate("greetings", "ar", [("NAME", "سامي")])?,
+            "مرحبا، \u{2067}سامي\u{2069}! COUNT"
+        );
+        // Left-to-right languages are left alone
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Hello, Julian! COUNT"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+//! Top-to-bottom construction of a [`Translator`].
+
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translator};
+
+/// Collects languages and messages, validating all of them in [`TranslatorBuilder::build`].
+///
+/// ```
+/// use binja::TranslatorBuilder;
+///
+/// let tr = TranslatorBuilder::new()
+///     .languages(["en", "pt"])
+///     .text("greetings", ["NAME"], [("en", "Hello, NAME!"), ("pt", "Olá, NAME!")])
+///     .text("bye", ["NAME"], [("en", "Bye, NAME!"), ("pt", "Tchau, NAME!")])
+///     .build()?;
+///
+/// assert_eq!(tr.translate("bye", "pt", [("NAME", "Julian")])?, "Tchau, Julian!");
+/// # Ok::<(), binja::Error>(())
+/// ```
+#[derive(Default)]
+pub struct TranslatorBuilder {
+    languages: Vec<SmallStr>,
+    texts: Vec<PendingText>,
+}
+
+struct PendingText {
+    key: SmallStr,
+    arguments: Vec<SmallStr>,
+    translations: Vec<(SmallStr, SmallStr)>,
+}
+
+impl TranslatorBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds to the languages of the translator, see [`Translator::new`].
+    pub fn languages<S: Into<SmallStr>, I: IntoIterator<Item = S>>(mut self, languages: I) -> Self {
+        self.languages.extend(languages.into_iter().map(Into::into));
+        self
+    }
+
+    /// Queues a message to be added through [`Translator::add_text`].
+    pub fn text<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, S2)>,
+    >(
+        mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+    ) -> Self {
+        self.texts.push(PendingText {
+            key: key.into(),
+            arguments: arguments.into_iter().map(Into::into).collect(),
+            translations: translations
+                .into_iter()
+                .map(|(language, message)| (language.into(), message.into()))
+                .collect(),
+        });
+        self
+    }
+
+    /// Builds the translator, failing with the first error found among the queued messages.
+    pub fn build(self) -> Result<Translator, Error> {
+        let mut translator = Translator::new(self.languages);
+
+        for PendingText {
+            key,
+            arguments,
+            translations,
+        } in self.texts
+        {
+            translator.add_text(key, arguments, translations)?;
+        }
+
+        Ok(translator)
+    }
+}
+
+impl Translator {
+    /// Shorthand for [`TranslatorBuilder::new`].
+    pub fn builder() -> TranslatorBuilder {
+        TranslatorBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn builder() -> Result<(), Error> {
+        let tr = Translator::builder()
+            .languages(["pt", "en"])
+            .text(
+                "greetings",
+                ["NAME"],
+                [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+            )
+            .text("bye", [""; 0], [("en", "Bye!"), ("pt", "Tchau!")])
+            .build()?;
+
+        assert_eq!(tr.len(), 2);
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(tr.translate("bye", "en", [("", ""); 0])?, "Bye!");
+
+        let result = Translator::builder()
+            .languages(["pt", "en"])
+            .text("greetings", [""; 0], [("en", "Hi!"), ("cz", "Ahoj!")])
+            .text("bye", [""; 0], [("en", "Bye!")])
+            .build();
+        assert_eq!(result.err(), Some(Error::UnknownLanguage("cz".into())));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,190 @@
+//! Changing the case of argument values within messages, as in `{NAME:upper}`.
+
+use compact_str::CompactString as SmallStr;
+
+use crate::plural::primary_subtag;
+
+/// The case an argument value is changed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Case {
+    Upper,
+    Lower,
+    /// The first letter of each word in upper case, keeping the others as given.
+    Title,
+}
+
+/// The suffix of placeholders whose value has its case changed, as in `NAME:upper`.
+pub(crate) const CASE_ANNOTATIONS: [(Case, &str); 3] = [
+    (Case::Upper, ":upper"),
+    (Case::Lower, ":lower"),
+    (Case::Title, ":title"),
+];
+
+/// Whether `language` cases `i` as the dotted `İ` and `I` as the dotless `ı`, e.g. `tr`.
+pub(crate) fn is_turkic(language: &str) -> bool {
+    matches!(primary_subtag(language), "tr" | "az")
+}
+
+fn push_upper(output: &mut SmallStr, ch: char, turkic: bool) {
+    match ch {
+        'i' if turkic => output.push('İ'),
+        _ => output.extend(ch.to_uppercase()),
+    }
+}
+
+fn push_lower(output: &mut SmallStr, ch: char, turkic: bool) {
+    match ch {
+        'I' if turkic => output.push('ı'),
+        'İ' if turkic => output.push('i'),
+        _ => output.extend(ch.to_lowercase()),
+    }
+}
+
+/// `value` changed to `case`, with the Turkic casing of `i` if `turkic`.
+pub(crate) fn change_case(value: &str, case: Case, turkic: bool) -> SmallStr {
+    let mut changed = SmallStr::with_capacity(value.len());
+    let mut word_start = true;
+
+    for ch in value.chars() {
+        match case {
+            Case::Upper => push_upper(&mut changed, ch, turkic),
+            Case::Lower => push_lower(&mut changed, ch, turkic),
+            Case::Title if word_start => push_upper(&mut changed, ch, turkic),
+            Case::Title => changed.push(ch),
+        }
+        word_start = !ch.is_alphanumeric() && ch != '\'';
+    }
+
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, PluralCategory, Translator};
+
+    #[test]
+    fn case_annotations() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "tr"]).with_delimiters("{", "}");
+        tr.add_text(
+            "greetings",
+            ["NAME", "CITY?"],
+            [
+                (
+                    "en",
+                    "{NAME:upper}! {NAME:title}, {NAME:lower} or {NAME}{CITY:title}",
+                ),
+                ("tr", "{NAME:upper} {NAME:lower} {NAME:title}"),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "o'brien mcIntyre")])?,
+            "O'BRIEN MCINTYRE! O'brien McIntyre, o'brien mcintyre or o'brien mcIntyre"
+        );
+        assert_eq!(
+            tr.translate(
+                "greetings",
+                "en",
+                [("NAME", "İlker"), ("CITY", " in istanbul")]
+            )?,
+            "İLKER! İlker, i\u{307}lker or İlker In Istanbul"
+        );
+        // Turkish keeps the dot of `i`, and leaves `I` dotless
+        assert_eq!(
+            tr.translate("greetings", "tr", [("NAME", "iIıİ irmak")])?,
+            "İIIİ İRMAK iııi irmak İIıİ İrmak"
+        );
+        // Arguments left out are kept as-is
+        assert_eq!(
+            tr.translate("greetings", "tr", [("", ""); 0])?,
+            "{NAME:upper} {NAME:lower} {NAME:title}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn case_annotations_strict() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).with_strict_arguments(true);
+        tr.add_text("shout", ["NAME"], [("en", "NAME:upper, NAME!")])?;
+
+        assert_eq!(
+            tr.translate("shout", "en", [("NAME", "Julian")])?,
+            "JULIAN, Julian!"
+        );
+
+        // Using only a cased placeholder still counts as using the argument
+        let mut tr = Translator::new(["en"])
+            .with_delimiters("{", "}")
+            .with_strict_arguments(true);
+        tr.add_text("whisper", ["NAME"], [("en", "psst, {NAME:lower}")])?;
+        assert_eq!(
+            tr.translate("whisper", "en", [("NAME", "Julian")])?,
+            "psst, julian"
+        );
+
+        tr.rename_argument("whisper", "NAME", "WHO")?;
+        assert_eq!(tr.message("whisper", "en"), Some("psst, {WHO:lower}"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn cased_placeholders_that_occur() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]).with_delimiters("{", "}");
+        tr.add_text(
+            "greetings",
+            ["NAME", "CITY"],
+            [
+                ("en", "{NAME:upper} from {CITY}"),
+                ("pt", "{NAME:title} de {CITY}"),
+            ],
+        )?;
+        tr.add_text(
+            "plain",
+            ["NAME"],
+            [("en", "Hi, {NAME}"), ("pt", "Olá, {NAME}")],
+        )?;
+
+        // Only the ones some message uses are matched
+        let cased = |key| -> Vec<_> {
+            tr.translations[key]
+                .cased_placeholders
+                .iter()
+                .map(|(_, _, placeholder)| placeholder.as_str())
+                .collect()
+        };
+        assert_eq!(cased("greetings"), ["{NAME:upper}", "{NAME:title}"]);
+        assert!(cased("plain").is_empty());
+
+        let args = [("NAME", "ana"), ("CITY", "Lisbon")];
+        assert_eq!(tr.translate("greetings", "en", args)?, "ANA from Lisbon");
+        assert_eq!(tr.translate("greetings", "pt", args)?, "Ana de Lisbon");
+        assert_eq!(tr.translate("plain", "en", [("NAME", "ana")])?, "Hi, ana");
+
+        tr.add_plural(
+            "items",
+            ["COUNT", "OWNER"],
+            [
+                (
+                    "en",
+                    vec![
+                        (PluralCategory::One, "{OWNER:title} has one item"),
+                        (PluralCategory::Other, "{OWNER} has {COUNT} items"),
+                    ],
+                ),
+                (
+                    "pt",
+                    vec![(PluralCategory::Other, "{OWNER} tem {COUNT} itens")],
+                ),
+            ],
+        )?;
+        assert_eq!(tr.plurals["items"].cased_placeholders.len(), 1);
+        assert_eq!(
+            tr.translate_plural("items", "en", 1, [("OWNER", "ana")])?,
+            "Ana has one item"
+        );
+
+        Ok(())
+    }
+}
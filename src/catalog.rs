@@ -0,0 +1,166 @@
+//! Catalogs holding a single language each, such as `.po` or Android `strings.xml` files.
+
+use std::collections::{HashMap, HashSet};
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{ensure_same_arguments, unshift_language, Error, LanguageId, Translation, Translator};
+
+/// Messages read from single-language catalogs for languages loaded so far, waiting
+/// for the remaining languages before being turned into translations.
+#[derive(Default)]
+pub(crate) struct PendingCatalog {
+    loaded: HashSet<LanguageId>,
+    messages: HashMap<SmallStr, HashMap<LanguageId, SmallStr>>,
+    /// Arguments declared for each key on top of the ones found in its messages.
+    pub(crate) arguments: HashMap<SmallStr, Vec<SmallStr>>,
+}
+
+impl Translator {
+    /// Whether any single-language catalog was loaded while waiting for other languages.
+    pub(crate) fn has_pending_catalogs(&self) -> bool {
+        let catalogs = [
+            #[cfg(feature = "gettext")]
+            &self.po_catalog,
+            #[cfg(feature = "android")]
+            &self.android_catalog,
+            #[cfg(feature = "apple")]
+            &self.apple_catalog,
+            #[cfg(feature = "arb")]
+            &self.arb_catalog,
+        ];
+
+        catalogs.iter().any(|catalog| !catalog.loaded.is_empty())
+    }
+
+    /// Drops the messages of `removed` from pending catalogs, shifting the ids after it.
+    pub(crate) fn remove_pending_language(&mut self, removed: LanguageId) {
+        let catalogs = [
+            #[cfg(feature = "gettext")]
+            &mut self.po_catalog,
+            #[cfg(feature = "android")]
+            &mut self.android_catalog,
+            #[cfg(feature = "apple")]
+            &mut self.apple_catalog,
+            #[cfg(feature = "arb")]
+            &mut self.arb_catalog,
+        ];
+
+        for catalog in catalogs {
+            catalog.loaded = catalog
+                .loaded
+                .drain()
+                .filter_map(|id| unshift_language(id, removed))
+                .collect();
+            for messages in catalog.messages.values_mut() {
+                *messages = messages
+                    .drain()
+                    .filter_map(|(id, message)| Some((unshift_language(id, removed)?, message)))
+                    .collect();
+            }
+        }
+    }
+
+    /// Adds the `(key, message)` pairs of `entries`, all in `language_id`, to the pending
+    /// catalog picked by `catalog`.
+    ///
+    /// Once every known language has been loaded, each key is registered as through
+    /// [`Translator::add_text`], declaring the tokens found by `arguments_of` in its
+    /// messages, along with the ones declared in the catalog, as its arguments. Messages
+    /// of the same key using different tokens fail with [`Error::ArgumentMismatch`].
+    ///
+    /// Keys are checked in sorted order before any of them is added, so the first error is
+    /// the same on every load. On failure, nothing is added and the messages of this
+    /// language are discarded, keeping the ones of the languages loaded before.
+    pub(crate) fn load_catalog(
+        &mut self,
+        catalog: fn(&mut Translator) -> &mut PendingCatalog,
+        language_id: LanguageId,
+        entries: Vec<(SmallStr, SmallStr)>,
+        arguments_of: fn(&str) -> Vec<&str>,
+    ) -> Result<(), Error> {
+        let language_count = self.languages.len();
+        let pending = catalog(self);
+
+        for (key, message) in entries {
+            let messages = pending.messages.entry(key.clone()).or_default();
+            if messages.insert(language_id, message).is_some() {
+                return Err(Error::DuplicatedKey(key));
+            }
+        }
+        pending.loaded.insert(language_id);
+
+        if pending.loaded.len() < language_count {
+            return Ok(());
+        }
+
+        let mut pending = std::mem::take(pending);
+        let translations = match self.build_catalog(&pending, arguments_of) {
+            Ok(translations) => translations,
+            Err(error) => {
+                // Keep the languages loaded before, so that this one can be loaded again
+                for messages in pending.messages.values_mut() {
+                    messages.remove(&language_id);
+                }
+                pending.messages.retain(|_, messages| !messages.is_empty());
+                pending.loaded.remove(&language_id);
+                *catalog(self) = pending;
+
+                return Err(error);
+            }
+        };
+
+        for (key, translation) in translations {
+            self.insert_translation(key, translation);
+        }
+
+        Ok(())
+    }
+
+    /// Builds the translation of every key of a complete `pending` catalog, in sorted key
+    /// order, without adding any of them, so that the same error is reported on every load
+    /// and nothing is added on failure.
+    fn build_catalog(
+        &self,
+        pending: &PendingCatalog,
+        arguments_of: fn(&str) -> Vec<&str>,
+    ) -> Result<Vec<(SmallStr, Translation)>, Error> {
+        let mut keys: Vec<_> = pending.messages.keys().cloned().collect();
+        keys.sort_unstable();
+
+        keys.into_iter()
+            .map(|key| {
+                let messages = &pending.messages[&key];
+                let mut languages: Vec<_> = messages.iter().collect();
+                languages.sort_unstable_by_key(|(id, _)| **id);
+                ensure_same_arguments(
+                    &key,
+                    languages
+                        .iter()
+                        .map(|(id, message)| (self.languages[**id].as_str(), message.as_str())),
+                    arguments_of,
+                )?;
+
+                let mut arguments: Vec<SmallStr> = messages
+                    .values()
+                    .flat_map(|message| arguments_of(message))
+                    .map(Into::into)
+                    .chain(pending.arguments.get(&key).into_iter().flatten().cloned())
+                    .collect();
+                arguments.sort();
+                arguments.dedup();
+
+                if self.translations.contains_key(&key) {
+                    return Err(Error::DuplicatedKey(key));
+                }
+
+                let translations = languages
+                    .into_iter()
+                    .map(|(id, message)| (self.languages[*id].clone(), message.clone()));
+                let translation = self.build_text_translation(arguments, translations, true)?;
+
+                Ok((key, translation))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,212 @@
+//! Generating a strongly-typed enum of the keys of a catalog, meant to be called
+//! from build scripts.
+//!
+//! ```no_run
+//! // In build.rs
+//! let out_dir = std::env::var("OUT_DIR").unwrap();
+//! binja::codegen::emit_keys("i18n/catalog.json", format!("{out_dir}/keys.rs")).unwrap();
+//! println!("cargo:rerun-if-changed=i18n/catalog.json");
+//! ```
+//!
+//! The generated module can then be included and used in place of string keys:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/keys.rs"));
+//!
+//! tr.translate(Keys::Greetings.as_str(), "en", [("NAME", "Julian")])?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translator};
+
+/// Reads the JSON catalog at `json_path`, in the format of [`Translator::from_json`], and
+/// writes to `out_path` a Rust module with a `Keys` enum holding one variant per key.
+///
+/// Variants are named by turning keys into `UpperCamelCase`, splitting words at `_`, `-`,
+/// `.`, `/` and whitespace, so that `home.title` becomes `Keys::HomeTitle`. Keys holding
+/// any other character outside of ASCII letters and digits, or whose name would start
+/// with a digit, fail with [`Error::InvalidIdentifier`]. Keys that end up with the same
+/// name fail with [`Error::CollidingIdentifiers`].
+pub fn emit_keys(json_path: impl AsRef<Path>, out_path: impl AsRef<Path>) -> Result<(), Error> {
+    let (json_path, out_path) = (json_path.as_ref(), out_path.as_ref());
+
+    let json = std::fs::read_to_string(json_path)
+        .map_err(|err| Error::Io(format!("{}: {err}", json_path.display())))?;
+    let translator = Translator::from_json(&json)?;
+
+    let module = keys_module(translator.keys())?;
+
+    std::fs::write(out_path, module)
+        .map_err(|err| Error::Io(format!("{}: {err}", out_path.display())))
+}
+
+/// Writes the `Keys` enum of `keys`, with variants sorted by name.
+fn keys_module<'a>(keys: impl Iterator<Item = &'a str>) -> Result<String, Error> {
+    // Variant -> key
+    let mut variants: BTreeMap<SmallStr, &str> = BTreeMap::new();
+
+    for key in keys {
+        let variant = variant_name(key).ok_or_else(|| Error::InvalidIdentifier(key.into()))?;
+
+        if let Some(other) = variants.insert(variant, key) {
+            // Sorted so that the error does not depend on the iteration order of keys
+            let (first, second) = match other < key {
+                true => (other, key),
+                false => (key, other),
+            };
+            return Err(Error::CollidingIdentifiers(first.into(), second.into()));
+        }
+    }
+
+    let mut module = String::from(
+        "// Generated by `binja::codegen::emit_keys`, do not edit.\n\n\
+         /// The keys of the translation catalog.\n\
+         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n\
+         pub enum Keys {\n",
+    );
+    for variant in variants.keys() {
+        writeln!(module, "    {variant},")?;
+    }
+
+    module.push_str(
+        "}\n\n\
+         impl Keys {\n    \
+         /// Every key of the catalog.\n    \
+         pub const ALL: &'static [Keys] = &[",
+    );
+    for variant in variants.keys() {
+        write!(module, "Keys::{variant}, ")?;
+    }
+    module.push_str(
+        "];\n\n    \
+         /// The key this variant stands for.\n    \
+         pub const fn as_str(self) -> &'static str {\n        \
+         match self {\n",
+    );
+    for (variant, key) in &variants {
+        // `Debug` escapes strings the way Rust literals expect
+        writeln!(module, "            Keys::{variant} => {key:?},")?;
+    }
+    module.push_str("        }\n    }\n}\n");
+
+    Ok(module)
+}
+
+/// The `UpperCamelCase` identifier for `key`, if it makes a valid one.
+fn variant_name(key: &str) -> Option<SmallStr> {
+    let mut name = SmallStr::with_capacity(key.len());
+
+    for word in key
+        .split(['_', '-', '.', '/'])
+        .flat_map(str::split_whitespace)
+    {
+        if !word.chars().all(|ch| ch.is_ascii_alphanumeric()) {
+            return None;
+        }
+
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            name.push(first.to_ascii_uppercase());
+            name.push_str(chars.as_str());
+        }
+    }
+
+    match name.chars().next() {
+        Some(first) if first.is_ascii_alphabetic() && name != "Self" => Some(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{emit_keys, keys_module, variant_name};
+    use crate::Error;
+
+    #[test]
+    fn variant_names() {
+        assert_eq!(variant_name("greetings").as_deref(), Some("Greetings"));
+        assert_eq!(variant_name("home.title").as_deref(), Some("HomeTitle"));
+        assert_eq!(
+            variant_name("sign_in-button").as_deref(),
+            Some("SignInButton")
+        );
+        assert_eq!(variant_name("item 2").as_deref(), Some("Item2"));
+
+        assert_eq!(variant_name("2fa"), None);
+        assert_eq!(variant_name("what?"), None);
+        assert_eq!(variant_name("..."), None);
+        assert_eq!(variant_name("self"), None);
+
+        assert_eq!(
+            keys_module(["home.title", "home_title"].into_iter()),
+            Err(Error::CollidingIdentifiers(
+                "home.title".into(),
+                "home_title".into()
+            ))
+        );
+        assert_eq!(
+            keys_module(["2fa"].into_iter()),
+            Err(Error::InvalidIdentifier("2fa".into()))
+        );
+    }
+
+    #[test]
+    fn emit_keys_compiles() -> Result<(), Error> {
+        let dir = std::env::temp_dir().join(format!("binja-codegen-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let catalog = dir.join("catalog.json");
+        std::fs::write(
+            &catalog,
+            r#"{
+                "languages": ["en"],
+                "keys": {
+                    "greetings": { "translations": { "en": "Hello!" } },
+                    "home.title": { "translations": { "en": "Home" } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let keys = dir.join("keys.rs");
+        emit_keys(&catalog, &keys)?;
+
+        let main = dir.join("main.rs");
+        std::fs::write(
+            &main,
+            r#"include!("keys.rs");
+
+            fn main() {
+                assert_eq!(Keys::Greetings.as_str(), "greetings");
+                assert_eq!(Keys::HomeTitle.as_str(), "home.title");
+                assert_eq!(Keys::ALL, [Keys::Greetings, Keys::HomeTitle]);
+            }"#,
+        )
+        .unwrap();
+
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".into());
+        let status = std::process::Command::new(rustc)
+            .arg("--edition=2021")
+            .arg("-o")
+            .arg(dir.join("main"))
+            .arg(&main)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let status = std::process::Command::new(dir.join("main"))
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let _ = std::fs::remove_dir_all(&dir);
+
+        Ok(())
+    }
+}
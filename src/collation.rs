@@ -0,0 +1,132 @@
+//! Sorting exported keys by the collation of a language, so that `é` sorts next to `e`.
+
+use compact_str::CompactString as SmallStr;
+use icu_collator::options::CollatorOptions;
+use icu_collator::{Collator, CollatorBorrowed, CollatorPreferences};
+use icu_locale_core::Locale;
+
+use crate::Translator;
+
+/// Compares keys by the collation of a language, see [`Translator::with_collation`].
+pub(crate) struct Collation {
+    language: SmallStr,
+    collator: CollatorBorrowed<'static>,
+}
+
+impl Clone for Collation {
+    fn clone(&self) -> Self {
+        Self::new(&self.language)
+    }
+}
+
+impl Collation {
+    /// The collation of `language`, or the root collation if it isn't a valid BCP 47 tag
+    /// or has no data of its own.
+    fn new(language: &str) -> Self {
+        let locale = Locale::try_from_str(language).unwrap_or(Locale::UNKNOWN);
+
+        let collator = Collator::try_new(
+            CollatorPreferences::from(&locale),
+            CollatorOptions::default(),
+        )
+        .or_else(|_| Collator::try_new(Default::default(), CollatorOptions::default()))
+        .expect("the root collation is always compiled in");
+
+        Self {
+            language: language.into(),
+            collator,
+        }
+    }
+
+    /// The language whose collation this is.
+    #[cfg(feature = "serde")]
+    pub(crate) fn language(&self) -> &str {
+        &self.language
+    }
+
+    pub(crate) fn compare(&self, left: &str, right: &str) -> core::cmp::Ordering {
+        // Identical keys apart from what the collation ignores still get a stable order
+        self.collator
+            .compare(left, right)
+            .then_with(|| left.cmp(right))
+    }
+}
+
+impl Translator {
+    /// Sorts keys by the collation of `language` instead of by their bytes in
+    /// [`Translator::to_json`], [`Translator::to_properties`] and [`Translator::to_xliff`],
+    /// so that exported catalogs read in the order translators expect, e.g. `éclair`
+    /// between `dune` and `fable` rather than after both.
+    ///
+    /// Codes that aren't valid BCP 47 tags use the root collation, which already orders
+    /// accented letters next to their base letter.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["fr"]).with_collation("fr");
+    /// for key in ["fable", "éclair", "dune"] {
+    ///     tr.add_text(key, [""; 0], [("fr", key)])?;
+    /// }
+    ///
+    /// assert_eq!(tr.to_properties("fr")?, "dune=dune\n\\u00E9clair=\\u00E9clair\nfable=fable\n");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn with_collation(mut self, language: &str) -> Self {
+        self.collation = Some(Collation::new(language));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn collated_export() -> Result<(), Error> {
+        let keys = ["zebra", "Ábaco", "abacus", "épée", "eagle", "ezra", "Zoe"];
+        let properties = |tr: &Translator| -> Result<Vec<String>, Error> {
+            Ok(tr
+                .to_properties("es")?
+                .lines()
+                .map(|line| line.split_once('=').unwrap().0.into())
+                .collect())
+        };
+
+        let mut tr = Translator::new(["es"]);
+        for key in keys {
+            tr.add_text(key, [""; 0], [("es", "-")])?;
+        }
+        assert_eq!(
+            properties(&tr)?,
+            [
+                "Zoe",
+                "abacus",
+                "eagle",
+                "ezra",
+                "zebra",
+                "\\u00C1baco",
+                "\\u00E9p\\u00E9e"
+            ]
+        );
+
+        let mut tr = Translator::new(["es"]).with_collation("es");
+        for key in keys {
+            tr.add_text(key, [""; 0], [("es", "-")])?;
+        }
+        assert_eq!(
+            properties(&tr)?,
+            [
+                "\\u00C1baco",
+                "abacus",
+                "eagle",
+                "\\u00E9p\\u00E9e",
+                "ezra",
+                "zebra",
+                "Zoe"
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+//! Summary of how complete the translations of a [`Translator`] are.
+
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::Translator;
+
+/// Translation completeness, as reported by [`Translator::coverage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    pub total_keys: usize,
+    /// How many keys have a message in each language, in sorted language order.
+    pub translated: Vec<(SmallStr, usize)>,
+    /// Every `(key, language)` pair lacking a message, sorted by key, then language.
+    pub gaps: Vec<(SmallStr, SmallStr)>,
+}
+
+impl Coverage {
+    /// Whether every key has a message in every language.
+    pub fn is_complete(&self) -> bool {
+        self.gaps.is_empty()
+    }
+}
+
+impl Translator {
+    /// Summarizes which keys lack messages, e.g. for CI gating when using
+    /// [`Translator::add_text_partial`].
+    pub fn coverage(&self) -> Coverage {
+        let mut keys: Vec<_> = self.translations.iter().collect();
+        keys.sort_unstable_by_key(|(key, _)| *key);
+
+        let mut translated: Vec<_> = self
+            .languages
+            .iter()
+            .map(|language| (language.clone(), 0))
+            .collect();
+        let mut gaps = Vec::new();
+
+        for (key, translation) in keys {
+            for (language_id, (language, count)) in translated.iter_mut().enumerate() {
+                if translation.translations.contains_key(&language_id) {
+                    *count += 1;
+                } else {
+                    gaps.push((key.clone(), language.clone()));
+                }
+            }
+        }
+
+        Coverage {
+            total_keys: self.translations.len(),
+            translated,
+            gaps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn coverage() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]);
+        assert!(tr.coverage().is_complete());
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Good morning, NAME!"),
+                ("pt", "Bom dia, NAME!"),
+                ("it", "Buongiorno, NAME!"),
+            ],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!")])?;
+
+        let coverage = tr.coverage();
+        assert_eq!(coverage.total_keys, 2);
+        assert_eq!(
+            coverage.translated,
+            [("en".into(), 2), ("it".into(), 1), ("pt".into(), 1)]
+        );
+        assert_eq!(
+            coverage.gaps,
+            [("bye".into(), "it".into()), ("bye".into(), "pt".into())]
+        );
+        assert!(!coverage.is_complete());
+
+        Ok(())
+    }
+}
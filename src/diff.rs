@@ -0,0 +1,122 @@
+//! Comparison of the keys of two translators.
+
+use alloc::vec::Vec;
+
+use crate::Translator;
+
+/// The differences between the keys of two translators, see [`Translator::diff`].
+///
+/// Every list is sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff<'a> {
+    only_in_self: Vec<&'a str>,
+    only_in_other: Vec<&'a str>,
+    differing_arguments: Vec<&'a str>,
+}
+
+impl<'a> Diff<'a> {
+    /// Keys registered in the translator `diff` was called on, but not in the other one.
+    pub fn only_in_self(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.only_in_self.iter().copied()
+    }
+
+    /// Keys registered in the other translator, but not in the one `diff` was called on.
+    pub fn only_in_other(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.only_in_other.iter().copied()
+    }
+
+    /// Keys registered in both translators, but declaring different arguments.
+    pub fn differing_arguments(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.differing_arguments.iter().copied()
+    }
+
+    /// Whether both translators have the same keys, with the same arguments.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_self.is_empty()
+            && self.only_in_other.is_empty()
+            && self.differing_arguments.is_empty()
+    }
+}
+
+impl Translator {
+    /// Compares the keys of this translator with those of `other`, e.g. to find
+    /// which keys of a reference catalog a downstream one is missing.
+    ///
+    /// Arguments are compared regardless of the order they were declared in.
+    pub fn diff<'a>(&'a self, other: &'a Translator) -> Diff<'a> {
+        let mut only_in_self = Vec::new();
+        let mut differing_arguments = Vec::new();
+
+        for (key, translation) in &self.translations {
+            let Some(other_translation) = other.translations.get(key) else {
+                only_in_self.push(key.as_str());
+                continue;
+            };
+
+            let mut arguments: Vec<_> = translation
+                .arguments
+                .resolve(&self.argument_table)
+                .names()
+                .collect();
+            let mut other_arguments: Vec<_> = other_translation
+                .arguments
+                .resolve(&other.argument_table)
+                .names()
+                .collect();
+            arguments.sort_unstable();
+            other_arguments.sort_unstable();
+
+            if arguments != other_arguments {
+                differing_arguments.push(key.as_str());
+            }
+        }
+
+        let mut only_in_other: Vec<_> = other
+            .keys()
+            .filter(|key| !self.translations.contains_key(*key))
+            .collect();
+
+        only_in_self.sort_unstable();
+        only_in_other.sort_unstable();
+        differing_arguments.sort_unstable();
+
+        Diff {
+            only_in_self,
+            only_in_other,
+            differing_arguments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn diff() -> Result<(), Error> {
+        let mut reference = Translator::new(["en"]);
+        reference.add_text("greetings", ["NAME"], [("en", "Hello, NAME!")])?;
+        reference.add_text(
+            "welcome",
+            ["NAME", "PLACE"],
+            [("en", "Welcome to PLACE, NAME!")],
+        )?;
+        reference.add_text("bye", [""; 0], [("en", "Bye!")])?;
+        reference.add_text("thanks", [""; 0], [("en", "Thanks!")])?;
+
+        let mut downstream = Translator::new(["pt"]);
+        downstream.add_text("greetings", ["NAME"], [("pt", "Olá, NAME!")])?;
+        downstream.add_text("welcome", ["PLACE"], [("pt", "Bem-vindo a PLACE!")])?;
+        downstream.add_text("yes", [""; 0], [("pt", "Sim")])?;
+
+        let diff = reference.diff(&downstream);
+        assert_eq!(diff.only_in_self().collect::<Vec<_>>(), ["bye", "thanks"]);
+        assert_eq!(diff.only_in_other().collect::<Vec<_>>(), ["yes"]);
+        assert_eq!(diff.differing_arguments().collect::<Vec<_>>(), ["welcome"]);
+        assert!(!diff.is_empty());
+
+        assert!(reference.diff(&reference).is_empty());
+
+        Ok(())
+    }
+}
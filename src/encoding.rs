@@ -0,0 +1,133 @@
+//! Loading files written in encodings other than UTF-8, such as Latin-1 or UTF-16.
+
+use encoding_rs::Encoding;
+
+use crate::{Error, Translator};
+
+/// Transcodes `bytes`, written in `encoding`, into UTF-8, skipping a leading byte order
+/// mark of that encoding.
+///
+/// Fails with [`Error::Encoding`] naming the offset of the first invalid byte sequence,
+/// instead of replacing it.
+pub fn decode(bytes: &[u8], encoding: &'static Encoding) -> Result<String, Error> {
+    let mut decoder = encoding.new_decoder_with_bom_removal();
+    let capacity = decoder
+        .max_utf8_buffer_length_without_replacement(bytes.len())
+        .ok_or_else(|| Error::Encoding("input too long to decode".into()))?;
+
+    let mut decoded = String::with_capacity(capacity);
+    let (result, read) = decoder.decode_to_string_without_replacement(bytes, &mut decoded, true);
+
+    match result {
+        encoding_rs::DecoderResult::InputEmpty => Ok(decoded),
+        encoding_rs::DecoderResult::Malformed(invalid, unread) => {
+            let offset = read - invalid as usize - unread as usize;
+            Err(Error::Encoding(format!(
+                "invalid {} byte sequence at offset {offset}",
+                encoding.name()
+            )))
+        }
+        // Unreachable, since the buffer fits the worst case
+        encoding_rs::DecoderResult::OutputFull => {
+            Err(Error::Encoding("decoding buffer too small".into()))
+        }
+    }
+}
+
+impl Translator {
+    /// Like [`Translator::load_po`], but reads `bytes` written in `encoding`, see [`decode`].
+    #[cfg(feature = "gettext")]
+    pub fn load_po_encoded(
+        &mut self,
+        language: &str,
+        bytes: &[u8],
+        encoding: &'static Encoding,
+    ) -> Result<(), Error> {
+        self.load_po(language, decode(bytes, encoding)?.as_bytes())
+    }
+
+    /// Like [`Translator::load_apple_strings`], but reads `bytes` written in `encoding`,
+    /// such as the UTF-16 `.strings` files Xcode used to write, see [`decode`].
+    #[cfg(feature = "apple")]
+    pub fn load_apple_strings_encoded(
+        &mut self,
+        language: &str,
+        bytes: &[u8],
+        encoding: &'static Encoding,
+    ) -> Result<(), Error> {
+        self.load_apple_strings(language, decode(bytes, encoding)?.as_bytes())
+    }
+
+    /// Like [`Translator::load_android_xml`], but reads `bytes` written in `encoding`,
+    /// see [`decode`].
+    #[cfg(feature = "android")]
+    pub fn load_android_xml_encoded(
+        &mut self,
+        language: &str,
+        bytes: &[u8],
+        encoding: &'static Encoding,
+    ) -> Result<(), Error> {
+        self.load_android_xml(language, decode(bytes, encoding)?.as_bytes())
+    }
+
+    /// Like [`Translator::load_xliff`], but reads `bytes` written in `encoding`, see [`decode`].
+    #[cfg(feature = "xliff")]
+    pub fn load_xliff_encoded(
+        bytes: &[u8],
+        encoding: &'static Encoding,
+    ) -> Result<Translator, Error> {
+        Translator::load_xliff(decode(bytes, encoding)?.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use encoding_rs::{UTF_16LE, UTF_8, WINDOWS_1252};
+
+    use super::decode;
+    use crate::Error;
+
+    #[test]
+    fn decode_latin1() -> Result<(), Error> {
+        // `Café é bom` in Latin-1, whose `é` is the single byte 0xE9
+        let latin1 = b"Caf\xe9 \xe9 bom";
+        assert_eq!(decode(latin1, WINDOWS_1252)?, "Café é bom");
+
+        // The same text in UTF-16, after its byte order mark
+        let utf16: Vec<u8> = [0xFEFF]
+            .into_iter()
+            .chain("Café é bom".encode_utf16())
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        assert_eq!(decode(&utf16, UTF_16LE)?, "Café é bom");
+
+        assert_eq!(
+            decode(latin1, UTF_8),
+            Err(Error::Encoding(
+                "invalid UTF-8 byte sequence at offset 3".into()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "gettext")]
+    #[test]
+    fn load_po_encoded() -> Result<(), Error> {
+        use crate::Translator;
+
+        let mut tr = Translator::new(["fr"]);
+        tr.load_po_encoded(
+            "fr",
+            b"msgid \"coffee\"\nmsgstr \"Caf\xe9 pour %{NAME}\"\n",
+            WINDOWS_1252,
+        )?;
+
+        assert_eq!(
+            tr.translate("coffee", "fr", [("%{NAME}", "Julian")])?,
+            "Café pour Julian"
+        );
+
+        Ok(())
+    }
+}
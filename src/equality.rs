@@ -0,0 +1,120 @@
+//! Comparing translators by their contents, e.g. a loaded catalog against an expected one.
+
+use compact_str::CompactString as SmallStr;
+
+use crate::intern::ArgumentTable;
+use crate::{Map, Translation, Translator};
+
+/// Whether both maps hold the same keys, declaring the same arguments, and messages for
+/// the same languages, compared through `same_message`.
+fn same_keys<M>(
+    (keys, table): (&Map<SmallStr, Translation<M>>, &ArgumentTable),
+    (other_keys, other_table): (&Map<SmallStr, Translation<M>>, &ArgumentTable),
+    same_message: impl Fn(&M, &M) -> bool,
+) -> bool {
+    keys.len() == other_keys.len()
+        && keys.iter().all(|(key, translation)| {
+            let Some(other) = other_keys.get(key) else {
+                return false;
+            };
+
+            translation.declared_arguments(table) == other.declared_arguments(other_table)
+                && translation.translations.len() == other.translations.len()
+                && translation
+                    .translations
+                    .iter()
+                    .all(|(language_id, message)| {
+                        other
+                            .translations
+                            .get(language_id)
+                            .is_some_and(|other| same_message(message, other))
+                    })
+        })
+}
+
+/// Compares languages, keys, their declared arguments and their messages in each language,
+/// regardless of how they're stored, e.g. whether they're interned.
+///
+/// Settings, such as delimiters or fallback chains, are left out, as are automata built
+/// from the arguments.
+impl PartialEq for Translator {
+    fn eq(&self, other: &Self) -> bool {
+        let table = &self.argument_table;
+        let other_table = &other.argument_table;
+
+        self.languages == other.languages
+            && same_keys(
+                (&self.translations, table),
+                (&other.translations, other_table),
+                |message, other| message.as_str() == other.as_str(),
+            )
+            && same_keys(
+                (&self.plurals, table),
+                (&other.plurals, other_table),
+                PartialEq::eq,
+            )
+            && same_keys(
+                (&self.selects, table),
+                (&other.selects, other_table),
+                PartialEq::eq,
+            )
+            && same_keys(
+                (&self.ranged, table),
+                (&other.ranged, other_table),
+                PartialEq::eq,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    fn build(tr: &mut Translator, farewell: &str) -> Result<(), Error> {
+        tr.add_text(
+            "greetings",
+            ["NAME", "COUNT:number", "TITLE?"],
+            [
+                ("en", "Hi, TITLENAME! COUNT:number new"),
+                ("pt", "Olá, TITLENAME! COUNT:number novas"),
+            ],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", farewell)])?;
+        tr.add_select(
+            "invite",
+            ["NAME"],
+            [
+                ("en", [("other", "NAME invited you")]),
+                ("pt", [("other", "NAME convidou-te")]),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn translator_eq() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        build(&mut tr, "Bye!")?;
+
+        // Built in its own way, but with the same contents
+        let mut other = Translator::new(["pt", "en"])
+            .with_interned_arguments()
+            .with_message_interning();
+        build(&mut other, "Bye!")?;
+        assert!(tr == other);
+
+        let mut different = Translator::new(["en", "pt"]);
+        build(&mut different, "Goodbye!")?;
+        assert!(tr != different);
+
+        assert!(Translator::new(["en", "pt"]) != Translator::new(["en", "pt", "it"]));
+
+        let mut different = Translator::new(["en", "pt"]);
+        build(&mut different, "Bye!")?;
+        different.remove_text("bye")?;
+        assert!(tr != different);
+
+        Ok(())
+    }
+}
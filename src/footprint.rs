@@ -0,0 +1,166 @@
+//! Estimating how much memory a [`Translator`] holds.
+
+use core::mem::{size_of, size_of_val};
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{LanguageId, PluralCategory, Translation, Translator};
+
+/// The bytes `string` keeps on the heap, if it doesn't fit inline.
+pub(crate) fn heap_size(string: &SmallStr) -> usize {
+    match string.is_heap_allocated() {
+        true => string.capacity(),
+        false => 0,
+    }
+}
+
+impl<M> Translation<M> {
+    /// Estimates the heap bytes held by this translation, given those held by each message.
+    fn footprint(&self, message_footprint: impl Fn(&M) -> usize) -> usize {
+        let messages: usize = self
+            .translations
+            .values()
+            .map(|message| size_of::<(LanguageId, M)>() + message_footprint(message))
+            .sum();
+
+        self.arguments.footprint()
+            + self.automaton.memory_usage()
+            + self.unescaped_delimiter.as_ref().map_or(0, heap_size)
+            + size_of_val(&*self.patterns)
+            + size_of_val(&*self.cased_placeholders)
+            + self
+                .cased_placeholders
+                .iter()
+                .map(|(_, _, placeholder)| heap_size(placeholder))
+                .sum::<usize>()
+            + size_of_val(&*self.number_arguments)
+            + size_of_val(&*self.list_arguments)
+            + self
+                .formatted_arguments
+                .iter()
+                .map(|(_, annotation)| size_of::<(usize, SmallStr)>() + heap_size(annotation))
+                .sum::<usize>()
+            + size_of_val(&*self.optional_arguments)
+            + size_of_val(&*self.declared_order)
+            + self
+                .arguments_by_name
+                .keys()
+                .map(|name| size_of::<(SmallStr, usize)>() + heap_size(name))
+                .sum::<usize>()
+            + messages
+    }
+}
+
+impl Translator {
+    /// Estimates the heap bytes held by this translator: its languages, keys, arguments,
+    /// messages and the automatons matching their placeholders.
+    ///
+    /// This is only an estimate, e.g. the spare capacity of maps isn't accounted for,
+    /// meant to weigh options such as [`Translator::with_message_interning`].
+    pub fn memory_footprint(&self) -> usize {
+        let languages: usize = self
+            .languages
+            .iter()
+            .map(|language| size_of::<SmallStr>() + heap_size(language))
+            .sum();
+
+        let texts: usize = self
+            .translations
+            .iter()
+            .map(|(key, translation)| {
+                size_of_val(translation)
+                    + size_of::<SmallStr>()
+                    + heap_size(key)
+                    + translation.footprint(|message| message.footprint())
+            })
+            .sum();
+
+        let plurals: usize = self
+            .plurals
+            .iter()
+            .map(|(key, translation)| {
+                size_of_val(translation)
+                    + size_of::<SmallStr>()
+                    + heap_size(key)
+                    + translation.footprint(|forms| {
+                        forms
+                            .values()
+                            .map(|form| size_of::<(PluralCategory, SmallStr)>() + heap_size(form))
+                            .sum()
+                    })
+            })
+            .sum();
+
+        let selects: usize = self
+            .selects
+            .iter()
+            .map(|(key, translation)| {
+                size_of_val(translation)
+                    + size_of::<SmallStr>()
+                    + heap_size(key)
+                    + translation.footprint(|variants| {
+                        variants
+                            .iter()
+                            .map(|(variant, message)| {
+                                2 * size_of::<SmallStr>() + heap_size(variant) + heap_size(message)
+                            })
+                            .sum()
+                    })
+            })
+            .sum();
+
+        let ranged: usize = self
+            .ranged
+            .iter()
+            .map(|(key, translation)| {
+                size_of_val(translation)
+                    + size_of::<SmallStr>()
+                    + heap_size(key)
+                    + translation.footprint(|ranges| {
+                        ranges
+                            .iter()
+                            .map(|(range, message)| {
+                                size_of_val(range) + size_of::<SmallStr>() + heap_size(message)
+                            })
+                            .sum()
+                    })
+            })
+            .sum();
+
+        languages
+            + texts
+            + plurals
+            + selects
+            + ranged
+            + self.argument_table.footprint()
+            + self.message_table.footprint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn memory_footprint() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        let empty = tr.memory_footprint();
+        assert!(empty > 0);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Good morning, NAME! It's lovely to see you."),
+                ("pt", "Bom dia, NAME! É um prazer te ver."),
+            ],
+        )?;
+        let one_key = tr.memory_footprint();
+        assert!(one_key > empty);
+
+        tr.add_text("ok", [""; 0], [("en", "OK"), ("pt", "OK")])?;
+        assert!(tr.memory_footprint() > one_key);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+//! User-defined formatting of argument values of any type, as in `{price:currency}`.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::any::Any;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translator};
+
+/// Formats the values of the arguments declared with a given annotation, see
+/// [`Translator::with_formatter`].
+pub trait ArgumentFormatter: Send + Sync {
+    /// Formats `value` for a message of `language`.
+    fn format(&self, value: &dyn Any, language: &str) -> String;
+}
+
+impl<F: Fn(&dyn Any, &str) -> String + Send + Sync> ArgumentFormatter for F {
+    fn format(&self, value: &dyn Any, language: &str) -> String {
+        self(value, language)
+    }
+}
+
+/// `value` as a string, if it's a string or an integer.
+fn plain_value(value: &dyn Any) -> Option<String> {
+    macro_rules! downcast {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(value.to_string());
+                }
+            )*
+        };
+    }
+
+    downcast!(String, &str, SmallStr, i8, i16, i32, i64, u8, u16, u32, u64, isize, usize);
+
+    None
+}
+
+impl Translator {
+    /// Registers `formatter` for the arguments declared with the `annotation` suffix, as
+    /// in `price:currency` for the `currency` annotation, when translating through
+    /// [`Translator::translate_typed`].
+    ///
+    /// Such arguments are given by their bare name, and their placeholder keeps the suffix.
+    /// Only applies to keys added afterwards, for which any other suffix is part of the
+    /// name of the argument, as in `time:hh`.
+    ///
+    /// ```
+    /// use std::any::Any;
+    ///
+    /// use binja::Translator;
+    ///
+    /// let currency = |value: &dyn Any, language: &str| {
+    ///     let cents = value.downcast_ref::<u64>().copied().unwrap_or_default();
+    ///     match language {
+    ///         "pt" => format!("{},{:02} €", cents / 100, cents % 100),
+    ///         _ => format!("€{}.{:02}", cents / 100, cents % 100),
+    ///     }
+    /// };
+    ///
+    /// let mut tr = Translator::new(["en", "pt"])
+    ///     .with_delimiters("{", "}")
+    ///     .with_formatter("currency", currency);
+    /// tr.add_text(
+    ///     "total",
+    ///     ["price:currency"],
+    ///     [("en", "Total: {price:currency}"), ("pt", "Total: {price:currency}")],
+    /// )?;
+    ///
+    /// let price: Box<dyn Any> = Box::new(1250_u64);
+    /// assert_eq!(tr.translate_typed("total", "pt", &[("price", price)])?, "Total: 12,50 €");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn with_formatter(
+        mut self,
+        annotation: &str,
+        formatter: impl ArgumentFormatter + 'static,
+    ) -> Self {
+        self.formatters
+            .insert(annotation.into(), Arc::new(formatter));
+        self
+    }
+
+    /// Like [`Translator::translate`], but takes values of any type, formatted by the
+    /// formatter registered for the annotation of their argument, see
+    /// [`Translator::with_formatter`].
+    ///
+    /// Arguments without such an annotation take strings or integers, and fail with
+    /// [`Error::UnsupportedValue`] otherwise. Arguments whose annotation has no formatter
+    /// fail with [`Error::MissingFormatter`].
+    pub fn translate_typed(
+        &self,
+        key: &str,
+        language: &str,
+        args: &[(&str, Box<dyn Any>)],
+    ) -> Result<String, Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+        let (language_id, _) = self.find_target(language)?;
+        let code = &self.languages[language_id];
+
+        let values = args
+            .iter()
+            .map(|(name, value)| {
+                let &index = translation
+                    .arguments_by_name
+                    .get(*name)
+                    .ok_or_else(|| Error::UnknownArgument((*name).into()))?;
+
+                let formatted = match translation.formatter_of(index) {
+                    Some(annotation) => self
+                        .formatters
+                        .get(annotation)
+                        .ok_or_else(|| Error::MissingFormatter(annotation.into()))?
+                        .format(value.as_ref(), code),
+                    None => plain_value(value.as_ref())
+                        .ok_or_else(|| Error::UnsupportedValue((*name).into()))?,
+                };
+
+                Ok((*name, formatted))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        self.translate(key, language, values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use core::any::Any;
+
+    use crate::{Error, Translator};
+
+    fn uppercase(value: &dyn Any, _: &str) -> String {
+        value
+            .downcast_ref::<&str>()
+            .map(|value| value.to_uppercase())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn translate_typed() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).with_formatter("uppercase", uppercase);
+        tr.add_text(
+            "greetings",
+            ["NAME:uppercase", "COUNT:number", "DATE:date"],
+            [("en", "Hi, NAME:uppercase! COUNT:number new. DATE:date")],
+        )?;
+
+        let args: [(&str, Box<dyn Any>); 2] =
+            [("NAME", Box::new("Julian")), ("COUNT", Box::new(1200))];
+        assert_eq!(
+            tr.translate_typed("greetings", "en", &args)?,
+            "Hi, JULIAN! 1,200 new. DATE:date"
+        );
+
+        // The simple string path is left as-is
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Hi, Julian! COUNT:number new. DATE:date"
+        );
+
+        // Without a formatter, the suffix is part of the name
+        assert_eq!(
+            tr.translate_typed("greetings", "en", &[("DATE:date", Box::new(7))])?,
+            "Hi, NAME:uppercase! COUNT:number new. 7"
+        );
+        assert_eq!(
+            tr.translate_typed("greetings", "en", &[("DATE", Box::new(0))]),
+            Err(Error::UnknownArgument("DATE".into()))
+        );
+        assert_eq!(
+            tr.translate_typed("greetings", "en", &[("COUNT", Box::new(1.5))]),
+            Err(Error::UnsupportedValue("COUNT".into()))
+        );
+        assert_eq!(
+            tr.translate_typed("greetings", "en", &[("SURNAME", Box::new("Doe"))]),
+            Err(Error::UnknownArgument("SURNAME".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn unregistered_annotations() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).with_delimiters("{", "}");
+        tr.add_text(
+            "alarm",
+            ["time:hh", "time:mm"],
+            [("en", "{time:hh}:{time:mm}")],
+        )?;
+
+        assert_eq!(
+            tr.translate("alarm", "en", [("time:hh", "07"), ("time:mm", "30")])?,
+            "07:30"
+        );
+        assert_eq!(
+            tr.translate("alarm", "en", [("time", "07")]),
+            Err(Error::UnknownArgument("time".into()))
+        );
+
+        // Keys keep the formatters of the translator they were added to
+        let mut formatted = Translator::new(["en"]).with_formatter("uppercase", uppercase);
+        formatted.add_text("shout", ["NAME:uppercase"], [("en", "NAME:uppercase!")])?;
+        tr.merge(formatted)?;
+        assert_eq!(
+            tr.translate_typed("shout", "en", &[("NAME", Box::new("Julian"))]),
+            Err(Error::MissingFormatter("uppercase".into()))
+        );
+
+        Ok(())
+    }
+}
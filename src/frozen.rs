@@ -0,0 +1,365 @@
+//! An immutable [`Translator`] with its keys in a perfect hash table.
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translation, Translator};
+
+/// A [`Translator`] that can no longer be modified, made through [`Translator::freeze`].
+///
+/// Keys are placed in a table by a perfect hash of them, built when freezing, so that
+/// looking one up takes a single cheap hash and comparison instead of going through a
+/// `HashMap`. Translates exactly as the translator it was made from, references to other
+/// keys and the pseudolocale included, and can be shared across threads as is.
+pub struct FrozenTranslator {
+    /// Every key, at the slot given by `hash`.
+    keys: Box<[SmallStr]>,
+    /// The translation of every key, at the same slot.
+    translations: Box<[Translation]>,
+    /// The slot of every key, in sorted order of the keys.
+    sorted: Box<[usize]>,
+    hash: PerfectHash,
+    /// Everything else needed to translate, with no texts of its own.
+    translator: Translator,
+}
+
+impl Translator {
+    /// Turns this translator into an immutable [`FrozenTranslator`].
+    pub fn freeze(mut self) -> FrozenTranslator {
+        let texts: Vec<_> = core::mem::take(&mut self.translations)
+            .into_iter()
+            .collect();
+
+        let hash = PerfectHash::new(texts.iter().map(|(key, _)| key.as_str()));
+
+        let mut slots: Vec<Option<(SmallStr, Translation)>> = texts.iter().map(|_| None).collect();
+        for (key, translation) in texts {
+            let slot = hash.slot(&key);
+            slots[slot] = Some((key, translation));
+        }
+        let (keys, translations): (Vec<_>, Vec<_>) = slots
+            .into_iter()
+            .map(|slot| slot.expect("every slot holds exactly one key"))
+            .unzip();
+
+        let mut sorted: Vec<usize> = (0..keys.len()).collect();
+        sorted.sort_unstable_by(|&a, &b| keys[a].cmp(&keys[b]));
+
+        FrozenTranslator {
+            keys: keys.into(),
+            translations: translations.into(),
+            sorted: sorted.into(),
+            hash,
+            translator: self,
+        }
+    }
+}
+
+impl FrozenTranslator {
+    /// See [`Translator::translate`].
+    pub fn translate<S1: Into<SmallStr>, S2: Into<SmallStr>, I: IntoIterator<Item = (S1, S2)>>(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<String, Error> {
+        self.translate_cow(key, language, args).map(Cow::into_owned)
+    }
+
+    /// See [`Translator::translate_cow`].
+    pub fn translate_cow<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<Cow<'_, str>, Error> {
+        let translation = self.get(key).ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        self.translator
+            .translate_found(translation, key, language, args, &|key| self.get(key))
+    }
+
+    /// Whether a message was registered under `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Every registered message key, in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.sorted.iter().map(|&slot| self.keys[slot].as_str())
+    }
+
+    /// Every language known to this translator, in sorted order.
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.translator.languages()
+    }
+
+    /// The number of registered message keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Whether no message keys were registered.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    fn get(&self, key: &str) -> Option<&Translation> {
+        if self.keys.is_empty() {
+            return None;
+        }
+
+        let slot = self.hash.slot(key);
+        (self.keys[slot] == key).then(|| &self.translations[slot])
+    }
+}
+
+/// How many keys share a bucket on average, trading the time taken to build a
+/// [`PerfectHash`] for its size.
+const KEYS_PER_BUCKET: usize = 4;
+
+/// A perfect hash of a set of keys, mapping each of them to a distinct slot in `0..len`,
+/// built through hash and displace.
+///
+/// The hash of a key picks one of its buckets, and the pilot of that bucket, found when
+/// building, moves every key of the bucket to a free slot. Keys outside the set are mapped
+/// to an arbitrary slot.
+struct PerfectHash {
+    seed: u64,
+    /// The pilot of each bucket.
+    pilots: Box<[u32]>,
+    len: usize,
+}
+
+impl PerfectHash {
+    fn new<'a>(keys: impl Iterator<Item = &'a str> + Clone) -> Self {
+        let len = keys.clone().count();
+        let buckets = len.div_ceil(KEYS_PER_BUCKET).max(1);
+
+        // Fails only if two keys collide on all 64 bits of their hash, so trying another
+        // seed is all it takes
+        (0..)
+            .find_map(|seed| {
+                let hashes: Vec<u64> = keys.clone().map(|key| hash(key, seed)).collect();
+                let pilots = pilots(&hashes, buckets, len)?;
+
+                Some(Self { seed, pilots, len })
+            })
+            .expect("some seed tells every key apart")
+    }
+
+    /// The slot of `key`, which is only meaningful if `key` was part of the set.
+    fn slot(&self, key: &str) -> usize {
+        let hash = hash(key, self.seed);
+
+        slot(hash, self.pilots[bucket(hash, self.pilots.len())], self.len)
+    }
+}
+
+/// Finds the pilot of every one of `buckets` buckets so that the keys whose hashes are
+/// `hashes` all land in distinct slots, unless two of them have the same hash.
+fn pilots(hashes: &[u64], buckets: usize, len: usize) -> Option<Box<[u32]>> {
+    let mut members: Vec<Vec<u64>> = vec![Vec::new(); buckets];
+    for &hash in hashes {
+        members[bucket(hash, buckets)].push(hash);
+    }
+
+    // The fullest buckets are the hardest to place, so they're placed first
+    let mut order: Vec<usize> = (0..buckets).collect();
+    order.sort_unstable_by_key(|&bucket| core::cmp::Reverse(members[bucket].len()));
+
+    let mut pilots = vec![0; buckets].into_boxed_slice();
+    let mut taken = vec![false; len];
+    let mut slots = Vec::with_capacity(KEYS_PER_BUCKET);
+
+    for bucket in order {
+        let hashes = &mut members[bucket];
+        if hashes.is_empty() {
+            break;
+        }
+
+        // No pilot can tell apart keys with the same hash
+        hashes.sort_unstable();
+        if hashes.windows(2).any(|pair| pair[0] == pair[1]) {
+            return None;
+        }
+
+        let pilot = (0..=u32::MAX).find(|&pilot| {
+            slots.clear();
+            slots.extend(hashes.iter().map(|&hash| slot(hash, pilot, len)));
+            slots.sort_unstable();
+
+            slots.windows(2).all(|pair| pair[0] != pair[1])
+                && slots.iter().all(|&slot| !taken[slot])
+        })?;
+
+        for &slot in &slots {
+            taken[slot] = true;
+        }
+        pilots[bucket] = pilot;
+    }
+
+    Some(pilots)
+}
+
+/// The bucket of a key whose hash is `hash`, out of `buckets`.
+fn bucket(hash: u64, buckets: usize) -> usize {
+    reduce(hash >> 32, buckets)
+}
+
+/// The slot of a key whose hash is `hash`, moved by `pilot`, out of `len`.
+fn slot(hash: u64, pilot: u32, len: usize) -> usize {
+    let moved = (hash ^ u64::from(pilot).wrapping_mul(PILOT_MULTIPLIER)).wrapping_mul(MULTIPLIER);
+
+    reduce(moved >> 32, len)
+}
+
+/// Maps `value`, below 2³², to `0..len` through a multiplication rather than a slower
+/// division.
+fn reduce(value: u64, len: usize) -> usize {
+    ((value * len as u64) >> 32) as usize
+}
+
+const MULTIPLIER: u64 = 0x517c_c1b7_2722_0a95;
+const PILOT_MULTIPLIER: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Hashes `key` with `seed`, reading it a word at a time.
+fn hash(key: &str, seed: u64) -> u64 {
+    let mut hash = seed ^ (key.len() as u64);
+    let mut chunks = key.as_bytes().chunks_exact(8);
+    for chunk in chunks.by_ref() {
+        let word = u64::from_le_bytes(chunk.try_into().expect("chunks are 8 bytes long"));
+        hash = (hash.rotate_left(5) ^ word).wrapping_mul(MULTIPLIER);
+    }
+
+    let rest = chunks
+        .remainder()
+        .iter()
+        .rev()
+        .fold(0, |word, &byte| word << 8 | u64::from(byte));
+    hash = (hash.rotate_left(5) ^ rest).wrapping_mul(MULTIPLIER);
+
+    mix(hash)
+}
+
+/// The finalizer of MurmurHash3, spreading every bit of `hash` over all the others.
+fn mix(mut hash: u64) -> u64 {
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PerfectHash;
+    use crate::{Error, FrozenTranslator, Translator};
+
+    #[test]
+    fn perfect_hash() {
+        for len in [0, 1, 2, 7, 100, 5000] {
+            let keys: Vec<_> = (0..len).map(|index| format!("key-{index}")).collect();
+            let hash = PerfectHash::new(keys.iter().map(String::as_str));
+
+            let mut slots: Vec<_> = keys.iter().map(|key| hash.slot(key)).collect();
+            slots.sort_unstable();
+            assert_eq!(slots, (0..len).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn frozen_translator() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]).with_delimiters("{", "}");
+
+        for index in 0..100 {
+            tr.add_text(
+                format!("key-{index}"),
+                ["NAME"],
+                [
+                    ("en", format!("Message {index} for {{NAME}}")),
+                    ("pt", format!("Mensagem {index} para {{NAME}}")),
+                ],
+            )?;
+        }
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!")])?;
+        tr.set_fallback_chain("pt", ["en"])?;
+
+        let expected: Vec<_> = (0..100)
+            .map(|index| tr.translate(&format!("key-{index}"), "pt", [("NAME", "Julian")]))
+            .collect::<Result<_, _>>()?;
+
+        let frozen = tr.freeze();
+        assert_eq!(frozen.len(), 101);
+        assert_eq!(frozen.keys().next(), Some("bye"));
+        for (index, expected) in expected.into_iter().enumerate() {
+            assert_eq!(
+                frozen.translate(&format!("key-{index}"), "pt", [("NAME", "Julian")])?,
+                expected
+            );
+        }
+        assert_eq!(frozen.translate("bye", "pt", [("", ""); 0])?, "Bye!");
+        assert!(frozen.contains_key("key-7"));
+        assert!(!frozen.contains_key("key-100"));
+        assert_eq!(
+            frozen.translate("greetings", "pt", [("", ""); 0]),
+            Err(Error::MissingKey("greetings".into()))
+        );
+        assert_eq!(
+            frozen.translate("bye", "cz", [("", ""); 0]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        let empty = Translator::new(["en"]).freeze();
+        assert!(empty.is_empty());
+        assert_eq!(
+            empty.translate("bye", "en", [("", ""); 0]),
+            Err(Error::MissingKey("bye".into()))
+        );
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<FrozenTranslator>();
+
+        Ok(())
+    }
+
+    #[test]
+    fn frozen_references_and_pseudolocale() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"])
+            .resolve_references(true)
+            .pseudolocalize(true);
+        tr.add_text("greeting", ["NAME"], [("en", "Hello, NAME")])?;
+        tr.add_text(
+            "welcome",
+            ["PLACE"],
+            [("en", "${greeting}, welcome to PLACE!")],
+        )?;
+        tr.add_text("broken", [""; 0], [("en", "${missing}")])?;
+
+        let args = [("NAME", "Ana"), ("PLACE", "Lisbon")];
+        let expected = [
+            tr.translate("welcome", "en", args)?,
+            tr.translate("welcome", "en-XA", args)?,
+        ];
+        assert_eq!(expected[0], "Hello, Ana, welcome to Lisbon!");
+
+        let frozen = tr.freeze();
+        assert_eq!(frozen.translate("welcome", "en", args)?, expected[0]);
+        assert_eq!(frozen.translate("welcome", "en-XA", args)?, expected[1]);
+        assert_eq!(
+            frozen.translate("broken", "en", [("", ""); 0]),
+            Err(Error::MissingKey("missing".into()))
+        );
+
+        Ok(())
+    }
+}
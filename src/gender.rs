@@ -0,0 +1,161 @@
+//! Messages agreeing with the grammatical gender of their arguments.
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+
+use crate::{Error, Translator};
+
+/// The grammatical gender of an argument value, see [`Translator::translate_gendered`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+impl Gender {
+    /// The marker of gendered sections meant for this gender, as in `{NAME:f}`.
+    fn marker(self) -> &'static str {
+        match self {
+            Gender::Masculine => "m",
+            Gender::Feminine => "f",
+            Gender::Neuter => "n",
+        }
+    }
+}
+
+impl Translator {
+    /// Like [`Translator::translate`], but each argument value carries a [`Gender`] the
+    /// message can agree with.
+    ///
+    /// Text between `{NAME:m}` and `{/NAME}` is only kept when `NAME` is masculine, and
+    /// likewise for `{NAME:f}` and `{NAME:n}`. Messages without such sections, or values
+    /// without a section for their gender, just get their arguments replaced.
+    ///
+    /// ```
+    /// use binja::{Gender, Translator};
+    ///
+    /// let mut tr = Translator::new(["fr"]);
+    /// tr.add_text("welcome", ["NAME"], [("fr", "Bienvenu{NAME:f}e{/NAME}, NAME !")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate_gendered("welcome", "fr", &[("NAME", "Marie", Gender::Feminine)])?,
+    ///     "Bienvenue, Marie !"
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn translate_gendered(
+        &self,
+        key: &str,
+        language: &str,
+        args: &[(&str, &str, Gender)],
+    ) -> Result<String, Error> {
+        let (translation, message, format) = self.lookup(key, language)?;
+
+        let mut message = Cow::Borrowed(message.as_str());
+        for &(name, _, gender) in args {
+            if let Cow::Owned(selected) = select_gendered(&message, name, gender)? {
+                message = Cow::Owned(selected);
+            }
+        }
+
+        let args = args.iter().map(|&(name, value, _)| (name, value));
+        translation
+            .replace(&self.argument_table, format, &message, args)
+            .map(Cow::into_owned)
+    }
+}
+
+/// Keeps the gendered sections of `name` in `message` meant for `gender`, dropping
+/// the others along with every section marker.
+fn select_gendered<'a>(
+    message: &'a str,
+    name: &str,
+    gender: Gender,
+) -> Result<Cow<'a, str>, Error> {
+    let opening = format!("{{{name}:");
+    let closing = format!("{{/{name}}}");
+
+    let mut selected = String::new();
+    let mut rest = message;
+
+    while let Some(start) = rest.find(&opening) {
+        let after_opening = &rest[start + opening.len()..];
+
+        let Some((marker, section)) = after_opening
+            .split_once('}')
+            .filter(|(marker, _)| ["m", "f", "n"].contains(marker))
+        else {
+            // Not a section marker, e.g. `{NAME:number}`
+            selected.push_str(&rest[..start + opening.len()]);
+            rest = after_opening;
+            continue;
+        };
+
+        let end = section
+            .find(&closing)
+            .ok_or_else(|| Error::Parse(format!("unterminated `{{{name}:{marker}}}` section")))?;
+
+        selected.push_str(&rest[..start]);
+        if marker == gender.marker() {
+            selected.push_str(&section[..end]);
+        }
+        rest = &section[end + closing.len()..];
+    }
+
+    if selected.is_empty() && rest.len() == message.len() {
+        return Ok(Cow::Borrowed(message));
+    }
+    selected.push_str(rest);
+
+    Ok(Cow::Owned(selected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Gender;
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_gendered() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "fr"]);
+        tr.add_text(
+            "welcome",
+            ["NAME"],
+            [
+                ("en", "Welcome, NAME!"),
+                (
+                    "fr",
+                    "{NAME:m}Cher{/NAME}{NAME:f}Chère{/NAME} NAME, soyez bienvenu{NAME:f}e{/NAME} !",
+                ),
+            ],
+        )?;
+
+        let welcome =
+            |lang, name, gender| tr.translate_gendered("welcome", lang, &[("NAME", name, gender)]);
+
+        assert_eq!(
+            welcome("fr", "Marie", Gender::Feminine)?,
+            "Chère Marie, soyez bienvenue !"
+        );
+        assert_eq!(
+            welcome("fr", "Jean", Gender::Masculine)?,
+            "Cher Jean, soyez bienvenu !"
+        );
+        // No gendered sections to pick from
+        assert_eq!(welcome("en", "Marie", Gender::Feminine)?, "Welcome, Marie!");
+
+        tr.add_text(
+            "broken",
+            ["NAME"],
+            [("en", "NAME"), ("fr", "{NAME:f}e NAME")],
+        )?;
+        assert!(matches!(
+            tr.translate_gendered("broken", "fr", &[("NAME", "Marie", Gender::Feminine)]),
+            Err(Error::Parse(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+//! Handles to languages, sparing the lookup of their code on every translation.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::pseudo::{Pseudo, PSEUDO_LOCALE};
+use crate::{Error, LanguageId, Translator};
+
+/// A language of a [`Translator`], as found by [`Translator::language_id`].
+///
+/// Handles go stale once languages are added or removed, after which using them
+/// fails with [`Error::StaleLanguage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Language {
+    id: LanguageId,
+    /// The [`Translator::language_generation`] this handle was made in.
+    generation: u32,
+    /// How the text of messages is altered, for the pseudolocale.
+    pseudo: Pseudo,
+}
+
+impl Translator {
+    /// Finds `code` among the known languages or their aliases, returning a handle
+    /// to translate into it through [`Translator::translate_by_id`].
+    pub fn language_id(&self, code: &str) -> Option<Language> {
+        self.find_target(code).ok().map(|(id, pseudo)| Language {
+            id,
+            generation: self.language_generation,
+            pseudo,
+        })
+    }
+
+    /// Like [`Translator::translate`], but into the language of a handle.
+    ///
+    /// Fails with [`Error::StaleLanguage`] if languages were added or removed since
+    /// `language` was made.
+    pub fn translate_by_id<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: Language,
+        args: I,
+    ) -> Result<String, Error> {
+        if language.generation != self.language_generation {
+            return Err(Error::StaleLanguage);
+        }
+
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+        let code = match language.pseudo {
+            Pseudo::Off => self.languages[language.id].as_str(),
+            _ => PSEUDO_LOCALE,
+        };
+
+        let message = self
+            .resolve_message(translation, language.id)
+            .ok_or_else(|| Error::UntranslatedForLanguage(key.into(), code.into()))?;
+
+        if self.has_references(message) {
+            let mut output = String::new();
+            self.translate_with_references(&mut output, key, code, args)?;
+            return Ok(output);
+        }
+
+        translation
+            .replace(
+                &self.argument_table,
+                self.value_format(language.id, language.pseudo),
+                message,
+                args,
+            )
+            .map(Cow::into_owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_by_id() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_alias("pt-BR", "pt")?;
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+        )?;
+        tr.add_text("bye", [""; 0], [("en", "Bye!"), ("pt", "Tchau!")])?;
+
+        let pt = tr.language_id("pt-BR").unwrap();
+        assert_eq!(tr.language_id("pt"), Some(pt));
+        assert_eq!(tr.language_id("cz"), None);
+
+        assert_eq!(
+            tr.translate_by_id("greetings", pt, [("NAME", "Julian")])?,
+            "Olá, Julian!"
+        );
+        assert_eq!(tr.translate_by_id("bye", pt, [("", ""); 0])?, "Tchau!");
+        assert_eq!(
+            tr.translate_by_id("farewell", pt, [("", ""); 0]),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        tr.remove_language("en")?;
+        assert_eq!(
+            tr.translate_by_id("bye", pt, [("", ""); 0]),
+            Err(Error::StaleLanguage)
+        );
+        let pt = tr.language_id("pt").unwrap();
+        assert_eq!(tr.translate_by_id("bye", pt, [("", ""); 0])?, "Tchau!");
+
+        Ok(())
+    }
+}
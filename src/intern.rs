@@ -0,0 +1,332 @@
+//! Sharing of argument names and messages across keys.
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::mem::{size_of, size_of_val};
+use core::ops::Deref;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::footprint::heap_size;
+use crate::{LanguageId, Map, Translator};
+
+/// Index of an argument in an [`ArgumentTable`].
+pub(crate) type ArgId = u32;
+
+/// Every distinct argument of a translator, see [`Translator::with_interned_arguments`].
+#[derive(Default)]
+pub(crate) struct ArgumentTable {
+    /// The name and placeholder of each argument, indexed by [`ArgId`].
+    entries: Vec<(SmallStr, SmallStr)>,
+    ids: Map<(SmallStr, SmallStr), ArgId>,
+}
+
+impl ArgumentTable {
+    fn intern(&mut self, name: SmallStr, placeholder: SmallStr) -> ArgId {
+        let entries = &mut self.entries;
+
+        *self
+            .ids
+            .entry((name, placeholder))
+            .or_insert_with_key(|entry| {
+                entries.push(entry.clone());
+                ArgId::try_from(entries.len() - 1).expect("too many distinct arguments")
+            })
+    }
+
+    /// Estimates the heap bytes held by this table, see [`Translator::memory_footprint`].
+    pub(crate) fn footprint(&self) -> usize {
+        let entries: usize = self
+            .entries
+            .iter()
+            .map(|(name, placeholder)| heap_size(name) + heap_size(placeholder))
+            .sum();
+
+        // Each entry is held twice, by `entries` and as a key of `ids`
+        2 * entries
+            + self.entries.capacity() * size_of::<(SmallStr, SmallStr)>()
+            + self.ids.len() * size_of::<((SmallStr, SmallStr), ArgId)>()
+    }
+}
+
+/// A message of a key in a given language.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Message {
+    Owned(SmallStr),
+    /// Shared with every identical message, see [`Translator::with_message_interning`].
+    Interned(Arc<str>),
+}
+
+impl Message {
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            Message::Owned(message) => message,
+            Message::Interned(message) => message,
+        }
+    }
+
+    /// Estimates the heap bytes held by this message alone, leaving out interned ones,
+    /// which are accounted for by the [`MessageTable`].
+    pub(crate) fn footprint(&self) -> usize {
+        match self {
+            Message::Owned(message) => heap_size(message),
+            Message::Interned(_) => 0,
+        }
+    }
+}
+
+impl Deref for Message {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<SmallStr> for Message {
+    fn from(message: SmallStr) -> Self {
+        Message::Owned(message)
+    }
+}
+
+/// Every distinct message of a translator, see [`Translator::with_message_interning`].
+#[derive(Default)]
+pub(crate) struct MessageTable {
+    messages: BTreeSet<Arc<str>>,
+}
+
+impl MessageTable {
+    fn intern(&mut self, message: &str) -> Arc<str> {
+        match self.messages.get(message) {
+            Some(interned) => interned.clone(),
+            None => {
+                let interned: Arc<str> = message.into();
+                self.messages.insert(interned.clone());
+                interned
+            }
+        }
+    }
+
+    /// Estimates the heap bytes held by this table, see [`Translator::memory_footprint`].
+    pub(crate) fn footprint(&self) -> usize {
+        self.messages
+            .iter()
+            .map(|message| size_of::<Arc<str>>() + 2 * size_of::<usize>() + message.len())
+            .sum()
+    }
+}
+
+/// The arguments of a single key.
+pub(crate) enum Arguments {
+    Owned {
+        names: Box<[SmallStr]>,
+        /// How each argument is spelled in messages, i.e. wrapped in the translator's delimiters.
+        placeholders: Box<[SmallStr]>,
+    },
+    /// Indices into the translator's [`ArgumentTable`].
+    Interned(Box<[ArgId]>),
+}
+
+impl Arguments {
+    /// Estimates the heap bytes held by these arguments, leaving out interned ones,
+    /// which are accounted for by the [`ArgumentTable`].
+    pub(crate) fn footprint(&self) -> usize {
+        match self {
+            Arguments::Owned {
+                names,
+                placeholders,
+            } => names
+                .iter()
+                .chain(placeholders.iter())
+                .map(|argument| size_of::<SmallStr>() + heap_size(argument))
+                .sum(),
+            Arguments::Interned(ids) => size_of_val(&**ids),
+        }
+    }
+
+    /// Pairs these arguments with the table of the translator holding them.
+    pub(crate) fn resolve<'a>(&'a self, table: &'a ArgumentTable) -> ResolvedArguments<'a> {
+        ResolvedArguments {
+            arguments: self,
+            table,
+        }
+    }
+}
+
+/// [`Arguments`] along with the table needed to read interned ones.
+#[derive(Clone, Copy)]
+pub(crate) struct ResolvedArguments<'a> {
+    arguments: &'a Arguments,
+    table: &'a ArgumentTable,
+}
+
+impl<'a> ResolvedArguments<'a> {
+    pub(crate) fn len(self) -> usize {
+        match self.arguments {
+            Arguments::Owned { names, .. } => names.len(),
+            Arguments::Interned(ids) => ids.len(),
+        }
+    }
+
+    pub(crate) fn name(self, index: usize) -> &'a SmallStr {
+        match self.arguments {
+            Arguments::Owned { names, .. } => &names[index],
+            Arguments::Interned(ids) => &self.table.entries[ids[index] as usize].0,
+        }
+    }
+
+    pub(crate) fn placeholder(self, index: usize) -> &'a SmallStr {
+        match self.arguments {
+            Arguments::Owned { placeholders, .. } => &placeholders[index],
+            Arguments::Interned(ids) => &self.table.entries[ids[index] as usize].1,
+        }
+    }
+
+    pub(crate) fn names(self) -> impl Iterator<Item = &'a SmallStr> {
+        (0..self.len()).map(move |index| self.name(index))
+    }
+
+    pub(crate) fn placeholders(self) -> impl Iterator<Item = &'a SmallStr> {
+        (0..self.len()).map(move |index| self.placeholder(index))
+    }
+
+    pub(crate) fn to_owned(self) -> Arguments {
+        Arguments::Owned {
+            names: self.names().cloned().collect(),
+            placeholders: self.placeholders().cloned().collect(),
+        }
+    }
+}
+
+impl Translator {
+    /// Stores argument names in a table shared by every key, so that arguments
+    /// repeated across many keys, such as `NAME` or `COUNT`, are only stored once.
+    ///
+    /// Only applies to messages added afterwards.
+    pub fn with_interned_arguments(mut self) -> Self {
+        self.intern_arguments = true;
+        self
+    }
+
+    /// Stores messages in a table shared by every key, so that identical messages,
+    /// such as `OK` in every language, are only stored once.
+    ///
+    /// Only applies to messages added afterwards.
+    pub fn with_message_interning(mut self) -> Self {
+        self.intern_messages = true;
+        self
+    }
+
+    /// Interns the messages of a freshly built translation into this translator's table,
+    /// if interning is enabled.
+    pub(crate) fn store_messages(&mut self, translations: &mut Map<LanguageId, Message>) {
+        if !self.intern_messages {
+            return;
+        }
+
+        for message in translations.values_mut() {
+            *message = Message::Interned(self.message_table.intern(message));
+        }
+    }
+
+    /// Interns freshly built `arguments` into this translator's table, if interning is enabled.
+    pub(crate) fn store_arguments(&mut self, arguments: Arguments) -> Arguments {
+        match arguments {
+            Arguments::Owned {
+                names,
+                placeholders,
+            } if self.intern_arguments => {
+                let ids = names
+                    .into_vec()
+                    .into_iter()
+                    .zip(placeholders.into_vec())
+                    .map(|(name, placeholder)| self.argument_table.intern(name, placeholder))
+                    .collect();
+
+                Arguments::Interned(ids)
+            }
+            arguments => arguments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn interned_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]).with_interned_arguments();
+
+        for index in 0..1000 {
+            tr.add_text(
+                format!("key-{index}"),
+                ["NAME"],
+                [
+                    ("en", format!("Message {index} for NAME")),
+                    ("pt", format!("Mensagem {index} para NAME")),
+                ],
+            )?;
+        }
+        assert_eq!(tr.argument_table.entries.len(), 1);
+
+        assert_eq!(
+            tr.translate("key-42", "pt", [("NAME", "Julian")])?,
+            "Mensagem 42 para Julian"
+        );
+        assert_eq!(
+            tr.translate("key-42", "pt", [("NOME", "Julian")]),
+            Err(Error::UnknownArgument("NOME".into()))
+        );
+        assert_eq!(tr.arguments_for("key-7")?.collect::<Vec<_>>(), ["NAME"]);
+
+        tr.add_text(
+            "welcome",
+            ["PLACE", "NAME"],
+            [("en", "PLACE"), ("pt", "PLACE")],
+        )?;
+        assert_eq!(tr.argument_table.entries.len(), 2);
+
+        // Not shared unless enabled
+        let mut other = Translator::new(["en", "pt"]);
+        other.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi NAME"), ("pt", "Oi NAME")],
+        )?;
+        assert_eq!(other.argument_table.entries.len(), 0);
+
+        // Merging interned arguments into a translator with a different table
+        other.merge(tr)?;
+        assert_eq!(
+            other.translate("welcome", "en", [("PLACE", "Lisbon")])?,
+            "Lisbon"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn interned_messages() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]).with_message_interning();
+
+        tr.add_text("ok", [""; 0], [("en", "OK"), ("pt", "OK")])?;
+        tr.add_text("confirm", [""; 0], [("en", "OK"), ("pt", "OK")])?;
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+        )?;
+        assert_eq!(tr.message_table.messages.len(), 3);
+
+        assert_eq!(tr.translate("confirm", "pt", [("", ""); 0])?, "OK");
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
+            "Olá, Julian!"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,776 @@
+//! Loading and writing translation catalogs as JSON, through `serde_json`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use compact_str::CompactString as SmallStr;
+use serde_json::{Map as Object, Value};
+
+use crate::placeholder::placeholders;
+use crate::{ensure_same_arguments, Error, Map, PluralCategory, Translation, Translator};
+
+/// Parses a complete JSON document.
+///
+/// Documents nesting 128 or more arrays or objects fail with [`Error::Parse`]
+/// instead of overflowing the stack.
+pub(crate) fn parse(input: &str) -> Result<Value, Error> {
+    serde_json::from_str(input).map_err(|err| Error::Parse(err.to_string()))
+}
+
+pub(crate) fn as_str<'a>(value: &'a Value, context: &str) -> Result<&'a str, Error> {
+    value
+        .as_str()
+        .ok_or_else(|| unexpected(context, "a string", value))
+}
+
+pub(crate) fn as_array<'a>(value: &'a Value, context: &str) -> Result<&'a [Value], Error> {
+    match value {
+        Value::Array(values) => Ok(values),
+        other => Err(unexpected(context, "an array", other)),
+    }
+}
+
+pub(crate) fn as_object<'a>(
+    value: &'a Value,
+    context: &str,
+) -> Result<&'a Object<String, Value>, Error> {
+    value
+        .as_object()
+        .ok_or_else(|| unexpected(context, "an object", value))
+}
+
+fn as_u64(value: &Value, context: &str) -> Result<u64, Error> {
+    match value {
+        Value::Number(number) => number.as_u64().ok_or_else(|| {
+            Error::Parse(format!(
+                "expected {context} to be a whole number, found {number}"
+            ))
+        }),
+        other => Err(unexpected(context, "a number", other)),
+    }
+}
+
+fn unexpected(context: &str, expected: &str, found: &Value) -> Error {
+    let kind = match found {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    };
+
+    Error::Parse(format!("expected {context} to be {expected}, found {kind}"))
+}
+
+impl Translator {
+    /// Builds a translator from a JSON document shaped like
+    ///
+    /// ```json
+    /// {
+    ///     "languages": ["en", "pt"],
+    ///     "keys": {
+    ///         "greetings": {
+    ///             "arguments": ["NAME"],
+    ///             "translations": { "en": "Hello, NAME!", "pt": "Olá, NAME!" }
+    ///         }
+    ///     },
+    ///     "plurals": {
+    ///         "items": {
+    ///             "arguments": ["COUNT"],
+    ///             "translations": {
+    ///                 "en": { "one": "COUNT item", "other": "COUNT items" },
+    ///                 "pt": { "one": "COUNT item", "other": "COUNT itens" }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Every key goes through [`Translator::add_text`], so the usual validation applies,
+    /// or through [`Translator::add_text_partial`] if it is marked `"partial": true`.
+    /// `arguments` may be omitted for keys that take none.
+    ///
+    /// The optional `plurals`, `selects` and `ranged` sections go through
+    /// [`Translator::add_plural`], [`Translator::add_select`] and [`Translator::add_ranged`].
+    /// Plural messages are keyed by [category](PluralCategory::as_str), select messages by
+    /// variant, and ranged messages are lists of `{ "from": 1, "to": 3, "message": "..." }`.
+    pub fn from_json(json: &str) -> Result<Translator, Error> {
+        let document = parse(json)?;
+
+        let languages = document
+            .get("languages")
+            .ok_or_else(|| Error::Parse("missing `languages` field".into()))?;
+        let languages = as_array(languages, "`languages`")?
+            .iter()
+            .map(|language| as_str(language, "language"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut translator = Translator::new(languages);
+
+        for (key, entry) in section(&document, "keys")? {
+            let context = format!("key `{key}`");
+            let arguments = arguments(entry, &context)?;
+            let translations = translations(entry, &context, |message| as_str(message, &context))?;
+
+            match entry.get("partial") {
+                Some(Value::Bool(true)) => {
+                    translator.add_text_partial(key.clone(), arguments, translations)?
+                }
+                _ => translator.add_text(key.clone(), arguments, translations)?,
+            }
+        }
+
+        for (key, entry) in section(&document, "plurals")? {
+            let context = format!("plural `{key}`");
+            let arguments = arguments(entry, &context)?;
+            let translations = translations(entry, &context, |forms| {
+                as_object(forms, &context)?
+                    .iter()
+                    .map(|(name, message)| {
+                        let category = PLURAL_CATEGORIES
+                            .into_iter()
+                            .find(|category| category.as_str() == name)
+                            .ok_or_else(|| {
+                                Error::Parse(format!(
+                                    "unknown plural category `{name}` in {context}"
+                                ))
+                            })?;
+
+                        Ok((category, as_str(message, &context)?))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            })?;
+
+            translator.add_plural(key.clone(), arguments, translations)?;
+        }
+
+        for (key, entry) in section(&document, "selects")? {
+            let context = format!("select `{key}`");
+            let arguments = arguments(entry, &context)?;
+            let translations = translations(entry, &context, |variants| {
+                as_object(variants, &context)?
+                    .iter()
+                    .map(|(variant, message)| Ok((variant.as_str(), as_str(message, &context)?)))
+                    .collect::<Result<Vec<_>, Error>>()
+            })?;
+
+            translator.add_select(key.clone(), arguments, translations)?;
+        }
+
+        for (key, entry) in section(&document, "ranged")? {
+            let context = format!("ranged `{key}`");
+            let arguments = arguments(entry, &context)?;
+            let translations = translations(entry, &context, |ranges| {
+                as_array(ranges, &context)?
+                    .iter()
+                    .map(|range| {
+                        let field = |name: &str| {
+                            range.get(name).ok_or_else(|| {
+                                Error::Parse(format!("missing `{name}` in {context}"))
+                            })
+                        };
+                        let from = as_u64(field("from")?, &context)?;
+                        let to = as_u64(field("to")?, &context)?;
+
+                        Ok((from..=to, as_str(field("message")?, &context)?))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            })?;
+
+            translator.add_ranged(key.clone(), arguments, translations)?;
+        }
+
+        Ok(translator)
+    }
+
+    /// Builds a translator out of a directory of per-language JSON files, such as
+    /// `en.json` and `pt.json`, each mapping keys to messages.
+    ///
+    /// File stems are taken as language codes. Every `{NAME}`-style token found in
+    /// a key's messages becomes one of its arguments, spelled in full (i.e. `{NAME}`).
+    /// Every key must be present in every file, otherwise [`Error::UntranslatedForLanguage`]
+    /// names the first key and language missing, and its messages must all use the same
+    /// arguments, otherwise [`Error::ArgumentMismatch`] names the first language that doesn't.
+    pub fn load_dir(path: &Path) -> Result<Translator, Error> {
+        let io_error = |err: std::io::Error| Error::Io(format!("{}: {err}", path.display()));
+
+        // Language -> key -> message, sorted so that errors are deterministic
+        let mut catalogs: BTreeMap<SmallStr, BTreeMap<SmallStr, SmallStr>> = BTreeMap::new();
+
+        for entry in std::fs::read_dir(path).map_err(io_error)? {
+            let file_path = entry.map_err(io_error)?.path();
+
+            if file_path.extension() != Some("json".as_ref()) || !file_path.is_file() {
+                continue;
+            }
+
+            let language = file_path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or_else(|| {
+                    Error::Parse(format!("invalid file name `{}`", file_path.display()))
+                })?;
+
+            let contents = std::fs::read_to_string(&file_path)
+                .map_err(|err| Error::Io(format!("{}: {err}", file_path.display())))?;
+
+            let document = parse(&contents)?;
+            let messages = as_object(&document, &format!("`{}`", file_path.display()))?
+                .iter()
+                .map(|(key, message)| {
+                    Ok((key.into(), as_str(message, &format!("key `{key}`"))?.into()))
+                })
+                .collect::<Result<_, Error>>()?;
+
+            catalogs.insert(language.into(), messages);
+        }
+
+        let mut translator = Translator::new(catalogs.keys().cloned());
+
+        let mut keys: Vec<&SmallStr> = catalogs.values().flat_map(BTreeMap::keys).collect();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            let translations = catalogs
+                .iter()
+                .map(|(language, messages)| {
+                    let message = messages.get(key).ok_or_else(|| {
+                        Error::UntranslatedForLanguage(key.clone(), language.clone())
+                    })?;
+
+                    Ok((language.clone(), message.clone()))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            ensure_same_arguments(
+                key,
+                translations
+                    .iter()
+                    .map(|(language, message)| (language.as_str(), message.as_str())),
+                |message| placeholders(message, "{", "}").collect(),
+            )?;
+
+            let mut arguments: Vec<SmallStr> = translations
+                .iter()
+                .flat_map(|(_, message)| placeholders(message, "{", "}"))
+                .map(Into::into)
+                .collect();
+            arguments.sort();
+            arguments.dedup();
+
+            translator.add_text(key.clone(), arguments, translations)?;
+        }
+
+        Ok(translator)
+    }
+
+    /// Serializes this translator into the document shape read by [`Translator::from_json`].
+    ///
+    /// Languages are written in sorted order and keys in lexicographic order, or by the
+    /// [collation](Translator::with_collation) if one is set, so the output is stable
+    /// across runs. Keys lacking a message for some language are marked `"partial": true`,
+    /// and the `plurals`, `selects` and `ranged` sections are only written when not empty.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{\n    \"languages\": [");
+        for (index, language) in self.languages.iter().enumerate() {
+            if index > 0 {
+                json.push_str(", ");
+            }
+            write_string(&mut json, language);
+        }
+        json.push(']');
+
+        self.write_section(&mut json, "keys", &self.translations, |json, message| {
+            write_string(json, message)
+        });
+
+        if !self.plurals.is_empty() {
+            self.write_section(&mut json, "plurals", &self.plurals, |json, forms| {
+                let mut forms: Vec<_> = forms.iter().collect();
+                forms.sort_unstable();
+
+                json.push('{');
+                for (index, (category, message)) in forms.into_iter().enumerate() {
+                    json.push_str(if index > 0 { ", " } else { " " });
+                    write_string(json, category.as_str());
+                    json.push_str(": ");
+                    write_string(json, message);
+                }
+                json.push_str(" }");
+            });
+        }
+
+        if !self.selects.is_empty() {
+            self.write_section(&mut json, "selects", &self.selects, |json, variants| {
+                let mut variants: Vec<_> = variants.iter().collect();
+                variants.sort_unstable();
+
+                json.push('{');
+                for (index, (variant, message)) in variants.into_iter().enumerate() {
+                    json.push_str(if index > 0 { ", " } else { " " });
+                    write_string(json, variant);
+                    json.push_str(": ");
+                    write_string(json, message);
+                }
+                json.push_str(" }");
+            });
+        }
+
+        if !self.ranged.is_empty() {
+            self.write_section(&mut json, "ranged", &self.ranged, |json, ranges| {
+                json.push('[');
+                for (index, (range, message)) in ranges.iter().enumerate() {
+                    if index > 0 {
+                        json.push_str(", ");
+                    }
+                    json.push_str(&format!(
+                        "{{ \"from\": {}, \"to\": {}, \"message\": ",
+                        range.start(),
+                        range.end()
+                    ));
+                    write_string(json, message);
+                    json.push_str(" }");
+                }
+                json.push(']');
+            });
+        }
+
+        json.push_str("\n}\n");
+        json
+    }
+
+    /// Appends the `name` section of [`Translator::to_json`], writing the messages of
+    /// each language through `write_message`.
+    fn write_section<M>(
+        &self,
+        json: &mut String,
+        name: &str,
+        entries: &Map<SmallStr, Translation<M>>,
+        write_message: impl Fn(&mut String, &M),
+    ) {
+        json.push_str(",\n    ");
+        write_string(json, name);
+        json.push_str(": {");
+
+        let mut keys: Vec<_> = entries.iter().collect();
+        self.sort_keys(&mut keys);
+
+        for (index, (key, translation)) in keys.into_iter().enumerate() {
+            json.push_str(if index > 0 {
+                ",\n        "
+            } else {
+                "\n        "
+            });
+            write_string(json, key);
+            json.push_str(": {\n            \"arguments\": [");
+
+            let declared = translation.declared_arguments(&self.argument_table);
+            for (position, argument) in declared.iter().enumerate() {
+                if position > 0 {
+                    json.push_str(", ");
+                }
+                write_string(json, argument);
+            }
+            json.push(']');
+
+            if translation.translations.len() < self.languages.len() {
+                json.push_str(",\n            \"partial\": true");
+            }
+            json.push_str(",\n            \"translations\": {");
+
+            let mut first = true;
+            for (language_id, language) in self.languages.iter().enumerate() {
+                let Some(message) = translation.translations.get(&language_id) else {
+                    continue;
+                };
+
+                json.push_str(if first {
+                    "\n                "
+                } else {
+                    ",\n                "
+                });
+                first = false;
+                write_string(json, language);
+                json.push_str(": ");
+                write_message(json, message);
+            }
+            json.push_str("\n            }\n        }");
+        }
+
+        json.push_str("\n    }");
+    }
+}
+
+/// Every plural category, to look them up by name.
+const PLURAL_CATEGORIES: [PluralCategory; 6] = [
+    PluralCategory::Zero,
+    PluralCategory::One,
+    PluralCategory::Two,
+    PluralCategory::Few,
+    PluralCategory::Many,
+    PluralCategory::Other,
+];
+
+/// The entries of the `name` section of `document`, if any.
+fn section<'a>(
+    document: &'a Value,
+    name: &str,
+) -> Result<impl Iterator<Item = (&'a String, &'a Value)>, Error> {
+    let entries = match document.get(name) {
+        Some(section) => Some(as_object(section, &format!("`{name}`"))?),
+        None => None,
+    };
+
+    Ok(entries.into_iter().flatten())
+}
+
+/// The `arguments` of a key, which may be omitted if it takes none.
+fn arguments<'a>(entry: &'a Value, context: &str) -> Result<Vec<&'a str>, Error> {
+    match entry.get("arguments") {
+        Some(arguments) => as_array(arguments, context)?
+            .iter()
+            .map(|argument| as_str(argument, context))
+            .collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// The `translations` of a key, reading the messages of each language through `message`.
+fn translations<'a, M>(
+    entry: &'a Value,
+    context: &str,
+    message: impl Fn(&'a Value) -> Result<M, Error>,
+) -> Result<Vec<(&'a str, M)>, Error> {
+    let translations = entry
+        .get("translations")
+        .ok_or_else(|| Error::Parse(format!("missing `translations` for {context}")))?;
+
+    as_object(translations, context)?
+        .iter()
+        .map(|(language, value)| Ok((language.as_str(), message(value)?)))
+        .collect()
+}
+
+/// Appends `string` to `json` as a quoted JSON string.
+fn write_string(json: &mut String, string: &str) {
+    json.push_str(&serde_json::to_string(string).expect("strings always serialize"));
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::parse;
+    use crate::{Error, PluralCategory, Translator};
+
+    #[test]
+    fn parses_values() -> Result<(), Error> {
+        assert_eq!(
+            parse(r#" { "a": [1, -2.5e3, true, false, null], "b": "x\"é😀\n" } "#)?,
+            json!({ "a": [1, -2.5e3, true, false, null], "b": "x\"é😀\n" })
+        );
+        // Only characters below U+0020 must be escaped
+        assert_eq!(
+            parse("\"\u{7f}\u{85}\u{9f}\"")?,
+            json!("\u{7f}\u{85}\u{9f}")
+        );
+
+        let invalid = [
+            "[1, 2",
+            r#"{"a" 1}"#,
+            r#""\ud83d""#,
+            "{} {}",
+            "01",
+            "1.",
+            r#""\u+123""#,
+        ];
+        for document in invalid {
+            assert!(
+                matches!(parse(document), Err(Error::Parse(_))),
+                "{document}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn deeply_nested() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse(&nested(127)).is_ok());
+        assert!(matches!(parse(&nested(128)), Err(Error::Parse(_))));
+
+        // Would overflow the stack without a limit
+        assert!(matches!(
+            Translator::from_json(&"[".repeat(200_000)),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            Translator::from_json(&r#"{"a":"#.repeat(200_000)),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn from_json() -> Result<(), Error> {
+        let tr = Translator::from_json(
+            r#"{
+                "languages": ["en", "pt"],
+                "keys": {
+                    "greetings": {
+                        "arguments": ["NAME"],
+                        "translations": { "en": "Good morning, NAME!", "pt": "Bom dia, NAME!" }
+                    },
+                    "farewell": {
+                        "translations": { "en": "Goodbye!", "pt": "Tchau!" }
+                    }
+                }
+            }"#,
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(tr.translate("farewell", "en", [("", ""); 0])?, "Goodbye!");
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_json_missing_language() {
+        let result = Translator::from_json(
+            r#"{
+                "languages": ["en", "pt"],
+                "keys": {
+                    "greetings": {
+                        "arguments": ["NAME"],
+                        "translations": { "en": "Good morning, NAME!" }
+                    }
+                }
+            }"#,
+        );
+
+        assert_eq!(
+            result.err(),
+            Some(Error::MissingLanguages(vec!["pt".into()]))
+        );
+
+        assert!(matches!(
+            Translator::from_json(r#"{ "languages": "en" }"#),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    struct TempDir(std::path::PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("binja-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn write(&self, file: &str, contents: &str) {
+            std::fs::write(self.0.join(file), contents).unwrap();
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn load_dir() -> Result<(), Error> {
+        let dir = TempDir::new("load-dir");
+        dir.write(
+            "en.json",
+            r#"{ "greetings": "Good morning, {NAME}!", "bye": "Bye!" }"#,
+        );
+        dir.write(
+            "pt.json",
+            r#"{ "greetings": "Bom dia, {NAME}!", "bye": "Tchau!" }"#,
+        );
+        dir.write("README.md", "Not a catalog");
+
+        let tr = Translator::load_dir(&dir.0)?;
+
+        assert_eq!(tr.languages().collect::<Vec<_>>(), ["en", "pt"]);
+        assert_eq!(
+            tr.translate("greetings", "pt", [("{NAME}", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(tr.translate("bye", "en", [("", ""); 0])?, "Bye!");
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_dir_missing_key() {
+        let dir = TempDir::new("load-dir-missing-key");
+        dir.write(
+            "en.json",
+            r#"{ "greetings": "Good morning!", "bye": "Bye!" }"#,
+        );
+        dir.write("pt.json", r#"{ "greetings": "Bom dia!" }"#);
+
+        let error = Translator::load_dir(&dir.0).err();
+        assert_eq!(
+            error,
+            Some(Error::UntranslatedForLanguage("bye".into(), "pt".into()))
+        );
+        assert_eq!(
+            error.unwrap().to_string(),
+            "Key `bye` has no translation for language `pt`"
+        );
+    }
+
+    #[test]
+    fn load_dir_argument_mismatch() {
+        let dir = TempDir::new("load-dir-argument-mismatch");
+        dir.write("en.json", r#"{ "greetings": "Good morning, {NAME}!" }"#);
+        dir.write("pt.json", r#"{ "greetings": "Bom dia, {NOME}!" }"#);
+
+        assert_eq!(
+            Translator::load_dir(&dir.0).err(),
+            Some(Error::ArgumentMismatch {
+                key: "greetings".into(),
+                language: "pt".into()
+            })
+        );
+    }
+
+    #[test]
+    fn json_round_trip() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "PLACE", "TITLE?"],
+            [
+                ("en", "Good morning, TITLENAME!\n\tWelcome to \"PLACE\"."),
+                ("pt", "Bom dia, NAME!\n\tBem-vindo a \"PLACE\"."),
+            ],
+        )?;
+        tr.add_text("bye", [""; 0], [("en", "Bye\\"), ("pt", "Tchau\u{1}")])?;
+
+        let json = tr.to_json();
+        let restored = Translator::from_json(&json)?;
+
+        assert_eq!(restored.to_json(), json);
+        assert_eq!(restored.languages().collect::<Vec<_>>(), ["en", "pt"]);
+        for lang in ["en", "pt"] {
+            assert_eq!(
+                restored.translate("greetings", lang, [("NAME", "Julian"), ("PLACE", "Lisbon")])?,
+                tr.translate("greetings", lang, [("NAME", "Julian"), ("PLACE", "Lisbon")])?
+            );
+            assert_eq!(
+                restored.translate("bye", lang, [("", ""); 0])?,
+                tr.translate("bye", lang, [("", ""); 0])?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_round_trip_other_kinds() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_text_partial("colour", ["NAME"], [("en", "NAME's colour")])?;
+        tr.add_plural(
+            "items",
+            ["COUNT"],
+            [
+                (
+                    "en",
+                    [
+                        (PluralCategory::One, "COUNT item"),
+                        (PluralCategory::Other, "COUNT items"),
+                    ],
+                ),
+                (
+                    "pt",
+                    [
+                        (PluralCategory::One, "COUNT item"),
+                        (PluralCategory::Other, "COUNT itens"),
+                    ],
+                ),
+            ],
+        )?;
+        tr.add_select(
+            "invite",
+            ["NAME"],
+            [
+                (
+                    "en",
+                    [
+                        ("she", "NAME invited you to her party"),
+                        ("other", "NAME invited you"),
+                    ],
+                ),
+                (
+                    "pt",
+                    [
+                        ("she", "NAME convidou-te para a festa dela"),
+                        ("other", "NAME convidou-te"),
+                    ],
+                ),
+            ],
+        )?;
+        tr.add_ranged(
+            "apples",
+            ["COUNT"],
+            [
+                (
+                    "en",
+                    [(1..=4, "A few apples"), (5..=u64::MAX, "COUNT apples")],
+                ),
+                (
+                    "pt",
+                    [(1..=4, "Algumas maçãs"), (5..=u64::MAX, "COUNT maçãs")],
+                ),
+            ],
+        )?;
+
+        let json = tr.to_json();
+        assert!(json.contains("\"partial\": true"));
+
+        let restored = Translator::from_json(&json)?;
+        assert_eq!(restored.to_json(), json);
+
+        assert_eq!(
+            restored.translate("colour", "en", [("NAME", "Ana")])?,
+            "Ana's colour"
+        );
+        assert_eq!(
+            restored.translate("colour", "pt", [("NAME", "Ana")]),
+            Err(Error::UntranslatedForLanguage("colour".into(), "pt".into()))
+        );
+        for lang in ["en", "pt"] {
+            for count in [1, 2] {
+                assert_eq!(
+                    restored.translate_plural("items", lang, count, [("COUNT", "2")])?,
+                    tr.translate_plural("items", lang, count, [("COUNT", "2")])?
+                );
+            }
+            for variant in ["she", "they"] {
+                assert_eq!(
+                    restored.translate_select("invite", lang, variant, [("NAME", "Ana")])?,
+                    tr.translate_select("invite", lang, variant, [("NAME", "Ana")])?
+                );
+            }
+            for count in [3, 12] {
+                assert_eq!(
+                    restored.translate_ranged("apples", lang, count, [("COUNT", "12")])?,
+                    tr.translate_ranged("apples", lang, count, [("COUNT", "12")])?
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,30 +1,391 @@
-use std::collections::HashMap;
+//! # Features
+//!
+//! - `std` (default): without it, the crate only needs `alloc`, so that it can be used in
+//!   `no_std` targets. Keys are then kept in a `BTreeMap`, and [`Translator::translate_map`]
+//!   is unavailable.
+//! - `json`, `gettext` and `rayon`: loading catalogs from files and building
+//!   translators across threads through `rayon`. All of them require `std`.
+//! - `android`: loading Android `strings.xml` resources through
+//!   [`Translator::load_android_xml`].
+//! - `apple`: loading Apple `.strings` files through [`Translator::load_apple_strings`].
+//! - `arb`: loading Flutter `.arb` files through [`Translator::load_arb`].
+//! - `codegen`: generating an enum of the keys of a JSON catalog from build scripts,
+//!   see [`codegen::emit_keys`].
+//! - `hot_reload`: [`ReloadableTranslator`], reloading a JSON catalog whenever it changes.
+//! - `xliff`: exchanging translations as XLIFF 1.2 documents through
+//!   [`Translator::load_xliff`] and [`Translator::to_xliff`].
+//! - `encoding`: loading files written in encodings other than UTF-8, such as Latin-1
+//!   or UTF-16, through [`decode`] and the `_encoded` variants of the loaders.
+//! - `archive`: a compact binary catalog format, read in place through [`ArchivedCatalog`]
+//!   and loaded through [`Translator::from_archive`].
+//! - `collation`: sorting exported keys by the collation of a language through
+//!   [`Translator::with_collation`].
+//! - `serde`: `Serialize` and `Deserialize` for [`Translator`], re-validating every key
+//!   when deserializing.
+//! - `tracing`: `tracing` events when translating, for key lookups, fallback
+//!   languages and arguments given but unused, each with `key` and `lang` fields.
+//! - `test-util`: `PartialEq` for [`Translator`], comparing the contents of two translators,
+//!   e.g. a loaded catalog against an expected one.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
 
 use aho_corasick::AhoCorasick;
-use compact_str::CompactString as SmallStr;
+use case::{Case, CASE_ANNOTATIONS};
+use compact_str::{format_compact, CompactString as SmallStr};
+use intern::{ArgumentTable, Arguments, Message, MessageTable};
+use list::LIST_ANNOTATION;
+use number::NUMBER_ANNOTATION;
+use pseudo::Pseudo;
+use reference::FindText;
+
+#[cfg(feature = "android")]
+mod android;
+#[cfg(feature = "apple")]
+mod apple;
+#[cfg(feature = "arb")]
+mod arb;
+#[cfg(feature = "archive")]
+mod archive;
+mod batch;
+mod bidi;
+mod builder;
+mod case;
+#[cfg(any(
+    feature = "gettext",
+    feature = "android",
+    feature = "apple",
+    feature = "arb"
+))]
+mod catalog;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "collation")]
+mod collation;
+mod coverage;
+mod diff;
+#[cfg(feature = "encoding")]
+mod encoding;
+#[cfg(any(test, feature = "test-util"))]
+mod equality;
+mod footprint;
+mod formatter;
+mod frozen;
+mod gender;
+mod handle;
+mod intern;
+#[cfg(feature = "json")]
+mod json;
+mod lint;
+mod list;
+mod macros;
+mod merge;
+mod namespace;
+mod negotiate;
+mod number;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(any(feature = "json", feature = "gettext"))]
+mod placeholder;
+mod plural;
+#[cfg(feature = "gettext")]
+mod po;
+#[cfg(any(feature = "android", feature = "apple"))]
+mod printf;
+mod properties;
+mod pseudo;
+mod ranged;
+mod reference;
+#[cfg(feature = "hot_reload")]
+mod reload;
+mod segment;
+mod select;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shared;
+mod snapshot;
+#[cfg(feature = "std")]
+mod stream;
+mod subset;
+#[cfg(feature = "tracing")]
+mod trace;
+mod validate;
+mod writer;
+#[cfg(feature = "xliff")]
+mod xliff;
+#[cfg(any(feature = "android", feature = "xliff"))]
+mod xml;
+
+pub use aho_corasick::MatchKind;
+#[cfg(feature = "archive")]
+pub use archive::ArchivedCatalog;
+pub use builder::TranslatorBuilder;
+pub use coverage::Coverage;
+pub use diff::Diff;
+#[cfg(feature = "encoding")]
+pub use encoding::decode;
+#[cfg(feature = "encoding")]
+pub use encoding_rs;
+pub use formatter::ArgumentFormatter;
+pub use frozen::FrozenTranslator;
+pub use gender::Gender;
+pub use handle::Language;
+pub use lint::{Lint, LintKind};
+pub use list::ListStyle;
+pub use merge::OnConflict;
+#[cfg(feature = "rayon")]
+pub use parallel::Entry;
+pub use plural::PluralCategory;
+pub use ranged::{RangeIssue, RangeIssueKind};
+#[cfg(feature = "hot_reload")]
+pub use reload::ReloadableTranslator;
+pub use segment::Segment;
+pub use shared::SharedTranslator;
+pub use writer::MessageWriter;
+
+/// Items used by [`translate!`], which can't assume `std` is available.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::string::{String, ToString};
+}
 
 pub type LanguageId = usize;
 
+/// The map used throughout the translator, since `HashMap` is only available with `std`.
+#[cfg(feature = "std")]
+pub(crate) type Map<K, V> = std::collections::HashMap<K, V>;
+#[cfg(not(feature = "std"))]
+pub(crate) type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
 #[cfg_attr(test, derive(PartialEq))]
-#[derive(thiserror::Error, Debug)]
+#[derive(Debug)]
 pub enum Error {
-    #[error("Duplicated key `{0}`")]
     DuplicatedKey(SmallStr),
-    #[error("Duplicated argument `{0}`")]
+    DuplicatedLanguage(SmallStr),
     DuplicatedArgument(SmallStr),
-    #[error("Unknown language key: `{0}`")]
     UnknownLanguage(SmallStr),
-    #[error("Unknown argument: `{0}`")]
     UnknownArgument(SmallStr),
-    #[error("Key not found: `{0}`")]
     MissingKey(SmallStr),
-    #[error("Language not found: `{0}`")]
-    MissingLanguage(SmallStr),
-    #[error("Replacement error: `{0}`")]
-    AhoCorasickMatch(#[from] aho_corasick::MatchError),
+    UntranslatedForLanguage(SmallStr, SmallStr),
+    AhoCorasickMatch(aho_corasick::MatchError),
     // Note: this is a stringified version of `aho_corasick::MatchError` since it does not implement PartialEq
-    #[error("Replacement error: `{0}`")]
     AhoCorasickBuild(String),
+    MissingPluralCategory(SmallStr, PluralCategory),
+    MissingVariant(SmallStr, SmallStr),
+    CyclicReference(SmallStr),
+    ReferenceTooDeep(SmallStr),
+    UnusedArgument(SmallStr),
+    ArgumentCountMismatch { expected: usize, got: usize },
+    Fmt(fmt::Error),
+    Parse(String),
+    // Note: this is a stringified version of `std::io::Error` since it does not implement PartialEq
+    Io(String),
+    Encoding(String),
+    BatchFailed(SmallStr, Box<Error>),
+    InvalidIdentifier(SmallStr),
+    CollidingIdentifiers(SmallStr, SmallStr),
+    ArgumentMismatch { key: SmallStr, language: SmallStr },
+    NoLanguages,
+    LanguageAddedAfterKeys(SmallStr),
+    StaleLanguage,
+    MissingLanguages(Vec<SmallStr>),
+    MissingArgument(SmallStr),
+    UnreplacedPlaceholder(SmallStr),
+    MissingFormatter(SmallStr),
+    UnsupportedValue(SmallStr),
+    MissingRange(SmallStr, u64),
+    AmbiguousArguments(SmallStr, SmallStr),
+    EmptyArgument(SmallStr),
+    RepeatedArgument { key: SmallStr, argument: SmallStr },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DuplicatedKey(key) => write!(f, "Duplicated key `{key}`"),
+            Error::DuplicatedLanguage(language) => write!(f, "Duplicated language `{language}`"),
+            Error::DuplicatedArgument(argument) => write!(f, "Duplicated argument `{argument}`"),
+            Error::UnknownLanguage(language) => write!(f, "Unknown language key: `{language}`"),
+            Error::UnknownArgument(argument) => write!(f, "Unknown argument: `{argument}`"),
+            Error::MissingKey(key) => write!(f, "Key not found: `{key}`"),
+            Error::UntranslatedForLanguage(key, language) => {
+                write!(
+                    f,
+                    "Key `{key}` has no translation for language `{language}`"
+                )
+            }
+            Error::AhoCorasickMatch(err) => write!(f, "Replacement error: `{err}`"),
+            Error::AhoCorasickBuild(err) => write!(f, "Replacement error: `{err}`"),
+            Error::MissingPluralCategory(key, category) => {
+                write!(
+                    f,
+                    "Key `{key}` has neither a `{category}` nor an `other` plural form"
+                )
+            }
+            Error::MissingVariant(key, variant) => {
+                write!(
+                    f,
+                    "Key `{key}` has neither a `{variant}` nor an `other` variant"
+                )
+            }
+            Error::CyclicReference(key) => write!(f, "Key `{key}` references itself"),
+            Error::ReferenceTooDeep(key) => write!(f, "Key `{key}` is referenced too deep"),
+            Error::UnusedArgument(argument) => {
+                write!(f, "Argument `{argument}` does not appear in every message")
+            }
+            Error::ArgumentCountMismatch { expected, got } => {
+                write!(f, "Expected {expected} arguments, got {got}")
+            }
+            Error::Fmt(_) => f.write_str("Formatting error"),
+            Error::Parse(err) => write!(f, "Parse error: {err}"),
+            Error::Io(err) => write!(f, "I/O error: {err}"),
+            Error::Encoding(err) => write!(f, "Encoding error: {err}"),
+            Error::BatchFailed(key, err) => write!(f, "Failed to translate `{key}`: {err}"),
+            Error::InvalidIdentifier(key) => write!(f, "Key `{key}` is not a valid identifier"),
+            Error::CollidingIdentifiers(first, second) => {
+                write!(f, "Keys `{first}` and `{second}` make the same identifier")
+            }
+            Error::ArgumentMismatch { key, language } => {
+                write!(
+                    f,
+                    "Key `{key}` takes different arguments in language `{language}`"
+                )
+            }
+            Error::NoLanguages => f.write_str("The translator has no languages"),
+            Error::LanguageAddedAfterKeys(language) => {
+                write!(f, "Language `{language}` was added after messages")
+            }
+            Error::StaleLanguage => f.write_str("Languages changed since the language was found"),
+            Error::MissingLanguages(languages) => {
+                f.write_str("Missing translations for ")?;
+                for (index, language) in languages.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "`{language}`")?;
+                }
+                Ok(())
+            }
+            Error::MissingArgument(argument) => write!(f, "Argument `{argument}` was not given"),
+            Error::UnreplacedPlaceholder(argument) => {
+                write!(f, "Argument `{argument}` was left in the translation")
+            }
+            Error::MissingFormatter(annotation) => {
+                write!(f, "No formatter registered for `:{annotation}`")
+            }
+            Error::UnsupportedValue(argument) => {
+                write!(
+                    f,
+                    "Argument `{argument}` was given a value of an unsupported type"
+                )
+            }
+            Error::MissingRange(key, count) => {
+                write!(f, "Key `{key}` has no range containing {count}")
+            }
+            Error::AmbiguousArguments(inner, outer) => {
+                write!(f, "Argument `{inner}` appears within argument `{outer}`")
+            }
+            Error::EmptyArgument(key) => write!(f, "Key `{key}` declares an empty argument"),
+            Error::RepeatedArgument { key, argument } => {
+                write!(
+                    f,
+                    "Key `{key}` declares argument `{argument}` more than once"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            // `MatchError` only implements `Error` with `std`
+            #[cfg(feature = "std")]
+            Error::AhoCorasickMatch(err) => Some(err),
+            Error::Fmt(err) => Some(err),
+            Error::BatchFailed(_, err) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<aho_corasick::MatchError> for Error {
+    fn from(err: aho_corasick::MatchError) -> Self {
+        Error::AhoCorasickMatch(err)
+    }
+}
+
+impl From<fmt::Error> for Error {
+    fn from(err: fmt::Error) -> Self {
+        Error::Fmt(err)
+    }
+}
+
+/// Fails with [`Error::ArgumentMismatch`] naming the first of `messages` whose arguments,
+/// as found by `arguments_of`, differ from the ones of the first message.
+///
+/// Loaders inferring arguments from each language's messages use this to catch messages
+/// misspelling a placeholder, which would otherwise just declare one more argument.
+#[cfg(any(
+    feature = "json",
+    feature = "gettext",
+    feature = "android",
+    feature = "apple"
+))]
+fn ensure_same_arguments<'a>(
+    key: &str,
+    messages: impl IntoIterator<Item = (&'a str, &'a str)>,
+    arguments_of: impl Fn(&'a str) -> Vec<&'a str>,
+) -> Result<(), Error> {
+    use alloc::collections::BTreeSet;
+
+    let mut messages = messages.into_iter();
+    let Some((_, first)) = messages.next() else {
+        return Ok(());
+    };
+    let expected: BTreeSet<_> = arguments_of(first).into_iter().collect();
+
+    for (language, message) in messages {
+        let arguments: BTreeSet<_> = arguments_of(message).into_iter().collect();
+        if arguments != expected {
+            return Err(Error::ArgumentMismatch {
+                key: key.into(),
+                language: language.into(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// The id `id` takes once the language of id `removed` is gone, if it isn't that one.
+fn unshift_language(id: LanguageId, removed: LanguageId) -> Option<LanguageId> {
+    match id.cmp(&removed) {
+        core::cmp::Ordering::Less => Some(id),
+        core::cmp::Ordering::Equal => None,
+        core::cmp::Ordering::Greater => Some(id - 1),
+    }
+}
+
+/// Drops the messages of the language of id `removed` from `translations`,
+/// shifting the ids after it.
+fn remove_language_from<M>(translations: &mut Map<SmallStr, Translation<M>>, removed: LanguageId) {
+    for translation in translations.values_mut() {
+        translation.translations = core::mem::take(&mut translation.translations)
+            .into_iter()
+            .filter_map(|(id, message)| Some((unshift_language(id, removed)?, message)))
+            .collect();
+    }
 }
 
 pub struct Translator {
@@ -32,18 +393,151 @@ pub struct Translator {
     /// Translations must be provided for all of the entries in this slice.
     languages: Box<[SmallStr]>,
     /// Maps each key to its [`Translation`].
-    translations: HashMap<SmallStr, Translation>,
+    translations: Map<SmallStr, Translation>,
+    /// Languages to try, in order, when a key has no message for the given language.
+    fallbacks: Map<LanguageId, Box<[LanguageId]>>,
+    /// Bumped whenever languages are added or removed, invalidating [`Language`] handles.
+    language_generation: u32,
+    /// Alternative codes for known languages, e.g. `pt-BR` for `pt`.
+    aliases: Map<SmallStr, LanguageId>,
+    /// Maps each plural key to its [`Translation`], see [`Translator::add_plural`].
+    plurals: Map<SmallStr, Translation<plural::PluralForms>>,
+    /// Maps each select key to its [`Translation`], see [`Translator::add_select`].
+    selects: Map<SmallStr, Translation<select::SelectVariants>>,
+    /// Maps each ranged key to its [`Translation`], see [`Translator::add_ranged`].
+    ranged: Map<SmallStr, Translation<ranged::RangedMessages>>,
+    /// Whether every declared argument must appear in each message, see
+    /// [`Translator::with_strict_arguments`].
+    strict_arguments: bool,
+    /// Whether every argument must be given when translating, see
+    /// [`Translator::require_all_arguments`].
+    require_all_arguments: bool,
+    /// Whether language codes are compared ignoring ASCII case, see
+    /// [`Translator::case_insensitive_languages`].
+    case_insensitive_languages: bool,
+    /// Whether arguments are stored in `argument_table`, see [`Translator::with_interned_arguments`].
+    intern_arguments: bool,
+    /// Arguments shared by every key, when interning them.
+    argument_table: ArgumentTable,
+    /// Whether messages are stored in `message_table`, see [`Translator::with_message_interning`].
+    intern_messages: bool,
+    /// Messages shared by every key, when interning them.
+    message_table: MessageTable,
+    /// Wrapped around each argument to form its placeholder, see [`Translator::with_delimiters`].
+    delimiters: Option<(SmallStr, SmallStr)>,
+    /// How placeholders are matched within messages, see [`Translator::with_match_kind`].
+    match_kind: MatchKind,
+    /// How many references deep a message may go, see [`Translator::with_max_reference_depth`].
+    max_reference_depth: usize,
+    /// Whether `${key}` references are resolved, see [`Translator::resolve_references`].
+    resolve_references: bool,
+    /// The digit grouping separator of each language, see [`Translator::format_number`].
+    grouping_separators: Box<[char]>,
+    /// How each language joins lists, see [`Translator::format_list`].
+    list_patterns: Box<[list::ListPatterns]>,
+    /// Whether values are isolated within right-to-left messages, see [`Translator::isolate_rtl`].
+    isolate_rtl: bool,
+    /// Whether the `en-XA` pseudolocale is translated into, see [`Translator::pseudolocalize`].
+    pseudolocalize: bool,
+    /// Formats values by the annotation of their argument, see [`Translator::with_formatter`].
+    formatters: Map<SmallStr, Arc<dyn formatter::ArgumentFormatter>>,
+    /// Splits keys into namespaces, see [`Translator::with_namespace_separator`].
+    namespace_separator: SmallStr,
+    /// How exported keys are sorted, see [`Translator::with_collation`].
+    #[cfg(feature = "collation")]
+    collation: Option<collation::Collation>,
+    /// `.po` messages loaded so far, see [`Translator::load_po`].
+    #[cfg(feature = "gettext")]
+    po_catalog: catalog::PendingCatalog,
+    /// `strings.xml` messages loaded so far, see [`Translator::load_android_xml`].
+    #[cfg(feature = "android")]
+    android_catalog: catalog::PendingCatalog,
+    /// `.strings` messages loaded so far, see [`Translator::load_apple_strings`].
+    #[cfg(feature = "apple")]
+    apple_catalog: catalog::PendingCatalog,
+    /// `.arb` messages loaded so far, see [`Translator::load_arb`].
+    #[cfg(feature = "arb")]
+    arb_catalog: catalog::PendingCatalog,
 }
 
-struct Translation {
-    // TODO: store arguments in descending order
-    /// Arguments to be inserted into the given phrase.
-    arguments: Box<[SmallStr]>,
+struct Translation<M = Message> {
+    /// Arguments to be inserted into the given phrase, longest first.
+    arguments: Arguments,
+    /// Automaton matching the placeholder of every argument, the escaped opening delimiter
+    /// when delimiters are set, and `cased_placeholders`, longest first.
+    automaton: AhoCorasick,
+    /// What each pattern of `automaton` matches, see [`Translation::argument_of`].
+    patterns: Box<[Pattern]>,
+    /// What the escaped opening delimiter is replaced by, if delimiters are set.
+    unescaped_delimiter: Option<SmallStr>,
+    /// The placeholder of arguments with one of [`CASE_ANNOTATIONS`], as in `NAME:upper`, that
+    /// occur in some message, along with the index of their argument and their case.
+    cased_placeholders: Box<[(usize, Case, SmallStr)]>,
+    /// Indices of the arguments declared with a `:number` suffix.
+    number_arguments: Box<[usize]>,
+    /// Indices of the arguments declared with a `:list` suffix.
+    list_arguments: Box<[usize]>,
+    /// Indices of the arguments declared with the annotation of a formatter, as in
+    /// `price:currency`, along with that annotation.
+    formatted_arguments: Box<[(usize, SmallStr)]>,
+    /// Indices of the arguments declared with a trailing `?`, see [`OPTIONAL_ANNOTATION`].
+    optional_arguments: Box<[usize]>,
+    /// The index of each argument within `arguments`, in the order they were declared.
+    declared_order: Box<[usize]>,
+    /// Maps the name of each argument to its index within `arguments`.
+    arguments_by_name: Map<SmallStr, usize>,
     // LanguageId refers to the index of the given language in `Translator::languages`.
-    translations: HashMap<LanguageId, SmallStr>,
+    translations: Map<LanguageId, M>,
+}
+
+/// What a pattern of the automaton of a [`Translation`] matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pattern {
+    /// The placeholder of the argument at this index.
+    Argument(usize),
+    /// The escaped opening delimiter.
+    EscapedDelimiter,
+    /// The placeholder at this index within `cased_placeholders`.
+    Cased(usize),
+}
+
+/// Suffix of an argument that may be left out of both messages and translations,
+/// as in `TITLE?`. Such arguments are given by their bare name, as are their placeholders.
+///
+/// Optional arguments left out of [`Translator::translate`] and friends are replaced by
+/// nothing, and [`Translator::with_strict_arguments`] lets messages go without them.
+pub(crate) const OPTIONAL_ANNOTATION: char = '?';
+
+/// How argument values are given to and formatted within the messages of a language.
+#[derive(Clone, Copy)]
+struct ValueFormat {
+    /// Whether every argument must be given, see [`Translator::require_all_arguments`].
+    require_all: bool,
+    /// Groups the digits of number arguments, see [`Translator::format_number`].
+    grouping_separator: char,
+    /// Joins the items of list arguments, see [`Translator::format_list`].
+    list_patterns: list::ListPatterns,
+    /// Whether values are wrapped in bidi isolates, see [`Translator::isolate_rtl`].
+    isolate: bool,
+    /// Whether values are cased the Turkic way, e.g. `i` as `İ`, see [`case::is_turkic`].
+    turkic_casing: bool,
+    /// How the literal text of messages is altered, see [`Translator::pseudolocalize`].
+    pseudo: Pseudo,
+}
+
+impl Default for Translator {
+    /// Creates a translator without any language, to be added through
+    /// [`Translator::add_language`].
+    fn default() -> Self {
+        Self::new([""; 0])
+    }
 }
 
 impl Translator {
+    /// Creates a translator for `languages`, ignoring duplicates.
+    ///
+    /// A translator without any language can't hold messages: adding them fails
+    /// with [`Error::NoLanguages`].
     pub fn new<S: Into<SmallStr>, I: IntoIterator<Item = S>>(languages: I) -> Self {
         let mut languages: Vec<SmallStr> = languages.into_iter().map(Into::into).collect();
 
@@ -51,10 +545,273 @@ impl Translator {
         languages.sort();
         languages.dedup();
 
+        let grouping_separators = languages
+            .iter()
+            .map(|language| number::grouping_separator(language))
+            .collect();
+        let list_patterns = languages
+            .iter()
+            .map(|language| list::list_patterns(language))
+            .collect();
+
         Self {
             languages: languages.into(),
             translations: Default::default(),
+            fallbacks: Default::default(),
+            language_generation: 0,
+            aliases: Default::default(),
+            plurals: Default::default(),
+            selects: Default::default(),
+            ranged: Default::default(),
+            strict_arguments: false,
+            require_all_arguments: false,
+            case_insensitive_languages: false,
+            intern_arguments: false,
+            argument_table: Default::default(),
+            intern_messages: false,
+            message_table: Default::default(),
+            delimiters: None,
+            match_kind: MatchKind::LeftmostLongest,
+            max_reference_depth: reference::DEFAULT_MAX_REFERENCE_DEPTH,
+            resolve_references: false,
+            grouping_separators,
+            list_patterns,
+            isolate_rtl: false,
+            pseudolocalize: false,
+            formatters: Default::default(),
+            namespace_separator: namespace::DEFAULT_NAMESPACE_SEPARATOR.into(),
+            #[cfg(feature = "collation")]
+            collation: None,
+            #[cfg(feature = "gettext")]
+            po_catalog: Default::default(),
+            #[cfg(feature = "android")]
+            android_catalog: Default::default(),
+            #[cfg(feature = "apple")]
+            apple_catalog: Default::default(),
+            #[cfg(feature = "arb")]
+            arb_catalog: Default::default(),
+        }
+    }
+
+    /// When enabled, [`Translator::add_text`] and friends reject any declared argument
+    /// that does not appear in every given message with [`Error::UnusedArgument`].
+    ///
+    /// They also reject with [`Error::AmbiguousArguments`] any argument whose placeholder
+    /// appears within another one's, such as `NAME` within `NAME2`. Delimited placeholders,
+    /// see [`Translator::with_delimiters`], avoid this.
+    ///
+    /// Disabled by default.
+    pub fn with_strict_arguments(mut self, strict: bool) -> Self {
+        self.strict_arguments = strict;
+        self
+    }
+
+    /// When enabled, [`Translator::translate`] and friends fail with [`Error::MissingArgument`]
+    /// instead of keeping the placeholder of a declared argument left out of `args`.
+    /// Optional arguments may still be left out.
+    ///
+    /// Disabled by default.
+    pub fn require_all_arguments(mut self, require: bool) -> Self {
+        self.require_all_arguments = require;
+        self
+    }
+
+    /// Sets how placeholders are matched within messages when they overlap, such as
+    /// `NAME` and `NAME2`.
+    ///
+    /// With the default [`MatchKind::LeftmostLongest`], and with [`MatchKind::LeftmostFirst`]
+    /// since placeholders are tried longest first, `NAME2` is matched as a whole, as is
+    /// `NAME:upper`.
+    /// [`MatchKind::Standard`] matches whichever placeholder ends first instead, i.e. `NAME`.
+    ///
+    /// Only applies to messages added afterwards.
+    pub fn with_match_kind(mut self, match_kind: MatchKind) -> Self {
+        self.match_kind = match_kind;
+        self
+    }
+
+    /// When enabled, language codes given to [`Translator::add_text`], [`Translator::translate`]
+    /// and friends are matched ignoring ASCII case, so `EN` finds `en`. Languages keep the
+    /// casing they were registered with.
+    ///
+    /// Disabled by default.
+    pub fn case_insensitive_languages(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive_languages = case_insensitive;
+        self
+    }
+
+    /// When enabled, argument values replaced into messages of right-to-left languages,
+    /// such as Arabic or Hebrew, are wrapped in Unicode bidi isolates, so that e.g. a
+    /// Latin name does not reorder the text around it.
+    ///
+    /// Values starting with a left-to-right letter are wrapped in `U+2066` (LRI), values
+    /// starting with a right-to-left one in `U+2067` (RLI), and values without any letter
+    /// in `U+2068` (FSI), each closed by `U+2069` (PDI).
+    ///
+    /// Disabled by default.
+    pub fn isolate_rtl(mut self, isolate: bool) -> Self {
+        self.isolate_rtl = isolate;
+        self
+    }
+
+    /// Makes arguments only match when wrapped in `open` and `close` within messages,
+    /// e.g. `{{NAME}}` for the argument `NAME` given `{{` and `}}`.
+    ///
+    /// A literal opening delimiter can be written by escaping it with a backslash,
+    /// so `\{{NAME}}` is translated as `{{NAME}}`.
+    ///
+    /// Only applies to messages added afterwards.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]).with_delimiters("{{", "}}");
+    /// tr.add_text("greetings", ["NAME"], [("en", "Hello, {{NAME}}! NAME is a nice name.")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate("greetings", "en", [("NAME", "Julian")])?,
+    ///     "Hello, Julian! NAME is a nice name."
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn with_delimiters(mut self, open: &str, close: &str) -> Self {
+        self.delimiters = Some((open.into(), close.into()));
+        self
+    }
+
+    /// Sets the languages `translate` should try, in order, whenever a key
+    /// has no message for `language`. Replaces any previously set chain.
+    pub fn set_fallback_chain<S: Into<SmallStr>, I: IntoIterator<Item = S>>(
+        &mut self,
+        language: &str,
+        chain: I,
+    ) -> Result<(), Error> {
+        let language_id = self.find_language(language)?;
+
+        let chain = chain
+            .into_iter()
+            .map(|fallback| self.find_language(&fallback.into()))
+            .collect::<Result<_, _>>()?;
+
+        self.fallbacks.insert(language_id, chain);
+
+        Ok(())
+    }
+
+    /// Adds `language` to the known languages.
+    ///
+    /// Since every message added through [`Translator::add_text`] must cover all languages,
+    /// languages can only be added before any message, and fail with
+    /// [`Error::LanguageAddedAfterKeys`] afterwards.
+    pub fn add_language(&mut self, language: &str) -> Result<(), Error> {
+        if self.position_of(language).is_some() || self.aliases.contains_key(language) {
+            return Err(Error::DuplicatedLanguage(language.into()));
+        }
+
+        if !self.translations.is_empty()
+            || !self.plurals.is_empty()
+            || !self.selects.is_empty()
+            || !self.ranged.is_empty()
+            || self.has_pending_catalogs()
+        {
+            return Err(Error::LanguageAddedAfterKeys(language.into()));
+        }
+
+        // Languages are kept sorted, so ids from the new one onwards shift by one
+        let new_id = self
+            .languages
+            .partition_point(|known| known.as_str() < language);
+        let shift = |id: LanguageId| if id >= new_id { id + 1 } else { id };
+
+        let mut languages = core::mem::take(&mut self.languages).into_vec();
+        languages.insert(new_id, language.into());
+        self.languages = languages.into();
+        self.language_generation = self.language_generation.wrapping_add(1);
+
+        let mut grouping_separators = core::mem::take(&mut self.grouping_separators).into_vec();
+        grouping_separators.insert(new_id, number::grouping_separator(language));
+        self.grouping_separators = grouping_separators.into();
+
+        let mut list_patterns = core::mem::take(&mut self.list_patterns).into_vec();
+        list_patterns.insert(new_id, list::list_patterns(language));
+        self.list_patterns = list_patterns.into();
+
+        for id in self.aliases.values_mut() {
+            *id = shift(*id);
+        }
+        self.fallbacks = core::mem::take(&mut self.fallbacks)
+            .into_iter()
+            .map(|(id, chain)| (shift(id), chain.iter().copied().map(shift).collect()))
+            .collect();
+
+        Ok(())
+    }
+
+    /// Removes `language` from the known languages, along with its messages, aliases
+    /// and fallback chain. Fallback chains of other languages skip it from then on.
+    pub fn remove_language(&mut self, language: &str) -> Result<(), Error> {
+        let removed = self
+            .position_of(language)
+            .ok_or_else(|| Error::UnknownLanguage(language.into()))?;
+        let unshift = |id| unshift_language(id, removed);
+
+        let mut languages = core::mem::take(&mut self.languages).into_vec();
+        languages.remove(removed);
+        self.languages = languages.into();
+        self.language_generation = self.language_generation.wrapping_add(1);
+
+        let mut grouping_separators = core::mem::take(&mut self.grouping_separators).into_vec();
+        grouping_separators.remove(removed);
+        self.grouping_separators = grouping_separators.into();
+
+        let mut list_patterns = core::mem::take(&mut self.list_patterns).into_vec();
+        list_patterns.remove(removed);
+        self.list_patterns = list_patterns.into();
+
+        self.aliases.retain(|_, id| match unshift(*id) {
+            Some(new_id) => {
+                *id = new_id;
+                true
+            }
+            None => false,
+        });
+        self.fallbacks = core::mem::take(&mut self.fallbacks)
+            .into_iter()
+            .filter_map(|(id, chain)| {
+                Some((
+                    unshift(id)?,
+                    chain.iter().filter_map(|&id| unshift(id)).collect(),
+                ))
+            })
+            .collect();
+
+        remove_language_from(&mut self.translations, removed);
+        remove_language_from(&mut self.plurals, removed);
+        remove_language_from(&mut self.selects, removed);
+        remove_language_from(&mut self.ranged, removed);
+        #[cfg(any(
+            feature = "gettext",
+            feature = "android",
+            feature = "apple",
+            feature = "arb"
+        ))]
+        self.remove_pending_language(removed);
+
+        Ok(())
+    }
+
+    /// Makes `alias` stand for the known language `target` when translating,
+    /// e.g. so that `pt-BR` and `pt-PT` both resolve to `pt`.
+    pub fn add_alias(&mut self, alias: &str, target: &str) -> Result<(), Error> {
+        let target_id = self.find_language(target)?;
+
+        if self.position_of(alias).is_some() {
+            return Err(Error::DuplicatedLanguage(alias.into()));
         }
+
+        self.aliases.insert(alias.into(), target_id);
+
+        Ok(())
     }
 
     pub fn add_text<
@@ -68,106 +825,2662 @@ impl Translator {
         key: S3,
         arguments: I1,
         translations: I2,
+    ) -> Result<(), Error> {
+        self.insert_text(key, arguments, translations, true)
+    }
+
+    /// Adds every `(key, arguments, translations)` of `entries` through [`Translator::add_text`],
+    /// in order.
+    ///
+    /// Stops at the first entry that fails, keeping the ones added before it.
+    pub fn extend_texts<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, S2)>,
+        I: IntoIterator<Item = (S3, I1, I2)>,
+    >(
+        &mut self,
+        entries: I,
+    ) -> Result<(), Error> {
+        entries
+            .into_iter()
+            .try_for_each(|(key, arguments, translations)| {
+                self.add_text(key, arguments, translations)
+            })
+    }
+
+    /// Like [`Translator::add_text`], but accepts messages for only a subset of the languages.
+    ///
+    /// Translating into a language left unset walks its fallback chain, if any,
+    /// and fails with [`Error::UntranslatedForLanguage`] otherwise.
+    pub fn add_text_partial<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, S2)>,
+    >(
+        &mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+    ) -> Result<(), Error> {
+        self.insert_text(key, arguments, translations, false)
+    }
+
+    /// Replaces the message registered under `key`, which must already exist.
+    ///
+    /// Just like [`Translator::add_text`], every language must have a message.
+    pub fn update_text<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, S2)>,
+    >(
+        &mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+    ) -> Result<(), Error> {
+        let key = key.into();
+        if !self.translations.contains_key(&key) {
+            return Err(Error::MissingKey(key));
+        }
+
+        let mut translation = self.build_text_translation(arguments, translations, true)?;
+        translation.arguments = self.store_arguments(translation.arguments);
+        self.store_messages(&mut translation.translations);
+        self.translations.insert(key, translation);
+
+        Ok(())
+    }
+
+    fn insert_text<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, S2)>,
+    >(
+        &mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+        require_all_languages: bool,
     ) -> Result<(), Error> {
         let key = key.into();
         if self.translations.contains_key(&key) {
             return Err(Error::DuplicatedKey(key.clone()));
         }
 
-        let arguments = arguments.into_iter().map(Into::into).collect();
+        let translation =
+            self.build_text_translation(arguments, translations, require_all_languages)?;
+        self.insert_translation(key, translation);
+
+        Ok(())
+    }
+
+    /// Stores a freshly built text `translation` under `key`, interning what it holds if
+    /// enabled.
+    fn insert_translation(&mut self, key: SmallStr, mut translation: Translation) {
+        translation.arguments = self.store_arguments(translation.arguments);
+        self.store_messages(&mut translation.translations);
+
+        // TODO: Check if we have duplicate translations
+        self.translations.insert(key, translation);
+    }
+
+    fn build_text_translation<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        arguments: I1,
+        translations: I2,
+        require_all_languages: bool,
+    ) -> Result<Translation, Error> {
+        let translations = translations
+            .into_iter()
+            .map(|(language, message)| (language, Message::Owned(message.into())));
+        let translation = self.build_translation(
+            arguments,
+            translations,
+            require_all_languages,
+            |message, pattern| message.contains(pattern),
+        )?;
+
+        if self.strict_arguments {
+            let arguments = translation.arguments.resolve(&self.argument_table);
+            let unused = translation
+                .declared_order
+                .iter()
+                .copied()
+                .filter(|index| !translation.optional_arguments.contains(index))
+                .find(|&index| {
+                    translation.translations.values().any(|message| {
+                        !translation.occurs_in(message, arguments.placeholder(index), index)
+                    })
+                });
+
+            if let Some(index) = unused {
+                return Err(Error::UnusedArgument(arguments.name(index).clone()));
+            }
+
+            // e.g. `NAME` within `NAME2`, whose placeholder then depends on the match kind
+            let placeholder = |index| arguments.placeholder(index).as_str();
+            let ambiguous = translation.declared_order.iter().find_map(|&inner| {
+                translation
+                    .declared_order
+                    .iter()
+                    .find(|&&outer| {
+                        outer != inner && placeholder(outer).contains(placeholder(inner))
+                    })
+                    .map(|&outer| (inner, outer))
+            });
+
+            if let Some((inner, outer)) = ambiguous {
+                return Err(Error::AmbiguousArguments(
+                    arguments.name(inner).clone(),
+                    arguments.name(outer).clone(),
+                ));
+            }
+        }
+
+        Ok(translation)
+    }
+
+    fn build_translation<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        M,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S2, M)>,
+    >(
+        &self,
+        arguments: I1,
+        translations: I2,
+        require_all_languages: bool,
+        contains: impl Fn(&M, &str) -> bool,
+    ) -> Result<Translation<M>, Error> {
+        if self.languages.is_empty() {
+            return Err(Error::NoLanguages);
+        }
+
+        let mut declared: Vec<(usize, SmallStr, bool)> = arguments
+            .into_iter()
+            .map(Into::into)
+            .enumerate()
+            .map(
+                |(index, argument)| match argument.strip_suffix(OPTIONAL_ANNOTATION) {
+                    Some(name) => (index, name.into(), true),
+                    None => (index, argument, false),
+                },
+            )
+            .collect();
 
-        let mut processed_translations = HashMap::with_capacity(self.languages.len());
+        // Store the longest arguments first, so that e.g. `NAME2` comes before `NAME`
+        declared.sort_by_key(|(_, argument, _)| Reverse(argument.len()));
+
+        let mut declared_order = vec![0; declared.len()];
+        let mut optional_arguments = Vec::new();
+        for (stored, &(index, _, optional)) in declared.iter().enumerate() {
+            declared_order[index] = stored;
+            if optional {
+                optional_arguments.push(stored);
+            }
+        }
+        let declared: Box<[SmallStr]> = declared
+            .into_iter()
+            .map(|(_, argument, _)| argument)
+            .collect();
+
+        let mut number_arguments = Vec::new();
+        let mut list_arguments = Vec::new();
+        let mut formatted_arguments = Vec::new();
+        let arguments: Box<[SmallStr]> = declared
+            .iter()
+            .enumerate()
+            .map(|(index, argument)| {
+                if let Some(name) = argument.strip_suffix(NUMBER_ANNOTATION) {
+                    number_arguments.push(index);
+                    name.into()
+                } else if let Some(name) = argument.strip_suffix(LIST_ANNOTATION) {
+                    list_arguments.push(index);
+                    name.into()
+                } else if let Some((name, annotation)) =
+                    argument.rsplit_once(':').filter(|(name, annotation)| {
+                        // Otherwise, e.g. `time:hh` is an argument of its own
+                        !name.is_empty() && self.formatters.contains_key(*annotation)
+                    })
+                {
+                    formatted_arguments.push((index, annotation.into()));
+                    name.into()
+                } else {
+                    argument.clone()
+                }
+            })
+            .collect();
+
+        // Inserted last to first, so that the first of repeated names wins
+        let arguments_by_name = arguments
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(index, argument)| (argument.clone(), index))
+            .collect();
+
+        let mut processed_translations = Map::default();
 
         for (language_key, message) in translations {
             // TODO: check if we can change this to AsRef<str>
             let language_key: SmallStr = language_key.into();
             let language_id = self
-                .languages
-                .iter()
-                .position(|lang| lang == language_key)
+                .position_of(&language_key)
                 .ok_or_else(|| Error::UnknownLanguage(language_key.clone()))?;
 
             let is_duplicate = processed_translations
-                .insert(language_id, message.into())
+                .insert(language_id, message)
                 .is_some();
 
             if is_duplicate {
-                return Err(Error::DuplicatedKey(language_key));
+                return Err(Error::DuplicatedLanguage(language_key));
             }
         }
 
-        if processed_translations.len() < self.languages.len() {
-            return Err(Error::MissingLanguage(
-                "Not all languages have translations".into(),
-            ));
+        if require_all_languages && processed_translations.len() < self.languages.len() {
+            let missing = (0..self.languages.len())
+                .filter(|language_id| !processed_translations.contains_key(language_id))
+                .map(|language_id| self.languages[language_id].clone())
+                .collect();
+
+            return Err(Error::MissingLanguages(missing));
         }
 
-        let translation = Translation {
-            arguments,
-            translations: processed_translations,
+        let cased_placeholders: Box<[(usize, Case, SmallStr)]> = CASE_ANNOTATIONS
+            .iter()
+            .flat_map(|&(case, suffix)| {
+                declared.iter().enumerate().map(move |(index, argument)| {
+                    let placeholder = match &self.delimiters {
+                        Some((open, close)) => format_compact!("{open}{argument}{suffix}{close}"),
+                        None => format_compact!("{argument}{suffix}"),
+                    };
+
+                    (index, case, placeholder)
+                })
+            })
+            .filter(|(_, _, placeholder)| {
+                processed_translations
+                    .values()
+                    .any(|message| contains(message, placeholder))
+            })
+            .collect();
+
+        let placeholders: Box<[SmallStr]> = match &self.delimiters {
+            Some((open, close)) => declared
+                .iter()
+                .map(|argument| format_compact!("{open}{argument}{close}"))
+                .collect(),
+            None => declared,
         };
 
-        // TODO: Check if we have duplicate translations
-        self.translations.insert(key, translation);
+        let unescaped_delimiter = self.delimiters.as_ref().map(|(open, _)| open.clone());
+        let escaped_delimiter = unescaped_delimiter
+            .as_ref()
+            .map(|open| format_compact!("\\{open}"));
 
-        Ok(())
+        // Longest first, so that no placeholder is matched as one it starts with under
+        // `MatchKind::LeftmostFirst`, such as `NAME2` or `NAME:upper` as `NAME`. Arguments
+        // are stored longest first already, and keep their order
+        let mut patterns: Vec<(&str, Pattern)> = placeholders
+            .iter()
+            .enumerate()
+            .map(|(index, placeholder)| (placeholder.as_str(), Pattern::Argument(index)))
+            .chain(
+                escaped_delimiter
+                    .iter()
+                    .map(|escaped| (escaped.as_str(), Pattern::EscapedDelimiter)),
+            )
+            .chain(
+                cased_placeholders
+                    .iter()
+                    .enumerate()
+                    .map(|(cased, (_, _, placeholder))| {
+                        (placeholder.as_str(), Pattern::Cased(cased))
+                    }),
+            )
+            .collect();
+        patterns.sort_by_key(|(pattern, _)| Reverse(pattern.len()));
+
+        let automaton = AhoCorasick::builder()
+            .match_kind(self.match_kind)
+            .build(patterns.iter().map(|(pattern, _)| pattern))
+            .map_err(|err| Error::AhoCorasickBuild(err.to_string()))?;
+        let patterns: Box<[Pattern]> = patterns.into_iter().map(|(_, pattern)| pattern).collect();
+
+        Ok(Translation {
+            arguments: Arguments::Owned {
+                names: arguments,
+                placeholders,
+            },
+            automaton,
+            patterns,
+            unescaped_delimiter,
+            cased_placeholders,
+            number_arguments: number_arguments.into(),
+            list_arguments: list_arguments.into(),
+            formatted_arguments: formatted_arguments.into(),
+            optional_arguments: optional_arguments.into(),
+            declared_order: declared_order.into(),
+            arguments_by_name,
+            translations: processed_translations,
+        })
+    }
+
+    /// Removes the message registered under `key`, along with its automaton.
+    pub fn remove_text(&mut self, key: &str) -> Result<(), Error> {
+        self.translations
+            .remove(key)
+            .map(drop)
+            .ok_or_else(|| Error::MissingKey(key.into()))
     }
 
+    /// Translates the message registered under `key` into `language`, replacing
+    /// each of its arguments by the value given in `args`.
+    ///
+    /// The order of `args` does not matter: each value is matched to its argument
+    /// by name, so any `(argument, value)` iterator, such as a `HashMap`, gives the
+    /// same output. Arguments left out are kept as-is in the output, except for
+    /// optional ones, declared with a trailing `?`, which are left out as well.
+    ///
+    /// A placeholder followed by `:upper`, `:lower` or `:title`, as in `{NAME:upper}`, is
+    /// replaced by the value in that case, casing `i` the Turkic way in `tr` and `az`.
+    ///
+    /// Messages are scanned once, so values are never replaced themselves, even when
+    /// they spell the placeholder of another argument.
     pub fn translate<S1: Into<SmallStr>, S2: Into<SmallStr>, I: IntoIterator<Item = (S1, S2)>>(
         &self,
         key: &str,
         language: &str,
         args: I,
     ) -> Result<String, Error> {
-        // Fetch the appropriate translation based on key and language
-        let translation = self
-            .translations
-            .get(key)
-            .ok_or_else(|| Error::MissingKey(key.into()))?;
+        self.translate_cow(key, language, args).map(Cow::into_owned)
+    }
 
-        let language_id = self
-            .languages
+    /// Like [`Translator::translate`], taking arguments from a map built at runtime.
+    #[cfg(feature = "std")]
+    pub fn translate_map<K: AsRef<str>, V: AsRef<str>, H>(
+        &self,
+        key: &str,
+        language: &str,
+        args: &std::collections::HashMap<K, V, H>,
+    ) -> Result<String, Error> {
+        let args = args
             .iter()
-            .position(|lang| *lang == language)
-            .ok_or_else(|| Error::UnknownLanguage(language.into()))?;
-        let message_to_translate = &translation.translations[&language_id];
+            .map(|(argument, value)| (argument.as_ref(), value.as_ref()));
 
-        let mut arguments = Vec::new();
-        let mut values_to_replace = Vec::new();
+        self.translate(key, language, args)
+    }
 
-        for (argument_received, value_to_replace) in args {
-            let argument_received = argument_received.into();
+    /// Like [`Translator::translate`], but borrows the stored message when there's
+    /// nothing to replace in it, i.e. when no arguments are given or none of them occur.
+    pub fn translate_cow<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<Cow<'_, str>, Error> {
+        let translation = self.text(key, language)?;
 
-            // Check if we are expecting this argument
-            translation
-                .arguments
-                .iter()
-                .find(|arg| *arg == argument_received)
-                .ok_or_else(|| Error::UnknownArgument(argument_received.clone()))?;
+        self.translate_found(translation, key, language, args, &|key| {
+            self.translations.get(key)
+        })
+    }
 
-            if arguments.contains(&argument_received) {
-                return Err(Error::DuplicatedArgument(argument_received));
-            } else {
-                arguments.push(argument_received);
-                values_to_replace.push(value_to_replace.into());
-            }
+    /// Like [`Translator::translate_cow`], with `translation` already found under `key`,
+    /// and the keys its message references found through `find`.
+    pub(crate) fn translate_found<
+        'a,
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        translation: &'a Translation,
+        key: &str,
+        language: &str,
+        args: I,
+        find: FindText<'_>,
+    ) -> Result<Cow<'a, str>, Error> {
+        let (message_to_translate, format) = self.message_for(translation, key, language)?;
+
+        if self.has_references(message_to_translate) {
+            let mut output = String::new();
+            self.translate_with_references_in(&mut output, key, language, args, find)?;
+            return Ok(Cow::Owned(output));
+        }
+
+        #[cfg(feature = "tracing")]
+        let args =
+            self.trace_unused_arguments(translation, key, language, message_to_translate, args);
+
+        let mut args = args.into_iter().peekable();
+        if args.peek().is_none() && translation.is_kept_without_values(format, message_to_translate)
+        {
+            return Ok(Cow::Borrowed(message_to_translate));
+        }
+
+        translation.replace(&self.argument_table, format, message_to_translate, args)
+    }
+
+    /// Like [`Translator::translate`], but appends the translation to `writer`
+    /// instead of allocating a new `String`.
+    pub fn translate_into<
+        W: fmt::Write,
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        writer: &mut W,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<(), Error> {
+        let (translation, message_to_translate, format) = self.lookup(key, language)?;
+
+        if self.has_references(message_to_translate) {
+            return self.translate_with_references(writer, key, language, args);
+        }
+
+        #[cfg(feature = "tracing")]
+        let args =
+            self.trace_unused_arguments(translation, key, language, message_to_translate, args);
+
+        let mut args = args.into_iter().peekable();
+        if args.peek().is_none() && translation.is_kept_without_values(format, message_to_translate)
+        {
+            return writer.write_str(message_to_translate).map_err(Into::into);
+        }
+
+        translation.replace_into(
+            &self.argument_table,
+            format,
+            writer,
+            message_to_translate,
+            args,
+        )
+    }
+
+    /// Like [`Translator::translate`], but fails with [`Error::UnreplacedPlaceholder`] if
+    /// the message of `key` in `language` has the placeholder of an argument that `args`
+    /// left out.
+    ///
+    /// Only the message itself is checked, so escaped placeholders and values spelling
+    /// out a placeholder are fine.
+    pub fn translate_checked<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<String, Error> {
+        let args: Vec<(SmallStr, SmallStr)> = args
+            .into_iter()
+            .map(|(argument, value)| (argument.into(), value.into()))
+            .collect();
+
+        let output = self.translate(key, language, args.iter().cloned())?;
+
+        let (translation, message, _) = self.lookup(key, language)?;
+        let arguments = translation.arguments.resolve(&self.argument_table);
+
+        // Skip matches of the escaped opening delimiter, and optional arguments, which
+        // are replaced by nothing when left out
+        let leftover = translation
+            .automaton
+            .try_find_iter(message.as_str())?
+            .filter_map(|found| translation.argument_of(found.pattern().as_usize()))
+            .map(|(index, _)| index)
+            .filter(|index| !translation.optional_arguments.contains(index))
+            .find(|&index| {
+                let name = arguments.name(index);
+                args.iter().all(|(argument, _)| argument != name)
+            });
+
+        match leftover {
+            Some(index) => Err(Error::UnreplacedPlaceholder(arguments.name(index).clone())),
+            None => Ok(output),
+        }
+    }
+
+    /// Like [`Translator::translate`], but also returns the code of the language whose
+    /// message was translated, which differs from `language` when its fallback chain was
+    /// walked, e.g. for the `lang` attribute of an HTML element.
+    pub fn translate_resolved<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<(String, &str), Error> {
+        let output = self.translate(key, language, args)?;
+
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+        let (language_id, pseudo) = self.find_target(language)?;
+        let (resolved, _) = self
+            .resolve_language(translation, language_id)
+            .ok_or_else(|| Error::UntranslatedForLanguage(key.into(), language.into()))?;
+
+        match pseudo {
+            Pseudo::Off => Ok((output, &self.languages[resolved])),
+            _ => Ok((output, pseudo::PSEUDO_LOCALE)),
+        }
+    }
+
+    /// Like [`Translator::translate`], but returns `None` when the translation fails,
+    /// e.g. when `key` is missing or `language` is unknown.
+    pub fn try_translate<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Option<String> {
+        self.translate(key, language, args).ok()
+    }
+
+    /// Like [`Translator::translate`], but returns `default` as-is when the translation
+    /// fails, e.g. when `key` is missing or `language` is unknown.
+    ///
+    /// With the `tracing` feature, any other error is logged as a warning.
+    pub fn translate_or<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        default: &str,
+        args: I,
+    ) -> String {
+        self.translate(key, language, args).unwrap_or_else(|err| {
+            // Missing keys and languages are what a default is for, other errors are bugs
+            #[cfg(feature = "tracing")]
+            if !matches!(err, Error::MissingKey(_) | Error::UnknownLanguage(_)) {
+                tracing::warn!(key, lang = language, error = %err, "translated to the default");
+            }
+            #[cfg(not(feature = "tracing"))]
+            let _ = err;
+
+            default.into()
+        })
+    }
+
+    /// Like [`Translator::translate_or`], falling back to `key` itself.
+    pub fn translate_or_key<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> String {
+        self.translate_or(key, language, key, args)
+    }
+
+    /// Like [`Translator::translate`], but returns a [`SmallStr`], which keeps short
+    /// translations inline instead of allocating them.
+    pub fn translate_compact<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<SmallStr, Error> {
+        let mut output = SmallStr::default();
+        self.translate_into(&mut output, key, language, args)?;
+
+        Ok(output)
+    }
+
+    /// Translates a message whose arguments are the positional tokens `{0}`, `{1}`, ...,
+    /// replacing each `{i}` by `args[i]`.
+    ///
+    /// The key must have been declared with exactly as many arguments as `args` holds.
+    /// When [delimiters](Translator::with_delimiters) are set, arguments are the bare
+    /// indices `0`, `1`, ... instead, since the delimiters already surround them.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_text("welcome", ["{0}", "{1}"], [("en", "Welcome to {1}, {0}!")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate_positional("welcome", "en", &["Julian", "Lisbon"])?,
+    ///     "Welcome to Lisbon, Julian!"
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn translate_positional<S: Clone + Into<SmallStr>>(
+        &self,
+        key: &str,
+        language: &str,
+        args: &[S],
+    ) -> Result<String, Error> {
+        let expected = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?
+            .arguments
+            .resolve(&self.argument_table)
+            .len();
+
+        if args.len() != expected {
+            return Err(Error::ArgumentCountMismatch {
+                expected,
+                got: args.len(),
+            });
+        }
+
+        let args = args
+            .iter()
+            .enumerate()
+            .map(|(index, value)| match self.delimiters {
+                Some(_) => (format_compact!("{index}"), value.clone()),
+                None => (format_compact!("{{{index}}}"), value.clone()),
+            });
+
+        self.translate(key, language, args)
+    }
+
+    /// Every language known to this translator, in sorted order.
+    pub fn languages(&self) -> impl Iterator<Item = &str> {
+        self.languages.iter().map(SmallStr::as_str)
+    }
+
+    /// Every registered message key, in arbitrary order.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en", "pt"]);
+    /// tr.add_text("hello", ["NAME"], [("en", "Hello, NAME!"), ("pt", "Olá, NAME!")])?;
+    /// tr.add_text("bye", ["NAME"], [("en", "Bye, NAME!"), ("pt", "Tchau, NAME!")])?;
+    ///
+    /// let mut keys: Vec<_> = tr.keys().collect();
+    /// keys.sort();
+    /// assert_eq!(keys, ["bye", "hello"]);
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.translations.keys().map(SmallStr::as_str)
+    }
+
+    /// The arguments declared for `key`, in the order they were given.
+    pub fn arguments_for(&self, key: &str) -> Result<impl Iterator<Item = &str>, Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let arguments = translation.arguments.resolve(&self.argument_table);
+
+        Ok(translation
+            .declared_order
+            .iter()
+            .map(move |&index| arguments.name(index).as_str()))
+    }
+
+    /// Checks that `arg_names` could be given to [`Translator::translate`] for `key`,
+    /// without translating anything: each name must be one of its arguments, given once.
+    ///
+    /// Fails with [`Error::UnknownArgument`] or [`Error::DuplicatedArgument`] naming the
+    /// first offending argument.
+    pub fn check_arguments(&self, key: &str, arg_names: &[&str]) -> Result<(), Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let arguments = translation.arguments.resolve(&self.argument_table);
+        let mut received = vec![false; arguments.len()];
+        for name in arg_names {
+            translation.receive(name, &mut received)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether `arg` is one of the arguments declared for `key`, by the name given to
+    /// [`Translator::translate`].
+    pub fn key_uses_argument(&self, key: &str, arg: &str) -> Result<bool, Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        Ok(translation.arguments_by_name.contains_key(arg))
+    }
+
+    /// Every key declaring the argument `arg`, sorted, e.g. to find the messages to
+    /// update when renaming a placeholder.
+    pub fn keys_using_argument(&self, arg: &str) -> Vec<&str> {
+        let mut keys: Vec<_> = self
+            .translations
+            .iter()
+            .filter(|(_, translation)| translation.arguments_by_name.contains_key(arg))
+            .map(|(key, _)| key.as_str())
+            .collect();
+        keys.sort_unstable();
+
+        keys
+    }
+
+    /// Renames the argument `from` of `key` to `to`, rewriting its placeholder in the
+    /// message of every language.
+    ///
+    /// The argument keeps its annotations, e.g. renaming `COUNT` declared as `COUNT:number`
+    /// gives `TOTAL:number`. Fails with [`Error::UnknownArgument`] if `key` has no argument
+    /// `from`, and with [`Error::DuplicatedArgument`] if it already has one named `to`.
+    pub fn rename_argument(&mut self, key: &str, from: &str, to: &str) -> Result<(), Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let &renamed = translation
+            .arguments_by_name
+            .get(from)
+            .ok_or_else(|| Error::UnknownArgument(from.into()))?;
+        if translation.arguments_by_name.contains_key(to) {
+            return Err(Error::DuplicatedArgument(to.into()));
+        }
+
+        // The arguments as they were declared, annotations included
+        let arguments = translation.arguments.resolve(&self.argument_table);
+        let declare = |index: usize| {
+            let mut argument = SmallStr::from(match index == renamed {
+                true => to,
+                false => arguments.name(index).as_str(),
+            });
+            translation.push_annotation(index, &mut argument);
+            argument
+        };
+
+        // The new placeholder of the argument, cased with `suffix` if any
+        let renamed_declared = declare(renamed);
+        let placeholder = |suffix: &str| match &self.delimiters {
+            Some((open, close)) => format_compact!("{open}{renamed_declared}{suffix}{close}"),
+            None => format_compact!("{renamed_declared}{suffix}"),
+        };
+
+        let declared: Vec<SmallStr> = translation
+            .declared_order
+            .iter()
+            .map(|&index| {
+                let mut argument = declare(index);
+                if translation.optional_arguments.contains(&index) {
+                    argument.push(OPTIONAL_ANNOTATION);
+                }
+                argument
+            })
+            .collect();
+
+        let mut messages = Vec::with_capacity(translation.translations.len());
+        for (&language_id, message) in &translation.translations {
+            let mut rewritten = SmallStr::default();
+            let mut last_end = 0;
+            for found in translation.automaton.try_find_iter(message.as_str())? {
+                rewritten.push_str(&message[last_end..found.start()]);
+                match translation.argument_of(found.pattern().as_usize()) {
+                    Some((index, case)) if index == renamed => {
+                        let suffix = CASE_ANNOTATIONS
+                            .iter()
+                            .find(|(annotation, _)| Some(*annotation) == case)
+                            .map_or("", |(_, suffix)| suffix);
+                        rewritten.push_str(&placeholder(suffix));
+                    }
+                    _ => rewritten.push_str(&message[found.range()]),
+                }
+                last_end = found.end();
+            }
+            rewritten.push_str(&message[last_end..]);
+
+            messages.push((self.languages[language_id].clone(), rewritten));
+        }
+
+        let mut translation = self.build_text_translation(declared, messages, false)?;
+        translation.arguments = self.store_arguments(translation.arguments);
+        self.store_messages(&mut translation.translations);
+        self.translations.insert(key.into(), translation);
+
+        Ok(())
+    }
+
+    /// The message `key` would be translated from into `language`, walking its fallback
+    /// chain if needed, with its placeholders untouched.
+    ///
+    /// Unlike [`Translator::translate`], this borrows the stored message and skips
+    /// replacing arguments altogether.
+    pub fn message(&self, key: &str, language: &str) -> Option<&str> {
+        self.lookup(key, language)
+            .ok()
+            .map(|(_, message, _)| message.as_str())
+    }
+
+    /// Every `(language, message)` pair registered for `key`, in sorted language order.
+    ///
+    /// Messages are returned as stored, with their placeholders untouched.
+    pub fn translations_for(&self, key: &str) -> Result<impl Iterator<Item = (&str, &str)>, Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let pairs = self
+            .languages
+            .iter()
+            .enumerate()
+            .filter_map(|(language_id, language)| {
+                let message = translation.translations.get(&language_id)?;
+                Some((language.as_str(), message.as_str()))
+            });
+
+        Ok(pairs)
+    }
+
+    /// The languages, in sorted order, that have no message for `key`.
+    pub fn missing_languages(&self, key: &str) -> Result<Vec<&str>, Error> {
+        let translation = self
+            .translations
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let missing = self
+            .languages
+            .iter()
+            .enumerate()
+            .filter(|(language_id, _)| !translation.translations.contains_key(language_id))
+            .map(|(_, language)| language.as_str())
+            .collect();
+
+        Ok(missing)
+    }
+
+    /// Whether a message was registered under `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.translations.contains_key(key)
+    }
+
+    /// Whether `language` is one of the languages known to this translator.
+    pub fn has_language(&self, language: &str) -> bool {
+        self.position_of(language).is_some()
+    }
+
+    /// The number of registered message keys.
+    pub fn len(&self) -> usize {
+        self.translations.len()
+    }
+
+    /// Whether no message keys were registered yet.
+    pub fn is_empty(&self) -> bool {
+        self.translations.is_empty()
+    }
+
+    /// Fetches the translation of `key` along with its message for `language`,
+    /// and how to format argument values within it.
+    fn lookup(
+        &self,
+        key: &str,
+        language: &str,
+    ) -> Result<(&Translation, &Message, ValueFormat), Error> {
+        let translation = self.text(key, language)?;
+        let (message, format) = self.message_for(translation, key, language)?;
+
+        Ok((translation, message, format))
+    }
+
+    /// Fetches the translation of the text key `key`, to translate it into `language`.
+    fn text(&self, key: &str, language: &str) -> Result<&Translation, Error> {
+        let Some(translation) = self.translations.get(key) else {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(key, lang = language, "key not found");
+            #[cfg(not(feature = "tracing"))]
+            let _ = language;
+
+            return Err(Error::MissingKey(key.into()));
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(key, lang = language, "key found");
+
+        Ok(translation)
+    }
+
+    /// Fetches the message of `translation`, registered under `key`, for `language`,
+    /// along with how to format argument values within it.
+    fn message_for<'a, M>(
+        &self,
+        translation: &'a Translation<M>,
+        key: &str,
+        language: &str,
+    ) -> Result<(&'a M, ValueFormat), Error> {
+        let (language_id, pseudo) = self.find_target(language)?;
+        let (_, message) = self.message_in(translation, key, language, language_id)?;
+
+        Ok((message, self.value_format(language_id, pseudo)))
+    }
+
+    /// Fetches the message of `translation`, registered under `key`, for `language`,
+    /// found as `language_id`, walking its fallback chain if needed. Also returns the
+    /// language whose message it is.
+    fn message_in<'a, M>(
+        &self,
+        translation: &'a Translation<M>,
+        key: &str,
+        language: &str,
+        language_id: LanguageId,
+    ) -> Result<(LanguageId, &'a M), Error> {
+        let (found_id, message) = self
+            .resolve_language(translation, language_id)
+            .ok_or_else(|| Error::UntranslatedForLanguage(key.into(), language.into()))?;
+
+        #[cfg(feature = "tracing")]
+        if found_id != language_id {
+            tracing::warn!(
+                key,
+                lang = language,
+                fallback = self.languages[found_id].as_str(),
+                "translated through a fallback language"
+            );
+        }
+
+        Ok((found_id, message))
+    }
+
+    /// How argument values are formatted within messages of `language_id`, whose text is
+    /// altered as `pseudo` says.
+    fn value_format(&self, language_id: LanguageId, pseudo: Pseudo) -> ValueFormat {
+        ValueFormat {
+            require_all: self.require_all_arguments,
+            grouping_separator: self.grouping_separators[language_id],
+            list_patterns: self.list_patterns[language_id],
+            isolate: self.isolate_rtl && bidi::is_rtl(&self.languages[language_id]),
+            turkic_casing: case::is_turkic(&self.languages[language_id]),
+            pseudo,
+        }
+    }
+
+    /// Sorts the entries of exported keys, by the [collation](Translator::with_collation)
+    /// if one is set, or lexicographically otherwise.
+    pub(crate) fn sort_keys<T>(&self, entries: &mut [(&SmallStr, T)]) {
+        #[cfg(feature = "collation")]
+        if let Some(collation) = &self.collation {
+            entries.sort_unstable_by(|(left, _), (right, _)| collation.compare(left, right));
+            return;
+        }
+
+        entries.sort_unstable_by_key(|(key, _)| *key);
+    }
+
+    /// Finds `language` among the known languages or their aliases.
+    fn find_language(&self, language: &str) -> Result<LanguageId, Error> {
+        self.position_of(language)
+            .or_else(|| match self.case_insensitive_languages {
+                true => self
+                    .aliases
+                    .iter()
+                    .find_map(|(alias, &id)| alias.eq_ignore_ascii_case(language).then_some(id)),
+                false => self.aliases.get(language).copied(),
+            })
+            .ok_or_else(|| Error::UnknownLanguage(language.into()))
+    }
+
+    /// Finds `language` among the known languages, ignoring aliases.
+    fn position_of(&self, language: &str) -> Option<LanguageId> {
+        self.languages
+            .iter()
+            .position(|lang| match self.case_insensitive_languages {
+                true => lang.eq_ignore_ascii_case(language),
+                false => lang == language,
+            })
+    }
+
+    /// Whether any single-language catalog was loaded while waiting for other languages.
+    #[cfg(not(any(
+        feature = "gettext",
+        feature = "android",
+        feature = "apple",
+        feature = "arb"
+    )))]
+    fn has_pending_catalogs(&self) -> bool {
+        false
+    }
+
+    /// Finds the message for `language_id`, walking its fallback chain if needed.
+    fn resolve_message<'a, M>(
+        &self,
+        translation: &'a Translation<M>,
+        language_id: LanguageId,
+    ) -> Option<&'a M> {
+        self.resolve_language(translation, language_id)
+            .map(|(_, message)| message)
+    }
+
+    /// Like [`Translator::resolve_message`], but also returns the language the message
+    /// was found in.
+    fn resolve_language<'a, M>(
+        &self,
+        translation: &'a Translation<M>,
+        language_id: LanguageId,
+    ) -> Option<(LanguageId, &'a M)> {
+        let chain = self.fallbacks.get(&language_id).map(AsRef::as_ref);
+
+        core::iter::once(&language_id)
+            .chain(chain.unwrap_or_default())
+            .find_map(|&id| Some((id, translation.translations.get(&id)?)))
+    }
+}
+
+impl<M> Translation<M> {
+    /// The argument whose placeholder is pattern `pattern` of `self.automaton`, along with
+    /// the case its value is changed to, if any, or `None` for the escaped opening delimiter.
+    fn argument_of(&self, pattern: usize) -> Option<(usize, Option<Case>)> {
+        match self.patterns[pattern] {
+            Pattern::Argument(index) => Some((index, None)),
+            Pattern::EscapedDelimiter => None,
+            Pattern::Cased(cased) => {
+                let (index, case, _) = self.cased_placeholders[cased];
+                Some((index, Some(case)))
+            }
+        }
+    }
+
+    /// The annotation of the formatter of argument `index`, if any, as in `currency` for
+    /// `price:currency`.
+    fn formatter_of(&self, index: usize) -> Option<&str> {
+        self.formatted_arguments
+            .iter()
+            .find(|(formatted, _)| *formatted == index)
+            .map(|(_, annotation)| annotation.as_str())
+    }
+
+    /// Appends to `argument` the annotation argument `index` was declared with, if any,
+    /// as in `:number`.
+    fn push_annotation(&self, index: usize, argument: &mut SmallStr) {
+        if self.number_arguments.contains(&index) {
+            argument.push_str(NUMBER_ANNOTATION);
+        } else if self.list_arguments.contains(&index) {
+            argument.push_str(LIST_ANNOTATION);
+        } else if let Some(annotation) = self.formatter_of(index) {
+            argument.push(':');
+            argument.push_str(annotation);
         }
+    }
+
+    /// The arguments of this translation as they were declared, with their annotations.
+    #[cfg(any(
+        test,
+        feature = "archive",
+        feature = "json",
+        feature = "serde",
+        feature = "test-util"
+    ))]
+    fn declared_arguments(&self, table: &ArgumentTable) -> Vec<SmallStr> {
+        let arguments = self.arguments.resolve(table);
+
+        self.declared_order
+            .iter()
+            .map(|&index| {
+                let mut declared = arguments.name(index).clone();
+                self.push_annotation(index, &mut declared);
+                if self.optional_arguments.contains(&index) {
+                    declared.push(OPTIONAL_ANNOTATION);
+                }
+                declared
+            })
+            .collect()
+    }
+
+    /// Whether argument `index`, whose placeholder is `placeholder`, occurs in `message`,
+    /// cased or not.
+    fn occurs_in(&self, message: &str, placeholder: &str, index: usize) -> bool {
+        message.contains(placeholder)
+            || self
+                .cased_placeholders
+                .iter()
+                .any(|(cased, _, placeholder)| {
+                    *cased == index && message.contains(placeholder.as_str())
+                })
+    }
+
+    /// Replaces the arguments found in `message` by the values given in `args`.
+    ///
+    /// Borrows `message` if none of its arguments occur in it.
+    fn replace<'m, S1: Into<SmallStr>, S2: Into<SmallStr>, I: IntoIterator<Item = (S1, S2)>>(
+        &self,
+        table: &ArgumentTable,
+        format: ValueFormat,
+        message: &'m str,
+        args: I,
+    ) -> Result<Cow<'m, str>, Error> {
+        if format.pseudo != Pseudo::Off {
+            let mut output = String::new();
+            self.replace_into(table, format, &mut output, message, args)?;
+            return Ok(Cow::Owned(output));
+        }
+
+        let values_to_replace = self.values_to_replace(table, format, args)?;
+
+        if self.automaton.try_find(message)?.is_none() {
+            return Ok(Cow::Borrowed(message));
+        }
+
+        self.automaton
+            .try_replace_all(message, &values_to_replace)
+            .map(Cow::Owned)
+            .map_err(Into::into)
+    }
+
+    /// Whether `message` is translated as-is when no values are given, so that no
+    /// values need to be collected nor matches searched for.
+    ///
+    /// That's not the case if arguments must be given, optional ones must be left out of
+    /// it, it has an escaped delimiter to unescape, or it is pseudolocalized.
+    fn is_kept_without_values(&self, format: ValueFormat, message: &str) -> bool {
+        !format.require_all
+            && format.pseudo == Pseudo::Off
+            && self.optional_arguments.is_empty()
+            && self.unescaped_delimiter.as_ref().is_none_or(|open| {
+                !message
+                    .match_indices('\\')
+                    .any(|(at, _)| message[at + 1..].starts_with(open.as_str()))
+            })
+    }
+
+    /// Like [`Translation::replace`], but writes the result into `writer`.
+    fn replace_into<
+        W: fmt::Write + ?Sized,
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        table: &ArgumentTable,
+        format: ValueFormat,
+        writer: &mut W,
+        message: &str,
+        args: I,
+    ) -> Result<(), Error> {
+        let values_to_replace = self.values_to_replace(table, format, args)?;
+
+        match format.pseudo {
+            Pseudo::Off => {
+                self.write_replaced(writer, message, &values_to_replace, |writer, text| {
+                    writer.write_str(text)
+                })
+            }
+            Pseudo::Accented => {
+                self.write_replaced(writer, message, &values_to_replace, pseudo::write_accented)
+            }
+            Pseudo::Expanded => pseudo::write_expanded(writer, |writer| {
+                self.write_replaced(writer, message, &values_to_replace, pseudo::write_accented)
+            }),
+        }
+    }
+
+    /// Writes `message` into `writer`, replacing the arguments found in it by
+    /// `values_to_replace` and writing the text around them through `write_literal`.
+    fn write_replaced<W: fmt::Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        message: &str,
+        values_to_replace: &[SmallStr],
+        write_literal: fn(&mut W, &str) -> fmt::Result,
+    ) -> Result<(), Error> {
+        let mut last_end = 0;
+        for found in self.automaton.try_find_iter(message)? {
+            write_literal(writer, &message[last_end..found.start()])?;
+            writer.write_str(&values_to_replace[found.pattern().as_usize()])?;
+            last_end = found.end();
+        }
+        write_literal(writer, &message[last_end..])?;
+
+        Ok(())
+    }
+
+    /// Finds the index of `argument`, marking it in `received`, which holds whether
+    /// each argument was already given.
+    ///
+    /// Fails if `argument` is not one of the arguments or was already given.
+    fn receive(&self, argument: &str, received: &mut [bool]) -> Result<usize, Error> {
+        let index = *self
+            .arguments_by_name
+            .get(argument)
+            .ok_or_else(|| Error::UnknownArgument(argument.into()))?;
+
+        if core::mem::replace(&mut received[index], true) {
+            return Err(Error::DuplicatedArgument(argument.into()));
+        }
+
+        Ok(index)
+    }
+
+    /// Orders the values given in `args` to match the patterns of `self.automaton`,
+    /// formatted according to `format`.
+    fn values_to_replace<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        table: &ArgumentTable,
+        format: ValueFormat,
+        args: I,
+    ) -> Result<Vec<SmallStr>, Error> {
+        let arguments = self.arguments.resolve(table);
+
+        // Arguments that were not supplied are replaced by themselves, leaving them untouched
+        let mut values_to_replace: Vec<SmallStr> = arguments.placeholders().cloned().collect();
+        let mut received = vec![false; arguments.len()];
+
+        for (argument_received, value_to_replace) in args {
+            let index = self.receive(&argument_received.into(), &mut received)?;
+            values_to_replace[index] = value_to_replace.into();
+        }
+
+        for &index in self
+            .declared_order
+            .iter()
+            .filter(|&&index| !received[index])
+        {
+            if self.optional_arguments.contains(&index) {
+                values_to_replace[index] = SmallStr::default();
+            } else if format.require_all {
+                return Err(Error::MissingArgument(arguments.name(index).clone()));
+            }
+        }
+
+        for &index in self.number_arguments.iter() {
+            if let Some(number) = received[index]
+                .then(|| values_to_replace[index].parse::<i64>().ok())
+                .flatten()
+            {
+                values_to_replace[index] = number::group_digits(number, format.grouping_separator);
+            }
+        }
+
+        for &index in self.list_arguments.iter().filter(|&&index| received[index]) {
+            let items: Vec<&str> = values_to_replace[index]
+                .split(list::ITEM_SEPARATOR)
+                .collect();
+            values_to_replace[index] = list::join(format.list_patterns, ListStyle::And, &items);
+        }
+
+        if format.isolate {
+            for (value, _) in values_to_replace
+                .iter_mut()
+                .zip(&received)
+                .filter(|(_, received)| **received)
+            {
+                *value = bidi::isolate(value);
+            }
+        }
+
+        values_to_replace.extend(self.unescaped_delimiter.clone());
+
+        // Without cased placeholders, patterns are the arguments in order followed by the
+        // escaped delimiter, just like the values
+        if self.cased_placeholders.is_empty() {
+            return Ok(values_to_replace);
+        }
+
+        // Arguments left out keep their cased placeholder, except for optional ones
+        let values = self
+            .patterns
+            .iter()
+            .map(|&pattern| match pattern {
+                Pattern::Argument(index) => values_to_replace[index].clone(),
+                Pattern::EscapedDelimiter => values_to_replace[arguments.len()].clone(),
+                Pattern::Cased(cased) => {
+                    let (index, case, ref placeholder) = self.cased_placeholders[cased];
+                    match received[index] || self.optional_arguments.contains(&index) {
+                        true => {
+                            case::change_case(&values_to_replace[index], case, format.turkic_casing)
+                        }
+                        false => placeholder.clone(),
+                    }
+                }
+            })
+            .collect();
+
+        Ok(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{Error, MatchKind, PluralCategory, Translator};
+
+    #[test]
+    fn one_argument() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en", "it"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Good morning, NAME!"),
+                ("pt", "Bom dia, NAME!"),
+                ("it", "Buongiorno, NAME!"),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "it", [("NAME", "Julian")])?,
+            "Buongiorno, Julian!"
+        );
+
+        // Validations
+        assert_eq!(
+            tr.translate("greetings", "cz", [("NAME", "Julian")]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NOME", "Julian")]),
+            Err(Error::UnknownArgument("NOME".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arguments_in_any_order_and_subset() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "welcome",
+            ["NAME", "PLACE"],
+            [
+                ("en", "Welcome to PLACE, NAME!"),
+                ("pt", "Bem-vindo a PLACE, NAME!"),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("welcome", "en", [("PLACE", "Lisbon"), ("NAME", "Julian")])?,
+            "Welcome to Lisbon, Julian!"
+        );
+        assert_eq!(
+            tr.translate("welcome", "pt", [("NAME", "Julian"), ("PLACE", "Lisboa")])?,
+            "Bem-vindo a Lisboa, Julian!"
+        );
+
+        // Arguments that are not given are left as-is
+        assert_eq!(
+            tr.translate("welcome", "en", [("NAME", "Julian")])?,
+            "Welcome to PLACE, Julian!"
+        );
+
+        assert_eq!(
+            tr.translate("welcome", "en", [("NAME", "Julian"), ("NAME", "Kyle")]),
+            Err(Error::DuplicatedArgument("NAME".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping_arguments_in_any_order() -> Result<(), Error> {
+        for match_kind in [MatchKind::LeftmostLongest, MatchKind::LeftmostFirst] {
+            let mut tr = Translator::new(["en"]).with_match_kind(match_kind);
+            tr.add_text("pair", ["NAME", "NAME2"], [("en", "NAME and NAME2")])?;
+            tr.add_text("reversed", ["NAME2", "NAME"], [("en", "NAME2 and NAME")])?;
+
+            // Matches depend on the stored, longest-first order of arguments alone
+            for key in ["pair", "reversed"] {
+                let given_first =
+                    tr.translate(key, "en", [("NAME", "Julian"), ("NAME2", "Kyle")])?;
+                let given_last =
+                    tr.translate(key, "en", [("NAME2", "Kyle"), ("NAME", "Julian")])?;
+                assert_eq!(given_first, given_last);
+            }
+            assert_eq!(
+                tr.translate("pair", "en", [("NAME2", "Kyle"), ("NAME", "Julian")])?,
+                "Julian and Kyle"
+            );
+            assert_eq!(
+                tr.translate("reversed", "en", [("NAME", "Julian"), ("NAME2", "Kyle")])?,
+                "Kyle and Julian"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn extend_texts() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.extend_texts([
+            (
+                "greetings",
+                vec!["NAME"],
+                vec![("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+            ),
+            ("bye", vec![], vec![("en", "Bye!"), ("pt", "Tchau!")]),
+            (
+                "thanks",
+                vec![],
+                vec![("en", "Thanks!"), ("pt", "Obrigado!")],
+            ),
+        ])?;
+        assert_eq!(tr.len(), 3);
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
+            "Olá, Julian!"
+        );
+
+        let mut tr = Translator::new(["en", "pt"]);
+        assert_eq!(
+            tr.extend_texts([
+                (
+                    "greetings",
+                    vec!["NAME"],
+                    vec![("en", "Hi, NAME!"), ("pt", "Olá, NAME!")]
+                ),
+                ("bye", vec![], vec![("en", "Bye!")]),
+                (
+                    "thanks",
+                    vec![],
+                    vec![("en", "Thanks!"), ("pt", "Obrigado!")]
+                ),
+            ]),
+            Err(Error::MissingLanguages(vec!["pt".into()]))
+        );
+        assert!(tr.contains_key("greetings"));
+        assert!(!tr.contains_key("bye"));
+        assert!(!tr.contains_key("thanks"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn add_language() -> Result<(), Error> {
+        let mut tr = Translator::default();
+        tr.add_language("pt")?;
+        tr.add_language("it")?;
+        tr.set_fallback_chain("pt", ["it"])?;
+        tr.add_alias("pt-BR", "pt")?;
+        // Sorted before both, shifting their ids
+        tr.add_language("en")?;
+
+        assert_eq!(tr.languages().collect::<Vec<_>>(), ["en", "it", "pt"]);
+        assert_eq!(
+            tr.add_language("pt"),
+            Err(Error::DuplicatedLanguage("pt".into()))
+        );
+
+        tr.add_text_partial(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("it", "Ciao, NAME!")],
+        )?;
+        assert_eq!(
+            tr.translate("greetings", "pt-BR", [("NAME", "Julian")])?,
+            "Ciao, Julian!"
+        );
+        assert_eq!(tr.format_number("pt", 1234)?, "1.234");
+
+        assert_eq!(
+            tr.add_language("fr"),
+            Err(Error::LanguageAddedAfterKeys("fr".into()))
+        );
+        assert!(!tr.has_language("fr"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_language() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "it", "pt"]);
+        tr.set_fallback_chain("pt", ["it", "en"])?;
+        tr.add_alias("it-CH", "it")?;
+        tr.add_alias("pt-BR", "pt")?;
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Hi, NAME!"),
+                ("it", "Ciao, NAME!"),
+                ("pt", "Olá, NAME!"),
+            ],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!"), ("it", "Ciao!")])?;
+        tr.add_plural(
+            "apples",
+            ["N"],
+            [
+                ("en", [(PluralCategory::Other, "N apples")]),
+                ("it", [(PluralCategory::Other, "N mele")]),
+                ("pt", [(PluralCategory::Other, "N maçãs")]),
+            ],
+        )?;
+
+        tr.remove_language("it")?;
+
+        assert_eq!(tr.languages().collect::<Vec<_>>(), ["en", "pt"]);
+        assert_eq!(
+            tr.translate("greetings", "pt-BR", [("NAME", "Julian")])?,
+            "Olá, Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Hi, Julian!"
+        );
+        // `pt` now falls back straight to `en`
+        assert_eq!(tr.translate("bye", "pt", [("", ""); 0])?, "Bye!");
+        assert_eq!(
+            tr.translate_plural("apples", "pt", 2, [("N", "2")])?,
+            "2 maçãs"
+        );
+        assert_eq!(tr.format_number("pt", 1234)?, "1.234");
+        assert_eq!(
+            tr.translate("greetings", "it-CH", [("NAME", "Julian")]),
+            Err(Error::UnknownLanguage("it-CH".into()))
+        );
+        assert_eq!(
+            tr.remove_language("it"),
+            Err(Error::UnknownLanguage("it".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn untranslated_languages_do_not_panic() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_text_partial("greetings", ["NAME"], [("en", "Hi, NAME!")])?;
+        let untranslated = || Error::UntranslatedForLanguage("greetings".into(), "pt".into());
+
+        let args = [("NAME", "Julian")];
+        assert_eq!(tr.translate("greetings", "pt", args), Err(untranslated()));
+        assert_eq!(
+            tr.translate_into(&mut String::new(), "greetings", "pt", args),
+            Err(untranslated())
+        );
+        assert_eq!(
+            tr.translate_segments("greetings", "pt", args),
+            Err(untranslated())
+        );
+        let pt = tr.language_id("pt").unwrap();
+        assert_eq!(
+            tr.translate_by_id("greetings", pt, args),
+            Err(untranslated())
+        );
+        assert_eq!(
+            tr.translate_batch("pt", &[("greetings", &args)]),
+            Err(Error::BatchFailed(
+                "greetings".into(),
+                Box::new(untranslated())
+            ))
+        );
+
+        let tr = tr.freeze();
+        assert_eq!(tr.translate("greetings", "pt", args), Err(untranslated()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_translations() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]);
+
+        assert_eq!(
+            tr.add_text("greetings", ["NAME"], [("en", "Good morning, NAME!")]),
+            Err(Error::MissingLanguages(vec!["it".into(), "pt".into()]))
+        );
+
+        tr.add_text_partial("greetings", ["NAME"], [("en", "Good morning, NAME!")])?;
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+        for lang in ["pt", "it"] {
+            assert_eq!(
+                tr.translate("greetings", lang, [("NAME", "Julian")]),
+                Err(Error::UntranslatedForLanguage(
+                    "greetings".into(),
+                    lang.into()
+                ))
+            );
+        }
+
+        assert_eq!(tr.missing_languages("greetings")?, ["it", "pt"]);
+        tr.update_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Good morning, NAME!"),
+                ("pt", "Bom dia, NAME!"),
+                ("it", "Buongiorno, NAME!"),
+            ],
+        )?;
+        assert!(tr.missing_languages("greetings")?.is_empty());
+        assert_eq!(
+            tr.missing_languages("farewell"),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        assert_eq!(
+            tr.add_text_partial("farewell", ["NAME"], [("cz", "Na shledanou, NAME!")]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fallback_chain() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en", "it"]);
+
+        tr.add_text_partial("greetings", ["NAME"], [("en", "Good morning, NAME!")])?;
+
+        assert_eq!(
+            tr.set_fallback_chain("cz", ["en"]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+        assert_eq!(
+            tr.set_fallback_chain("it", ["pt", "cz"]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        assert_eq!(
+            tr.translate("greetings", "it", [("NAME", "Julian")]),
+            Err(Error::UntranslatedForLanguage(
+                "greetings".into(),
+                "it".into()
+            ))
+        );
+
+        tr.set_fallback_chain("it", ["pt", "en"])?;
+        assert_eq!(
+            tr.translate("greetings", "it", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+
+        tr.set_fallback_chain("it", ["pt"])?;
+        assert_eq!(
+            tr.translate("greetings", "it", [("NAME", "Julian")]),
+            Err(Error::UntranslatedForLanguage(
+                "greetings".into(),
+                "it".into()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn introspection() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en", "it", "en"]);
+        assert!(tr.is_empty());
+        assert_eq!(tr.languages().collect::<Vec<_>>(), ["en", "it", "pt"]);
+
+        tr.add_text_partial("greetings", [""; 0], [("en", "Hello!")])?;
+        assert_eq!(tr.len(), 1);
+        assert!(!tr.is_empty());
+        assert_eq!(tr.keys().collect::<Vec<_>>(), ["greetings"]);
+
+        assert!(tr.contains_key("greetings"));
+        assert!(!tr.contains_key("farewell"));
+        assert!(tr.has_language("en"));
+        assert!(tr.has_language("pt"));
+        assert!(!tr.has_language("cz"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_text() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+
+        tr.remove_text("greetings")?;
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")]),
+            Err(Error::MissingKey("greetings".into()))
+        );
+        assert_eq!(
+            tr.remove_text("greetings"),
+            Err(Error::MissingKey("greetings".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn prefixed_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]);
+
+        // Every argument is a prefix of the next one
+        let arguments: Vec<String> = (1..=200).map(|len| "A".repeat(len)).collect();
+        let message = arguments.join(" ");
+        tr.add_text("prefixes", arguments.clone(), [("en".into(), message)])?;
+
+        let args = arguments
+            .iter()
+            .map(|argument| (argument.as_str(), argument.len().to_string()));
+        let expected = (1..=200).map(|len| len.to_string()).collect::<Vec<_>>();
+        assert_eq!(tr.translate("prefixes", "en", args)?, expected.join(" "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn update_text() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        assert_eq!(
+            tr.update_text(
+                "greetings",
+                ["NAME"],
+                [("en", "Hello, NAME!"), ("pt", "Olá, NAME!")]
+            ),
+            Err(Error::MissingKey("greetings".into()))
+        );
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+
+        assert_eq!(
+            tr.update_text("greetings", ["NAME"], [("en", "Hello, NAME!")]),
+            Err(Error::MissingLanguages(vec!["pt".into()]))
+        );
+        // A failed update leaves the previous message in place
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+
+        tr.update_text(
+            "greetings",
+            ["NAME", "PLACE"],
+            [
+                ("en", "Hello, NAME from PLACE!"),
+                ("pt", "Olá, NAME de PLACE!"),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian"), ("PLACE", "Lisbon")])?,
+            "Hello, Julian from Lisbon!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian"), ("PLACE", "Lisboa")])?,
+            "Olá, Julian de Lisboa!"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn positional_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "welcome",
+            ["{0}", "{1}"],
+            [
+                ("en", "Welcome to {1}, {0}!"),
+                ("pt", "{0}, bem-vindo a {1}!"),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate_positional("welcome", "en", &["Julian", "Lisbon"])?,
+            "Welcome to Lisbon, Julian!"
+        );
+        assert_eq!(
+            tr.translate_positional("welcome", "pt", &[String::from("Julian"), "Lisboa".into()])?,
+            "Julian, bem-vindo a Lisboa!"
+        );
+
+        assert_eq!(
+            tr.translate_positional("welcome", "en", &["Julian", "Lisbon", "Kyle"]),
+            Err(Error::ArgumentCountMismatch {
+                expected: 2,
+                got: 3
+            })
+        );
+        assert_eq!(
+            tr.translate_positional("welcome", "en", &["Julian"]),
+            Err(Error::ArgumentCountMismatch {
+                expected: 2,
+                got: 1
+            })
+        );
+
+        // Named arguments cannot be given positionally
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi NAME"), ("pt", "Oi NAME")],
+        )?;
+        assert_eq!(
+            tr.translate_positional("greetings", "en", &["Julian"]),
+            Err(Error::UnknownArgument("{0}".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_cow() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text("bye", [""; 0], [("en", "Goodbye!"), ("pt", "Tchau!")])?;
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+
+        let no_args = [("", ""); 0];
+        assert!(matches!(
+            tr.translate_cow("bye", "pt", no_args)?,
+            Cow::Borrowed("Tchau!")
+        ));
+        assert!(matches!(
+            tr.translate_cow("greetings", "en", no_args)?,
+            Cow::Borrowed("Good morning, NAME!")
+        ));
+        assert_eq!(
+            tr.translate_cow("greetings", "en", [("NAME", "Julian")])?,
+            Cow::<str>::Owned("Good morning, Julian!".into())
+        );
+        assert_eq!(
+            tr.translate_cow("bye", "en", [("NAME", "Julian")]),
+            Err(Error::UnknownArgument("NAME".into()))
+        );
+
+        // Escaped delimiters are still unescaped without values
+        let mut escaped = Translator::new(["en"]).with_delimiters("{", "}");
+        escaped.add_text("template", ["NAME"], [("en", r"Hi {NAME}! Write \{NAME}")])?;
+        escaped.add_text("plain", ["NAME"], [("en", r"Hi {NAME}\n")])?;
+        assert_eq!(
+            escaped.translate_cow("template", "en", no_args)?,
+            Cow::<str>::Owned("Hi {NAME}! Write {NAME}".into())
+        );
+        assert!(matches!(
+            escaped.translate_cow("plain", "en", no_args)?,
+            Cow::Borrowed(r"Hi {NAME}\n")
+        ));
+
+        let mut output = String::new();
+        escaped.translate_into(&mut output, "plain", "en", no_args)?;
+        assert_eq!(output, r"Hi {NAME}\n");
+
+        // Declared and given, but not used by this message
+        tr.add_text(
+            "welcome",
+            ["NAME"],
+            [("en", "Welcome, NAME!"), ("pt", "Bem-vindo!")],
+        )?;
+        assert!(matches!(
+            tr.translate_cow("welcome", "pt", [("NAME", "Julian")])?,
+            Cow::Borrowed("Bem-vindo!")
+        ));
+        assert_eq!(
+            tr.translate("welcome", "pt", [("NAME", "Julian")])?,
+            "Bem-vindo!"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_into() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "welcome",
+            ["NAME", "PLACE"],
+            [
+                ("en", "Welcome to PLACE, NAME!"),
+                ("pt", "Bem-vindo a PLACE, NAME!"),
+            ],
+        )?;
+
+        let mut output = String::from("> ");
+        tr.translate_into(
+            &mut output,
+            "welcome",
+            "en",
+            [("NAME", "Julian"), ("PLACE", "Lisbon")],
+        )?;
+        assert_eq!(output, "> Welcome to Lisbon, Julian!");
+
+        output.push_str(" | ");
+        tr.translate_into(&mut output, "welcome", "pt", [("NAME", "Julian")])?;
+        assert_eq!(
+            output,
+            "> Welcome to Lisbon, Julian! | Bem-vindo a PLACE, Julian!"
+        );
+
+        assert_eq!(
+            tr.translate_into(&mut output, "welcome", "cz", [("NAME", "Julian")]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_or() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+        )?;
+
+        assert_eq!(
+            tr.translate_or("greetings", "pt", "Hello!", [("NAME", "Julian")]),
+            "Olá, Julian!"
+        );
+        assert_eq!(
+            tr.translate_or_key("greetings", "en", [("NAME", "Julian")]),
+            "Hi, Julian!"
+        );
+        assert_eq!(
+            tr.translate_or("farewell", "pt", "Bye, NAME!", [("NAME", "Julian")]),
+            "Bye, NAME!"
+        );
+        assert_eq!(
+            tr.translate_or("greetings", "cz", "Hello!", [("NAME", "Julian")]),
+            "Hello!"
+        );
+        assert_eq!(
+            tr.translate_or_key("farewell", "en", [("NAME", "Julian")]),
+            "farewell"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn optional_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]).with_strict_arguments(true);
+
+        tr.add_text(
+            "greetings",
+            ["NAME", "TITLE?"],
+            [("en", "Hi, TITLENAME!"), ("pt", "Olá, NAME!")],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Hi, Julian!"
+        );
+        assert_eq!(tr.translate("greetings", "en", [("", ""); 0])?, "Hi, NAME!");
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian"), ("TITLE", "Dr. ")])?,
+            "Hi, Dr. Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian"), ("TITLE", "Dr. ")])?,
+            "Olá, Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt", [("TITLE?", "Dr. ")]),
+            Err(Error::UnknownArgument("TITLE?".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn require_all_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "PLACE", "TITLE?"],
+            [
+                ("en", "Hi, TITLENAME! Welcome to PLACE."),
+                ("pt", "Olá, TITLENAME! Bem-vindo a PLACE."),
+            ],
+        )?;
+
+        // Lenient by default
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Hi, Julian! Welcome to PLACE."
+        );
+
+        let tr = tr.require_all_arguments(true);
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")]),
+            Err(Error::MissingArgument("PLACE".into()))
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("", ""); 0]),
+            Err(Error::MissingArgument("NAME".into()))
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian"), ("PLACE", "Lisboa")])?,
+            "Olá, Julian! Bem-vindo a Lisboa."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_checked() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "TITLE?"],
+            [("en", "Hi, TITLENAME!"), ("pt", "Olá, TITLENAME!")],
+        )?;
+
+        assert_eq!(
+            tr.translate_checked("greetings", "en", [("", ""); 0]),
+            Err(Error::UnreplacedPlaceholder("NAME".into()))
+        );
+        assert_eq!(
+            tr.translate_checked("greetings", "pt", [("NAME", "Julian")])?,
+            "Olá, Julian!"
+        );
+        assert_eq!(
+            tr.translate_checked("farewell", "pt", [("NAME", "Julian")]),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        let mut tr = Translator::new(["en"]).with_delimiters("{", "}");
+        tr.add_text("literal", ["A"], [("en", "{A} or literal \\{A}")])?;
+        tr.add_text("spelled", ["A", "B"], [("en", "{A} and {B}")])?;
+
+        assert_eq!(
+            tr.translate_checked("literal", "en", [("A", "1")])?,
+            "1 or literal {A}"
+        );
+        assert_eq!(
+            tr.translate_checked("spelled", "en", [("A", "{B}"), ("B", "2")])?,
+            "{B} and 2"
+        );
+        assert_eq!(
+            tr.translate_checked("spelled", "en", [("A", "1")]),
+            Err(Error::UnreplacedPlaceholder("B".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn check_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME", "AGE"],
+            [("en", "Hi, NAME! AGE"), ("pt", "Olá, NAME! AGE")],
+        )?;
+
+        tr.check_arguments("greetings", &["AGE", "NAME"])?;
+        tr.check_arguments("greetings", &["NAME"])?;
+        assert_eq!(
+            tr.check_arguments("greetings", &["NAME", "SURNAME"]),
+            Err(Error::UnknownArgument("SURNAME".into()))
+        );
+        assert_eq!(
+            tr.check_arguments("greetings", &["NAME", "AGE", "NAME"]),
+            Err(Error::DuplicatedArgument("NAME".into()))
+        );
+        assert_eq!(
+            tr.check_arguments("farewell", &["NAME"]),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn keys_using_argument() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME", "AGE"],
+            [("en", "Hi, NAME! AGE"), ("pt", "Olá, NAME! AGE")],
+        )?;
+        tr.add_text(
+            "bye",
+            ["NAME", "COUNT:number"],
+            [
+                ("en", "Bye, NAME! COUNT:number"),
+                ("pt", "Tchau, NAME! COUNT:number"),
+            ],
+        )?;
+        tr.add_text("thanks", [""; 0], [("en", "Thanks!"), ("pt", "Obrigado!")])?;
+
+        assert!(tr.key_uses_argument("greetings", "AGE")?);
+        assert!(tr.key_uses_argument("bye", "COUNT")?);
+        assert!(!tr.key_uses_argument("bye", "AGE")?);
+        assert!(!tr.key_uses_argument("thanks", "NAME")?);
+        assert_eq!(
+            tr.key_uses_argument("farewell", "NAME"),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        assert_eq!(tr.keys_using_argument("NAME"), ["bye", "greetings"]);
+        assert_eq!(tr.keys_using_argument("COUNT"), ["bye"]);
+        assert!(tr.keys_using_argument("SURNAME").is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rename_argument() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]).with_delimiters("{", "}");
+
+        tr.add_text(
+            "greetings",
+            ["NAME", "NAME2", "COUNT:number", "TITLE?"],
+            [
+                (
+                    "en",
+                    "Hi, {TITLE}{NAME} and {NAME2}! {NAME}, you have {COUNT:number} \\{NAME}",
+                ),
+                (
+                    "pt",
+                    "Olá, {TITLE}{NAME} e {NAME2}! {NAME}, tens {COUNT:number} \\{NAME}",
+                ),
+            ],
+        )?;
+
+        tr.rename_argument("greetings", "NAME", "FIRST_NAME")?;
+        tr.rename_argument("greetings", "COUNT", "TOTAL")?;
+        tr.rename_argument("greetings", "TITLE", "HONORIFIC")?;
+
+        assert_eq!(
+            tr.arguments_for("greetings")?.collect::<Vec<_>>(),
+            ["FIRST_NAME", "NAME2", "TOTAL", "HONORIFIC"]
+        );
+        assert_eq!(
+            tr.translations_for("greetings")?.collect::<Vec<_>>(),
+            [
+                ("en", "Hi, {HONORIFIC}{FIRST_NAME} and {NAME2}! {FIRST_NAME}, you have {TOTAL:number} \\{NAME}"),
+                ("pt", "Olá, {HONORIFIC}{FIRST_NAME} e {NAME2}! {FIRST_NAME}, tens {TOTAL:number} \\{NAME}"),
+            ]
+        );
+        assert_eq!(
+            tr.translate(
+                "greetings",
+                "en",
+                [
+                    ("FIRST_NAME", "Julian"),
+                    ("NAME2", "Ana"),
+                    ("TOTAL", "1200")
+                ]
+            )?,
+            "Hi, Julian and Ana! Julian, you have 1,200 {NAME}"
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")]),
+            Err(Error::UnknownArgument("NAME".into()))
+        );
+
+        assert_eq!(
+            tr.rename_argument("greetings", "NAME", "SURNAME"),
+            Err(Error::UnknownArgument("NAME".into()))
+        );
+        assert_eq!(
+            tr.rename_argument("greetings", "FIRST_NAME", "NAME2"),
+            Err(Error::DuplicatedArgument("NAME2".into()))
+        );
+        assert_eq!(
+            tr.rename_argument("farewell", "NAME", "SURNAME"),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_resolved() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en", "pt-BR"]);
+        tr.set_fallback_chain("pt-BR", ["pt", "en"])?;
+        tr.add_alias("br", "pt-BR")?;
+
+        tr.add_text_partial(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!")])?;
+
+        assert_eq!(
+            tr.translate_resolved("greetings", "pt-BR", [("NAME", "Julian")])?,
+            ("Olá, Julian!".into(), "pt")
+        );
+        assert_eq!(
+            tr.translate_resolved("bye", "br", [("", ""); 0])?,
+            ("Bye!".into(), "en")
+        );
+        assert_eq!(
+            tr.translate_resolved("greetings", "en", [("NAME", "Julian")])?,
+            ("Hi, Julian!".into(), "en")
+        );
+        assert_eq!(
+            tr.translate_resolved("bye", "pt", [("", ""); 0]),
+            Err(Error::UntranslatedForLanguage("bye".into(), "pt".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn try_translate() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+        )?;
+
+        assert_eq!(
+            tr.try_translate("greetings", "pt", [("NAME", "Julian")]),
+            Some("Olá, Julian!".into())
+        );
+        assert_eq!(
+            tr.try_translate("farewell", "pt", [("NAME", "Julian")]),
+            None
+        );
+        assert_eq!(
+            tr.try_translate("greetings", "cz", [("NAME", "Julian")]),
+            None
+        );
+        assert_eq!(tr.try_translate("greetings", "en", [("AGE", "20")]), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_compact() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Hi, NAME!"), ("pt", "Olá, NAME!")],
+        )?;
+        tr.add_text(
+            "welcome",
+            ["NAME"],
+            [
+                ("en", "Welcome to our humble abode, NAME!"),
+                ("pt", "Bem-vindo à nossa humilde morada, NAME!"),
+            ],
+        )?;
+
+        let short = tr.translate_compact("greetings", "pt", [("NAME", "Julian")])?;
+        assert_eq!(short, "Olá, Julian!");
+        assert!(!short.is_heap_allocated());
+
+        let long = tr.translate_compact("welcome", "en", [("NAME", "Julian")])?;
+        assert_eq!(long, "Welcome to our humble abode, Julian!");
+        assert_eq!(
+            tr.translate_compact("greetings", "cz", [("NAME", "Julian")]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn strict_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]).with_strict_arguments(true);
+
+        assert_eq!(
+            tr.add_text(
+                "greetings",
+                ["NAME", "PLACE"],
+                [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+            ),
+            Err(Error::UnusedArgument("PLACE".into()))
+        );
+        assert_eq!(
+            tr.add_text(
+                "greetings",
+                ["NAME"],
+                [("en", "Good morning, NAME!"), ("pt", "Bom dia!")],
+            ),
+            Err(Error::UnusedArgument("NAME".into()))
+        );
+        assert!(!tr.contains_key("greetings"));
 
-        // TODO: cache AhoCorasick automatons, or store them directly instead of Strings
-        let ac =
-            AhoCorasick::new(arguments).map_err(|err| Error::AhoCorasickBuild(err.to_string()))?;
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
 
-        ac.try_replace_all(message_to_translate, &values_to_replace)
-            .map_err(Into::into)
+        // Lenient by default
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "PLACE"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{Error, Translator};
+    #[test]
+    fn duplicated_language() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]);
+
+        assert_eq!(
+            tr.add_text("greetings", [""; 0], [("en", "a"), ("en", "b")]),
+            Err(Error::DuplicatedLanguage("en".into()))
+        );
+
+        tr.add_text("greetings", [""; 0], [("en", "a")])?;
+        assert_eq!(
+            tr.add_text("greetings", [""; 0], [("en", "a")]),
+            Err(Error::DuplicatedKey("greetings".into()))
+        );
+
+        Ok(())
+    }
 
     #[test]
-    fn one_argument() -> Result<(), Error> {
+    fn aliases() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "pt-BR", [("NAME", "Julian")]),
+            Err(Error::UnknownLanguage("pt-BR".into()))
+        );
+
+        tr.add_alias("pt-BR", "pt")?;
+        tr.add_alias("pt-PT", "pt")?;
+        assert_eq!(
+            tr.translate("greetings", "pt-BR", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "pt-PT", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+
+        assert_eq!(
+            tr.add_alias("cs-CZ", "cz"),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+        assert_eq!(
+            tr.add_alias("en", "pt"),
+            Err(Error::DuplicatedLanguage("en".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn case_insensitive_languages() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+        assert_eq!(
+            tr.add_text("bye", [""; 0], [("EN", "Bye!"), ("pt", "Tchau!")]),
+            Err(Error::UnknownLanguage("EN".into()))
+        );
+
+        let mut tr = Translator::new(["pt", "en"]).case_insensitive_languages(true);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("EN", "Good morning, NAME!"), ("Pt", "Bom dia, NAME!")],
+        )?;
+        assert_eq!(
+            tr.add_text("bye", [""; 0], [("en", "Bye!"), ("EN", "Bye!")]),
+            Err(Error::DuplicatedLanguage("EN".into()))
+        );
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "PT", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert!(tr.has_language("EN"));
+        assert_eq!(tr.languages().collect::<Vec<_>>(), ["en", "pt"]);
+
+        tr.add_alias("pt-BR", "PT")?;
+        assert_eq!(
+            tr.translate("greetings", "PT-br", [("NAME", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn delimiters() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]).with_delimiters("{{", "}}");
+
+        tr.add_text(
+            "greetings",
+            ["NAME", "NAME2"],
+            [
+                (
+                    "en",
+                    "Good morning, {{NAME}}! NAME and {{NAME2}} are names.",
+                ),
+                ("pt", "Bom dia, {{NAME}}! NAME e {{NAME2}} são nomes."),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian"), ("NAME2", "Kyle")])?,
+            "Good morning, Julian! NAME and Kyle are names."
+        );
+        // Placeholders of arguments not given are left as-is
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME2", "Kyle")])?,
+            "Bom dia, {{NAME}}! NAME e Kyle são nomes."
+        );
+
+        tr.add_text(
+            "welcome",
+            ["0", "1"],
+            [
+                ("en", "Welcome to {{1}}, {{0}}!"),
+                ("pt", "{{0}}, bem-vindo a {{1}}!"),
+            ],
+        )?;
+        assert_eq!(
+            tr.translate_positional("welcome", "en", &["Julian", "Lisbon"])?,
+            "Welcome to Lisbon, Julian!"
+        );
+
+        let mut tr = Translator::new(["en"])
+            .with_delimiters("{{", "}}")
+            .with_strict_arguments(true);
+        assert_eq!(
+            tr.add_text("greetings", ["NAME"], [("en", "Good morning, NAME!")]),
+            Err(Error::UnusedArgument("NAME".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn ambiguous_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]).with_strict_arguments(true);
+
+        assert_eq!(
+            tr.add_text(
+                "greetings",
+                ["NAME", "NAME2"],
+                [("en", "Hi, NAME and NAME2!"), ("pt", "Olá, NAME e NAME2!")],
+            ),
+            Err(Error::AmbiguousArguments("NAME".into(), "NAME2".into()))
+        );
+        assert_eq!(
+            tr.add_text(
+                "greetings",
+                ["LAST_NAME", "NAME"],
+                [
+                    ("en", "Hi, NAME LAST_NAME!"),
+                    ("pt", "Olá, NAME LAST_NAME!")
+                ],
+            ),
+            Err(Error::AmbiguousArguments("NAME".into(), "LAST_NAME".into()))
+        );
+        assert!(!tr.contains_key("greetings"));
+
+        // Accepted by default
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "NAME2"],
+            [("en", "Hi, NAME and NAME2!"), ("pt", "Olá, NAME e NAME2!")],
+        )?;
+
+        // Delimited placeholders don't overlap
+        let mut tr = Translator::new(["pt", "en"])
+            .with_delimiters("{", "}")
+            .with_strict_arguments(true);
+        tr.add_text(
+            "greetings",
+            ["NAME", "NAME2"],
+            [
+                ("en", "Hi, {NAME} and {NAME2}!"),
+                ("pt", "Olá, {NAME} e {NAME2}!"),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn escaped_delimiters() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).with_delimiters("{{", "}}");
+
+        tr.add_text(
+            "template",
+            ["NAME"],
+            [(
+                "en",
+                r"Hi {{NAME}}! Write \{{NAME}} or \{{ to get {{NAME}}.",
+            )],
+        )?;
+
+        assert_eq!(
+            tr.translate("template", "en", [("NAME", "Julian")])?,
+            "Hi Julian! Write {{NAME}} or {{ to get Julian."
+        );
+
+        let mut output = String::new();
+        tr.translate_into(&mut output, "template", "en", [("", ""); 0])?;
+        assert_eq!(output, "Hi {{NAME}}! Write {{NAME}} or {{ to get {{NAME}}.");
+
+        // Backslashes are kept as-is without delimiters
+        let mut tr = Translator::new(["en"]);
+        tr.add_text("path", ["DIR"], [("en", r"C:\DIR\{{")])?;
+        assert_eq!(
+            tr.translate("path", "en", [("DIR", "Users")])?,
+            r"C:\Users\{{"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn message() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en", "pt-BR"]);
+        tr.set_fallback_chain("pt-BR", ["pt"])?;
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Good morning, NAME!"),
+                ("pt", "Bom dia, NAME!"),
+                ("pt-BR", "Bom dia, NAME!"),
+            ],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("pt", "Tchau!")])?;
+
+        assert_eq!(tr.message("greetings", "en"), Some("Good morning, NAME!"));
+        assert_eq!(tr.message("bye", "pt-BR"), Some("Tchau!"));
+        assert_eq!(tr.message("bye", "en"), None);
+        assert_eq!(tr.message("greetings", "cz"), None);
+        assert_eq!(tr.message("farewell", "en"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn translations_for() -> Result<(), Error> {
         let mut tr = Translator::new(["pt", "en", "it"]);
 
         tr.add_text(
@@ -179,33 +3492,160 @@ mod tests {
                 ("it", "Buongiorno, NAME!"),
             ],
         )?;
+        tr.add_text_partial("bye", [""; 0], [("pt", "Tchau!")])?;
 
         assert_eq!(
-            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
-            "Bom dia, Julian!"
+            tr.translations_for("greetings")?.collect::<Vec<_>>(),
+            [
+                ("en", "Good morning, NAME!"),
+                ("it", "Buongiorno, NAME!"),
+                ("pt", "Bom dia, NAME!"),
+            ]
         );
         assert_eq!(
-            tr.translate("greetings", "en", [("NAME", "Julian")])?,
-            "Good morning, Julian!"
+            tr.translations_for("bye")?.collect::<Vec<_>>(),
+            [("pt", "Tchau!")]
         );
+
+        assert_eq!(tr.arguments_for("greetings")?.collect::<Vec<_>>(), ["NAME"]);
+        assert_eq!(tr.arguments_for("bye")?.count(), 0);
+        assert!(matches!(
+            tr.arguments_for("farewell"),
+            Err(Error::MissingKey(key)) if key == "farewell"
+        ));
+        assert!(matches!(
+            tr.translations_for("farewell"),
+            Err(Error::MissingKey(key)) if key == "farewell"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn translate_map() -> Result<(), Error> {
+        use std::collections::HashMap;
+
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "welcome",
+            ["NAME", "PLACE", "TIME"],
+            [
+                ("en", "Welcome to PLACE, NAME! It's TIME."),
+                ("pt", "Bem-vindo a PLACE, NAME! São TIME."),
+            ],
+        )?;
+
+        // Every map gets its own random seed, and thus its own iteration order
+        for _ in 0..32 {
+            let args: HashMap<String, String> =
+                [("NAME", "Julian"), ("PLACE", "Lisbon"), ("TIME", "9 AM")]
+                    .into_iter()
+                    .map(|(argument, value)| (argument.into(), value.into()))
+                    .collect();
+
+            assert_eq!(
+                tr.translate_map("welcome", "en", &args)?,
+                "Welcome to Lisbon, Julian! It's 9 AM."
+            );
+        }
+
+        let args = HashMap::from([("NAME", "Julian"), ("PLACE", "Lisboa")]);
         assert_eq!(
-            tr.translate("greetings", "it", [("NAME", "Julian")])?,
-            "Buongiorno, Julian!"
+            tr.translate_map("welcome", "pt", &args)?,
+            "Bem-vindo a Lisboa, Julian! São TIME."
         );
 
-        // Validations
+        Ok(())
+    }
+
+    #[test]
+    fn arguments_stored_longest_first() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]);
+        tr.add_text("greetings", ["NAME", "NAME2"], [("en", "NAME, NAME2")])?;
+
+        let translation = &tr.translations["greetings"];
+        let stored: Vec<_> = translation
+            .arguments
+            .resolve(&tr.argument_table)
+            .names()
+            .collect();
+        assert_eq!(stored, ["NAME2", "NAME"]);
+        // `NAME2` is matched as a whole, rather than as `NAME` followed by `2`
+        let first = translation.automaton.try_find("NAME2")?.unwrap();
         assert_eq!(
-            tr.translate("greetings", "cz", [("NAME", "Julian")]),
-            Err(Error::UnknownLanguage("cz".into()))
+            (
+                translation.argument_of(first.pattern().as_usize()),
+                first.end()
+            ),
+            (Some((0, None)), 5)
         );
+
+        // Still listed, and given, in any order
         assert_eq!(
-            tr.translate("greetings", "pt", [("NOME", "Julian")]),
-            Err(Error::UnknownArgument("NOME".into()))
+            tr.arguments_for("greetings")?.collect::<Vec<_>>(),
+            ["NAME", "NAME2"]
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian"), ("NAME2", "Kyle")])?,
+            "Julian, Kyle"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn values_are_not_rescanned() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "NAME2"],
+            [("en", "Hi, NAME! Bye, NAME2!")],
+        )?;
+
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "NAME2")])?,
+            "Hi, NAME2! Bye, NAME2!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "NAME2"), ("NAME2", "NAME")])?,
+            "Hi, NAME2! Bye, NAME!"
         );
 
         Ok(())
     }
 
+    #[test]
+    fn match_kinds() -> Result<(), Error> {
+        let translate_with = |match_kind| {
+            let mut tr = Translator::new(["en"]).with_match_kind(match_kind);
+            tr.add_text("greetings", ["NAME", "NAME2"], [("en", "NAME2, NAME")])?;
+
+            tr.translate("greetings", "en", [("NAME", "Julian"), ("NAME2", "Kyle")])
+        };
+
+        assert_eq!(translate_with(MatchKind::LeftmostLongest)?, "Kyle, Julian");
+        assert_eq!(translate_with(MatchKind::LeftmostFirst)?, "Kyle, Julian");
+        assert_eq!(translate_with(MatchKind::Standard)?, "Julian2, Julian");
+
+        // Cased placeholders are tried before the plain ones they start with
+        for match_kind in [MatchKind::LeftmostLongest, MatchKind::LeftmostFirst] {
+            let mut tr = Translator::new(["en"]).with_match_kind(match_kind);
+            tr.add_text(
+                "shout",
+                ["NAME", "NAME2"],
+                [("en", "NAME:upper, NAME2, NAME")],
+            )?;
+            assert_eq!(
+                tr.translate("shout", "en", [("NAME", "Julian"), ("NAME2", "Kyle")])?,
+                "JULIAN, Kyle, Julian"
+            );
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn overlapping_arguments() -> Result<(), Error> {
         let mut tr = Translator::new(["pt", "en", "it"]);
@@ -220,9 +3660,65 @@ mod tests {
             ],
         )?;
 
-        // TODO: disallow this
-        dbg!(
-            tr.translate("greetings", "pt", [("NAME", "Julian"), ("NAME2", "Kyle")])?
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian"), ("NAME2", "Kyle")])?,
+            "Bom dia, Julian! Boa tarde, Kyle!"
+        );
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME2", "Kyle"), ("NAME", "Julian")])?,
+            "Good morning, Julian! Good afternoon, Kyle!"
+        );
+
+        tr.add_text(
+            "adjacent",
+            ["NAME", "NAME2"],
+            [
+                ("en", "NAME2NAME NAMENAME2"),
+                ("pt", "NAME2NAME NAMENAME2"),
+                ("it", "NAME2NAME NAMENAME2"),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("adjacent", "en", [("NAME", "Julian"), ("NAME2", "Kyle")])?,
+            "KyleJulian JulianKyle"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_languages() -> Result<(), Error> {
+        let mut tr = Translator::new(Vec::<&str>::new());
+
+        assert_eq!(tr.languages().count(), 0);
+        assert_eq!(
+            tr.add_text("greetings", ["NAME"], Vec::<(&str, &str)>::new()),
+            Err(Error::NoLanguages)
+        );
+        assert_eq!(
+            tr.add_text_partial("greetings", ["NAME"], Vec::<(&str, &str)>::new()),
+            Err(Error::NoLanguages)
+        );
+        assert!(tr.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_languages_are_named() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]);
+
+        let added = tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("it", "Buongiorno, NAME!")],
+        );
+
+        assert_eq!(added, Err(Error::MissingLanguages(vec!["pt".into()])));
+        assert_eq!(
+            added.map_err(|err| err.to_string()),
+            Err("Missing translations for `pt`".into())
         );
 
         Ok(())
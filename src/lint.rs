@@ -0,0 +1,137 @@
+//! Auditing of the arguments used across the messages of each key.
+
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::Translator;
+
+/// A possible mistake found by [`Translator::lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub key: SmallStr,
+    /// The language whose message is at fault.
+    pub language: SmallStr,
+    pub argument: SmallStr,
+    pub kind: LintKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// The argument is used by the messages of other languages, but not by this one.
+    MissingArgument,
+    /// The argument is declared, but no language uses it.
+    UnusedArgument,
+}
+
+impl Translator {
+    /// Checks every key for declared arguments that are not used consistently across its
+    /// messages, sorted by key, then argument, then language.
+    ///
+    /// Unlike [`Translator::with_strict_arguments`], this never rejects a message, and is
+    /// meant as an audit pass, e.g. in CI.
+    pub fn lint(&self) -> Vec<Lint> {
+        let mut keys: Vec<_> = self.translations.iter().collect();
+        keys.sort_unstable_by_key(|(key, _)| *key);
+
+        let mut lints = Vec::new();
+
+        for (key, translation) in keys {
+            let arguments = translation.arguments.resolve(&self.argument_table);
+            // Optional arguments may be left out of any message
+            let mut arguments: Vec<_> = arguments
+                .names()
+                .zip(arguments.placeholders())
+                .enumerate()
+                .filter(|(index, _)| !translation.optional_arguments.contains(index))
+                .map(|(index, (argument, placeholder))| (argument, placeholder, index))
+                .collect();
+            arguments.sort_unstable();
+            arguments.dedup_by_key(|(argument, placeholder, _)| (*argument, *placeholder));
+
+            for (argument, placeholder, index) in arguments {
+                let mut missing = Vec::new();
+                let mut used_anywhere = false;
+
+                for (language_id, language) in self.languages.iter().enumerate() {
+                    let Some(message) = translation.translations.get(&language_id) else {
+                        continue;
+                    };
+
+                    if translation.occurs_in(message, placeholder, index) {
+                        used_anywhere = true;
+                    } else {
+                        missing.push(language);
+                    }
+                }
+
+                let kind = if used_anywhere {
+                    LintKind::MissingArgument
+                } else {
+                    LintKind::UnusedArgument
+                };
+
+                lints.extend(missing.into_iter().map(|language| Lint {
+                    key: key.clone(),
+                    language: language.clone(),
+                    argument: argument.clone(),
+                    kind,
+                }));
+            }
+        }
+
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Lint, LintKind};
+    use crate::{Error, Translator};
+
+    #[test]
+    fn lint() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]);
+
+        tr.add_text(
+            "greetings",
+            ["{NAME}", "{PLACE}"],
+            [
+                ("en", "Good morning, {NAME}!"),
+                ("pt", "Bom dia!"),
+                ("it", "Buongiorno, {NAME}!"),
+            ],
+        )?;
+        tr.add_text(
+            "bye",
+            ["{NAME}"],
+            [
+                ("en", "Bye, {NAME}!"),
+                ("pt", "Tchau, {NAME}!"),
+                ("it", "Ciao, {NAME}!"),
+            ],
+        )?;
+
+        let lint = |language: &str, argument: &str, kind| Lint {
+            key: "greetings".into(),
+            language: language.into(),
+            argument: argument.into(),
+            kind,
+        };
+
+        assert_eq!(
+            tr.lint(),
+            [
+                lint("pt", "{NAME}", LintKind::MissingArgument),
+                lint("en", "{PLACE}", LintKind::UnusedArgument),
+                lint("it", "{PLACE}", LintKind::UnusedArgument),
+                lint("pt", "{PLACE}", LintKind::UnusedArgument),
+            ]
+        );
+
+        tr.remove_text("greetings")?;
+        assert!(tr.lint().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+//! Locale-aware joining of lists of items inside messages.
+
+use alloc::string::String;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::plural::primary_subtag;
+use crate::{Error, Translator};
+
+/// Suffix of an argument whose values are formatted as lists, as in `NAMES:list`.
+pub(crate) const LIST_ANNOTATION: &str = ":list";
+
+/// Separates the items given as the value of a `:list` argument.
+pub(crate) const ITEM_SEPARATOR: char = '\n';
+
+/// The word joining the last two items of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListStyle {
+    /// Every item applies, as in `a, b, and c`.
+    And,
+    /// Any one item applies, as in `a, b, or c`.
+    Or,
+}
+
+/// How lists are joined in a given language.
+#[derive(Clone, Copy)]
+pub(crate) struct ListPatterns {
+    and: &'static str,
+    or: &'static str,
+    /// Whether lists of three or more items keep the comma before the last
+    /// conjunction, as in the English `a, b, and c`.
+    serial_comma: bool,
+}
+
+/// The list patterns of `language`, following CLDR.
+pub(crate) fn list_patterns(language: &str) -> ListPatterns {
+    let (and, or, serial_comma) = match primary_subtag(language) {
+        "en" => ("and", "or", true),
+        "pt" => ("e", "ou", false),
+        "it" => ("e", "o", false),
+        "es" => ("y", "o", false),
+        "fr" => ("et", "ou", false),
+        "de" => ("und", "oder", false),
+        "nl" => ("en", "of", false),
+        _ => ("and", "or", true),
+    };
+
+    ListPatterns {
+        and,
+        or,
+        serial_comma,
+    }
+}
+
+/// Joins `items` with `patterns`, as in `a, b, and c`.
+pub(crate) fn join(patterns: ListPatterns, style: ListStyle, items: &[&str]) -> SmallStr {
+    let conjunction = match style {
+        ListStyle::And => patterns.and,
+        ListStyle::Or => patterns.or,
+    };
+    let count = items.len();
+
+    let mut joined = SmallStr::default();
+    for (index, &item) in items.iter().enumerate() {
+        if index + 1 == count && index > 0 {
+            if count > 2 && patterns.serial_comma {
+                joined.push(',');
+            }
+            joined.push(' ');
+            joined.push_str(conjunction);
+            joined.push(' ');
+        } else if index > 0 {
+            joined.push_str(", ");
+        }
+        joined.push_str(item);
+    }
+
+    joined
+}
+
+impl Translator {
+    /// Joins `items` the way `language` lists them, as in `a, b, and c` in `en`
+    /// or `a, b e c` in `pt`.
+    ///
+    /// Arguments declared with a `:list` suffix, such as `NAMES:list`, are formatted
+    /// this way by [`Translator::translate`] and friends, joining with [`ListStyle::And`]
+    /// the items of their value separated by newlines. Such arguments are given by their
+    /// bare name, and their placeholder keeps the suffix.
+    ///
+    /// ```
+    /// use binja::{ListStyle, Translator};
+    ///
+    /// let mut tr = Translator::new(["en", "pt"]).with_delimiters("{", "}");
+    /// tr.add_text(
+    ///     "attendees",
+    ///     ["names:list"],
+    ///     [("en", "With {names:list}"), ("pt", "Com {names:list}")],
+    /// )?;
+    ///
+    /// assert_eq!(tr.format_list("en", &["a", "b", "c"], ListStyle::Or)?, "a, b, or c");
+    /// assert_eq!(tr.translate("attendees", "pt", [("names", "Ana\nRui")])?, "Com Ana e Rui");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn format_list(
+        &self,
+        language: &str,
+        items: &[&str],
+        style: ListStyle,
+    ) -> Result<String, Error> {
+        let language_id = self.find_language(language)?;
+
+        Ok(join(self.list_patterns[language_id], style, items).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, ListStyle, Translator};
+
+    #[test]
+    fn format_list() -> Result<(), Error> {
+        let tr = Translator::new(["en", "pt"]);
+
+        assert_eq!(tr.format_list("en", &[], ListStyle::And)?, "");
+        assert_eq!(tr.format_list("en", &["a"], ListStyle::And)?, "a");
+        assert_eq!(
+            tr.format_list("en", &["a", "b"], ListStyle::And)?,
+            "a and b"
+        );
+        assert_eq!(
+            tr.format_list("en", &["a", "b", "c"], ListStyle::And)?,
+            "a, b, and c"
+        );
+        assert_eq!(
+            tr.format_list("en", &["a", "b", "c"], ListStyle::Or)?,
+            "a, b, or c"
+        );
+
+        assert_eq!(tr.format_list("pt", &["a"], ListStyle::And)?, "a");
+        assert_eq!(tr.format_list("pt", &["a", "b"], ListStyle::And)?, "a e b");
+        assert_eq!(
+            tr.format_list("pt", &["a", "b", "c"], ListStyle::And)?,
+            "a, b e c"
+        );
+        assert_eq!(
+            tr.format_list("pt", &["a", "b", "c"], ListStyle::Or)?,
+            "a, b ou c"
+        );
+
+        assert_eq!(
+            tr.format_list("cz", &["a"], ListStyle::And),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_arguments() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_text(
+            "invite",
+            ["NAMES:list"],
+            [("en", "Invite NAMES:list?"), ("pt", "Convidar NAMES:list?")],
+        )?;
+
+        assert_eq!(
+            tr.translate("invite", "en", [("NAMES", "Ana\nRui\nJo")])?,
+            "Invite Ana, Rui, and Jo?"
+        );
+        assert_eq!(
+            tr.translate("invite", "pt", [("NAMES", "Ana\nRui")])?,
+            "Convidar Ana e Rui?"
+        );
+        assert_eq!(
+            tr.translate("invite", "en", [("NAMES", "Ana")])?,
+            "Invite Ana?"
+        );
+
+        Ok(())
+    }
+}
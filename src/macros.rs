@@ -0,0 +1,77 @@
+/// Calls [`Translator::translate`](crate::Translator::translate) with arguments
+/// written as `NAME = value` pairs.
+///
+/// Values may be of any type implementing [`Display`](core::fmt::Display), and each
+/// argument name is taken literally from its identifier.
+///
+/// ```
+/// use binja::{translate, Translator};
+///
+/// let mut tr = Translator::new(["en"]);
+/// tr.add_text("items", ["NAME", "COUNT"], [("en", "NAME has COUNT items")])?;
+///
+/// assert_eq!(
+///     translate!(tr, "items", "en", NAME = "Julian", COUNT = 3)?,
+///     "Julian has 3 items"
+/// );
+/// # Ok::<(), binja::Error>(())
+/// ```
+///
+/// Being a declarative macro, it cannot know which keys and arguments were registered,
+/// so typos in either are still only caught at runtime, as [`Error::MissingKey`] and
+/// [`Error::UnknownArgument`]. It also only supports arguments that are valid Rust
+/// identifiers; anything else needs a direct call to `translate`.
+///
+/// [`Error::MissingKey`]: crate::Error::MissingKey
+/// [`Error::UnknownArgument`]: crate::Error::UnknownArgument
+#[macro_export]
+macro_rules! translate {
+    ($translator:expr, $key:expr, $language:expr $(, $argument:ident = $value:expr)* $(,)?) => {
+        $translator.translate(
+            $key,
+            $language,
+            ::core::iter::empty::<(&str, $crate::__private::String)>()
+                $(.chain(::core::iter::once((
+                    ::core::stringify!($argument),
+                    $crate::__private::ToString::to_string(&$value),
+                ))))*,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_macro() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+
+        tr.add_text(
+            "welcome",
+            ["NAME", "PLACE"],
+            [
+                ("en", "Welcome to PLACE, NAME!"),
+                ("pt", "Bem-vindo a PLACE, NAME!"),
+            ],
+        )?;
+        tr.add_text("bye", [""; 0], [("en", "Bye!"), ("pt", "Tchau!")])?;
+
+        let name = String::from("Julian");
+        assert_eq!(
+            translate!(tr, "welcome", "en", NAME = name, PLACE = "Lisbon")?,
+            "Welcome to Lisbon, Julian!"
+        );
+        assert_eq!(
+            translate!(&tr, "welcome", "pt", PLACE = "Lisboa", NAME = name,)?,
+            "Bem-vindo a Lisboa, Julian!"
+        );
+        assert_eq!(translate!(tr, "bye", "pt")?, "Tchau!");
+        assert_eq!(
+            translate!(tr, "welcome", "en", NOME = name),
+            Err(Error::UnknownArgument("NOME".into()))
+        );
+
+        Ok(())
+    }
+}
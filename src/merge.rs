@@ -0,0 +1,224 @@
+//! Combining the messages of several translators.
+
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::intern::ArgumentTable;
+use crate::{Error, LanguageId, Map, Translation, Translator};
+
+/// What [`Translator::merge_with`] does with keys present in both translators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnConflict {
+    /// Fail with [`Error::DuplicatedKey`], leaving the translator untouched.
+    #[default]
+    Error,
+    /// Replace the existing message with the incoming one.
+    Overwrite,
+    /// Keep the existing message, discarding the incoming one.
+    Keep,
+}
+
+impl Translator {
+    /// Adds every key of `other` into this translator, failing on keys present in both.
+    ///
+    /// See [`Translator::merge_with`].
+    pub fn merge(&mut self, other: Translator) -> Result<(), Error> {
+        self.merge_with(other, OnConflict::Error)
+    }
+
+    /// Adds every key of `other` into this translator, resolving keys present in
+    /// both according to `on_conflict`.
+    ///
+    /// Every language of `other` must be known to this translator, otherwise
+    /// [`Error::UnknownLanguage`] names the first one that isn't. Nothing is merged
+    /// if an error is returned. Fallback chains and aliases of `other` are not merged.
+    pub fn merge_with(&mut self, other: Translator, on_conflict: OnConflict) -> Result<(), Error> {
+        // Maps each language id of `other` to the id of the same language in `self`
+        let language_ids = other
+            .languages
+            .iter()
+            .map(|language| {
+                self.position_of(language)
+                    .ok_or_else(|| Error::UnknownLanguage(language.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if on_conflict == OnConflict::Error {
+            let conflict = other
+                .translations
+                .keys()
+                .find(|key| self.translations.contains_key(*key))
+                .or_else(|| {
+                    other
+                        .plurals
+                        .keys()
+                        .find(|key| self.plurals.contains_key(*key))
+                })
+                .or_else(|| {
+                    other
+                        .selects
+                        .keys()
+                        .find(|key| self.selects.contains_key(*key))
+                })
+                .or_else(|| {
+                    other
+                        .ranged
+                        .keys()
+                        .find(|key| self.ranged.contains_key(*key))
+                });
+
+            if let Some(key) = conflict {
+                return Err(Error::DuplicatedKey(key.clone()));
+            }
+        }
+
+        // Taken out so that arguments can be interned into `self` while merging
+        let mut translations = core::mem::take(&mut self.translations);
+        let mut plurals = core::mem::take(&mut self.plurals);
+        let mut selects = core::mem::take(&mut self.selects);
+        let mut ranged = core::mem::take(&mut self.ranged);
+
+        let other_table = &other.argument_table;
+        self.merge_into(
+            &mut translations,
+            other.translations,
+            other_table,
+            &language_ids,
+            on_conflict,
+        );
+        self.merge_into(
+            &mut plurals,
+            other.plurals,
+            other_table,
+            &language_ids,
+            on_conflict,
+        );
+
+        self.merge_into(
+            &mut selects,
+            other.selects,
+            other_table,
+            &language_ids,
+            on_conflict,
+        );
+        self.merge_into(
+            &mut ranged,
+            other.ranged,
+            other_table,
+            &language_ids,
+            on_conflict,
+        );
+
+        self.translations = translations;
+        self.plurals = plurals;
+        self.selects = selects;
+        self.ranged = ranged;
+
+        Ok(())
+    }
+
+    fn merge_into<M>(
+        &mut self,
+        target: &mut Map<SmallStr, Translation<M>>,
+        source: Map<SmallStr, Translation<M>>,
+        source_table: &ArgumentTable,
+        language_ids: &[LanguageId],
+        on_conflict: OnConflict,
+    ) {
+        for (key, mut translation) in source {
+            if on_conflict == OnConflict::Keep && target.contains_key(&key) {
+                continue;
+            }
+
+            translation.translations = translation
+                .translations
+                .into_iter()
+                .map(|(language_id, message)| (language_ids[language_id], message))
+                .collect();
+
+            // Interned arguments refer to the table of the translator being merged
+            let arguments = translation.arguments.resolve(source_table).to_owned();
+            translation.arguments = self.store_arguments(arguments);
+
+            target.insert(key, translation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OnConflict;
+    use crate::{Error, Translator};
+
+    #[test]
+    fn merge() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "it", "pt"]);
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Good morning, NAME!"),
+                ("pt", "Bom dia, NAME!"),
+                ("it", "Buongiorno, NAME!"),
+            ],
+        )?;
+
+        // Language ids differ between both translators
+        let mut other = Translator::new(["pt", "it"]);
+        other.add_text(
+            "bye",
+            ["NAME"],
+            [("pt", "Tchau, NAME!"), ("it", "Ciao, NAME!")],
+        )?;
+
+        tr.merge(other)?;
+
+        assert_eq!(tr.len(), 2);
+        assert_eq!(
+            tr.translate("bye", "pt", [("NAME", "Julian")])?,
+            "Tchau, Julian!"
+        );
+        assert_eq!(
+            tr.translate("bye", "it", [("NAME", "Julian")])?,
+            "Ciao, Julian!"
+        );
+        assert_eq!(tr.missing_languages("bye")?, ["en"]);
+
+        let other = Translator::new(["pt", "cz"]);
+        assert_eq!(tr.merge(other), Err(Error::UnknownLanguage("cz".into())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_conflicts() -> Result<(), Error> {
+        let new_translator = |message| -> Result<Translator, Error> {
+            let mut tr = Translator::new(["en"]);
+            tr.add_text("greetings", [""; 0], [("en", message)])?;
+            Ok(tr)
+        };
+
+        let mut tr = new_translator("Hello!")?;
+
+        let mut other = new_translator("Hi!")?;
+        other.add_text("bye", [""; 0], [("en", "Bye!")])?;
+        assert_eq!(
+            tr.merge(other),
+            Err(Error::DuplicatedKey("greetings".into()))
+        );
+        // Nothing was merged
+        assert!(!tr.contains_key("bye"));
+
+        let mut other = new_translator("Hi!")?;
+        other.add_text("bye", [""; 0], [("en", "Bye!")])?;
+        tr.merge_with(other, OnConflict::Keep)?;
+        assert_eq!(tr.translate("greetings", "en", [("", ""); 0])?, "Hello!");
+        assert_eq!(tr.translate("bye", "en", [("", ""); 0])?, "Bye!");
+
+        tr.merge_with(new_translator("Hi!")?, OnConflict::Overwrite)?;
+        assert_eq!(tr.translate("greetings", "en", [("", ""); 0])?, "Hi!");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! Treating keys such as `checkout.button.pay` as nested within namespaces, on top of the
+//! flat map of keys.
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use crate::Translator;
+
+/// The namespace separator used unless [`Translator::with_namespace_separator`] is called.
+pub(crate) const DEFAULT_NAMESPACE_SEPARATOR: &str = ".";
+
+impl Translator {
+    /// Sets what splits keys into namespaces, for [`Translator::keys_in_namespace`] and
+    /// [`Translator::namespaces`].
+    ///
+    /// Defaults to `.`, as in `checkout.button.pay`.
+    pub fn with_namespace_separator(mut self, separator: &str) -> Self {
+        self.namespace_separator = separator.into();
+        self
+    }
+
+    /// Every key within the namespace `prefix`, including those of nested namespaces,
+    /// in arbitrary order.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_text("checkout.button.pay", [""; 0], [("en", "Pay")])?;
+    /// tr.add_text("checkout.title", [""; 0], [("en", "Checkout")])?;
+    /// tr.add_text("checkoutless", [""; 0], [("en", "-")])?;
+    ///
+    /// let mut keys: Vec<_> = tr.keys_in_namespace("checkout").collect();
+    /// keys.sort();
+    /// assert_eq!(keys, ["checkout.button.pay", "checkout.title"]);
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn keys_in_namespace<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a str> {
+        self.keys().filter(move |key| {
+            key.strip_prefix(prefix)
+                .is_some_and(|rest| rest.starts_with(self.namespace_separator.as_str()))
+        })
+    }
+
+    /// Every namespace holding at least one key, nested ones included, sorted.
+    ///
+    /// For `checkout.button.pay`, those are `checkout` and `checkout.button`.
+    pub fn namespaces(&self) -> Vec<&str> {
+        let separator = self.namespace_separator.as_str();
+        if separator.is_empty() {
+            return Vec::new();
+        }
+
+        let namespaces: BTreeSet<_> = self
+            .keys()
+            .flat_map(|key| key.match_indices(separator).map(|(at, _)| &key[..at]))
+            .collect();
+
+        namespaces.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn namespaces() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]);
+        tr.add_text("a.b", [""; 0], [("en", "B")])?;
+        tr.add_text("a.c", [""; 0], [("en", "C")])?;
+        tr.add_text("a.d.e", [""; 0], [("en", "E")])?;
+        tr.add_text("ab", [""; 0], [("en", "AB")])?;
+
+        let mut keys: Vec<_> = tr.keys_in_namespace("a").collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["a.b", "a.c", "a.d.e"]);
+        assert_eq!(tr.keys_in_namespace("a.d").collect::<Vec<_>>(), ["a.d.e"]);
+        assert_eq!(tr.keys_in_namespace("a.b").count(), 0);
+        assert_eq!(tr.namespaces(), ["a", "a.d"]);
+
+        let mut tr = Translator::new(["en"]).with_namespace_separator("::");
+        tr.add_text("checkout::pay", [""; 0], [("en", "Pay")])?;
+        tr.add_text("checkout.title", [""; 0], [("en", "Checkout")])?;
+        assert_eq!(
+            tr.keys_in_namespace("checkout").collect::<Vec<_>>(),
+            ["checkout::pay"]
+        );
+        assert_eq!(tr.namespaces(), ["checkout"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+//! Language negotiation from HTTP `Accept-Language` headers.
+
+use alloc::vec::Vec;
+
+use crate::Translator;
+
+impl Translator {
+    /// Picks the best language for an HTTP `Accept-Language` header, such as
+    /// `en-US,en;q=0.9,pt;q=0.8`.
+    ///
+    /// Ranges are tried by descending quality, then by their order in the header.
+    /// Each one is matched through RFC 4647 lookup, so `en-US` is looked up as `en-US`,
+    /// then `en`. Ranges with a quality of zero, and the `*` wildcard, are ignored.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let tr = Translator::new(["en", "pt"]);
+    /// assert_eq!(tr.negotiate("pt-BR,en;q=0.8"), Some("pt"));
+    /// assert_eq!(tr.negotiate("cz"), None);
+    /// ```
+    pub fn negotiate(&self, header: &str) -> Option<&str> {
+        let mut ranges: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let tag = parts.next().filter(|tag| !tag.is_empty())?;
+
+                let quality = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .map_or(Some(1.0), |quality| quality.parse().ok())?;
+
+                Some((tag, quality))
+            })
+            .filter(|&(tag, quality)| tag != "*" && quality > 0.0)
+            .collect();
+
+        // Stable, so equal qualities keep the header's order
+        ranges.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        ranges
+            .into_iter()
+            .find_map(|(tag, _)| self.lookup_language(tag))
+    }
+
+    /// RFC 4647 lookup: tries `tag`, then drops its trailing subtags one at a time.
+    fn lookup_language(&self, mut tag: &str) -> Option<&str> {
+        loop {
+            let found = self
+                .languages
+                .iter()
+                .find(|language| language.eq_ignore_ascii_case(tag));
+
+            if let Some(language) = found {
+                return Some(language);
+            }
+
+            let (prefix, _) = tag.rsplit_once('-')?;
+            // A single-letter subtag, such as in `zh-Hant-x-private`, can't end a tag
+            tag = match prefix.rsplit_once('-') {
+                Some((rest, singleton)) if singleton.len() == 1 => rest,
+                _ => prefix,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Translator;
+
+    #[test]
+    fn negotiate() {
+        let tr = Translator::new(["en", "pt", "pt-PT", "it"]);
+
+        // Exact match
+        assert_eq!(tr.negotiate("pt-PT"), Some("pt-PT"));
+        assert_eq!(tr.negotiate("IT"), Some("it"));
+
+        // Primary subtag fallback
+        assert_eq!(tr.negotiate("en-US,en;q=0.9,pt;q=0.8"), Some("en"));
+        assert_eq!(tr.negotiate("pt-BR"), Some("pt"));
+        assert_eq!(tr.negotiate("zh-Hant-x-private,it;q=0.1"), Some("it"));
+
+        // Quality, then header order
+        assert_eq!(tr.negotiate("cz, it;q=0.5, pt;q=0.9"), Some("pt"));
+        assert_eq!(tr.negotiate("it;q=0.5, pt;q=0.5"), Some("it"));
+        assert_eq!(tr.negotiate("en;q=0, pt;q=0.1"), Some("pt"));
+
+        // No match
+        assert_eq!(tr.negotiate("cz, de-DE;q=0.9, *;q=0.1"), None);
+        assert_eq!(tr.negotiate(""), None);
+        assert_eq!(tr.negotiate("en;q=abc"), None);
+    }
+}
@@ -0,0 +1,124 @@
+//! Locale-aware formatting of numbers inside messages.
+
+use alloc::string::String;
+
+use compact_str::{CompactString as SmallStr, ToCompactString};
+
+use crate::plural::primary_subtag;
+use crate::{Error, Translator};
+
+/// Suffix of an argument whose values are grouped as numbers, as in `COUNT:number`.
+pub(crate) const NUMBER_ANNOTATION: &str = ":number";
+
+/// The separator placed between every group of three digits in `language`.
+pub(crate) fn grouping_separator(language: &str) -> char {
+    match primary_subtag(language) {
+        "pt" | "it" | "es" | "de" | "nl" | "da" | "id" | "tr" => '.',
+        "fr" | "pl" | "cs" | "sv" | "fi" | "nb" | "ru" | "uk" => '\u{a0}',
+        _ => ',',
+    }
+}
+
+/// Writes `number` with every group of three digits split by `separator`.
+pub(crate) fn group_digits(number: i64, separator: char) -> SmallStr {
+    let digits = number.unsigned_abs().to_compact_string();
+
+    let mut grouped = SmallStr::default();
+    if number < 0 {
+        grouped.push('-');
+    }
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+
+    grouped
+}
+
+impl Translator {
+    /// Formats `number` with the digit grouping of `language`, as in `1,234` in `en`
+    /// or `1.234` in `pt`.
+    ///
+    /// Arguments declared with a `:number` suffix, such as `COUNT:number`, are formatted
+    /// this way by [`Translator::translate`] and friends whenever their value is an integer.
+    /// Such arguments are given by their bare name, and their placeholder keeps the suffix.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en", "pt"]).with_delimiters("{", "}");
+    /// tr.add_text(
+    ///     "downloads",
+    ///     ["n:number"],
+    ///     [("en", "{n:number} downloads"), ("pt", "{n:number} transferências")],
+    /// )?;
+    ///
+    /// assert_eq!(tr.format_number("en", 1234)?, "1,234");
+    /// assert_eq!(tr.translate("downloads", "pt", [("n", "1234")])?, "1.234 transferências");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn format_number(&self, language: &str, number: i64) -> Result<String, Error> {
+        let language_id = self.find_language(language)?;
+
+        Ok(group_digits(number, self.grouping_separators[language_id]).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::group_digits;
+    use crate::{Error, Translator};
+
+    #[test]
+    fn digit_grouping() {
+        assert_eq!(group_digits(0, ','), "0");
+        assert_eq!(group_digits(999, ','), "999");
+        assert_eq!(group_digits(1000, ','), "1,000");
+        assert_eq!(group_digits(-1234567, '.'), "-1.234.567");
+        assert_eq!(group_digits(i64::MIN, ','), "-9,223,372,036,854,775,808");
+    }
+
+    #[test]
+    fn format_number() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]);
+
+        assert_eq!(tr.format_number("en", 1234567)?, "1,234,567");
+        assert_eq!(tr.format_number("pt", 1234567)?, "1.234.567");
+        assert_eq!(tr.format_number("it", 1234567)?, "1.234.567");
+        assert_eq!(
+            tr.format_number("cz", 1234567),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        tr.add_text(
+            "downloads",
+            ["COUNT:number", "NAME"],
+            [
+                ("en", "NAME has COUNT:number downloads"),
+                ("pt", "NAME tem COUNT:number transferências"),
+                ("it", "NAME ha COUNT:number download"),
+            ],
+        )?;
+        assert_eq!(
+            tr.translate("downloads", "en", [("COUNT", "1234567"), ("NAME", "binja")])?,
+            "binja has 1,234,567 downloads"
+        );
+        assert_eq!(
+            tr.translate("downloads", "pt", [("COUNT", "1234567"), ("NAME", "binja")])?,
+            "binja tem 1.234.567 transferências"
+        );
+        // Values that aren't integers are left as given
+        assert_eq!(
+            tr.translate("downloads", "en", [("COUNT", "many"), ("NAME", "binja")])?,
+            "binja has many downloads"
+        );
+        assert_eq!(
+            tr.translate("downloads", "en", [("COUNT:number", "1")]),
+            Err(Error::UnknownArgument("COUNT:number".into()))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+//! Building large catalogs on several threads, through `rayon`.
+
+use compact_str::CompactString as SmallStr;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{Error, Translator};
+
+/// A key, its arguments and its `(language, message)` pairs, as given to [`Translator::add_text`].
+pub type Entry<K, A, M> = (K, Vec<A>, Vec<(A, M)>);
+
+impl Translator {
+    /// Builds a translator by adding every entry through [`Translator::add_text`], in order.
+    pub fn from_entries<
+        S: Into<SmallStr>,
+        K: Into<SmallStr>,
+        A: Into<SmallStr>,
+        M: Into<SmallStr>,
+        I1: IntoIterator<Item = S>,
+        I2: IntoIterator<Item = Entry<K, A, M>>,
+    >(
+        languages: I1,
+        entries: I2,
+    ) -> Result<Translator, Error> {
+        let mut translator = Translator::new(languages);
+
+        for (key, arguments, translations) in entries {
+            translator.add_text(key, arguments, translations)?;
+        }
+
+        Ok(translator)
+    }
+
+    /// Like [`Translator::from_entries`], but validates entries and builds their
+    /// automatons on the threads of the global `rayon` pool. Only inserting them is done
+    /// serially.
+    ///
+    /// The result is the same as the serial one: on failure, the error reported is the
+    /// one `from_entries` would have returned.
+    pub fn from_entries_par<
+        S: Into<SmallStr>,
+        K: Into<SmallStr> + Send,
+        A: Into<SmallStr> + Send,
+        M: Into<SmallStr> + Send,
+        I: IntoIterator<Item = S>,
+    >(
+        languages: I,
+        entries: Vec<Entry<K, A, M>>,
+    ) -> Result<Translator, Error> {
+        let mut translator = Translator::new(languages);
+
+        let built: Vec<_> = entries
+            .into_par_iter()
+            .map(|(key, arguments, translations)| {
+                let translation = translator.build_text_translation(arguments, translations, true);
+                (key.into(), translation)
+            })
+            .collect();
+
+        for (key, translation) in built {
+            // Checked in the same order `add_text` would have
+            if translator.translations.contains_key(&key) {
+                return Err(Error::DuplicatedKey(key));
+            }
+
+            translator.insert_translation(key, translation?);
+        }
+
+        Ok(translator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Entry;
+    use crate::{Error, Translator};
+
+    fn entries(count: usize) -> Vec<Entry<String, &'static str, String>> {
+        (0..count)
+            .map(|index| {
+                (
+                    format!("key-{index}"),
+                    vec!["NAME"],
+                    vec![
+                        ("en", format!("Message {index} for NAME")),
+                        ("pt", format!("Mensagem {index} para NAME")),
+                    ],
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parallel_equals_serial() -> Result<(), Error> {
+        let serial = Translator::from_entries(["en", "pt"], entries(1000))?;
+        let parallel = Translator::from_entries_par(["en", "pt"], entries(1000))?;
+
+        assert_eq!(parallel.len(), serial.len());
+        assert!(serial.diff(&parallel).is_empty());
+        for key in serial.keys() {
+            assert_eq!(
+                parallel.translations_for(key)?.collect::<Vec<_>>(),
+                serial.translations_for(key)?.collect::<Vec<_>>()
+            );
+            assert_eq!(
+                parallel.translate(key, "pt", [("NAME", "Julian")])?,
+                serial.translate(key, "pt", [("NAME", "Julian")])?
+            );
+        }
+
+        assert_eq!(
+            Translator::from_entries_par(["en", "pt"], entries(0))?.len(),
+            0
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parallel_reports_first_error() {
+        let mut entries = entries(1000);
+        entries[700].2.pop();
+        entries[900].0 = "key-3".into();
+        entries[200].2[0].0 = "cz";
+
+        let serial = Translator::from_entries(["en", "pt"], entries.clone()).err();
+        let parallel = Translator::from_entries_par(["en", "pt"], entries).err();
+
+        assert_eq!(serial, Some(Error::UnknownLanguage("cz".into())));
+        assert_eq!(parallel, serial);
+
+        // Both duplicated and invalid, which `add_text` reports as a duplicate
+        let mut entries = self::entries(1000);
+        entries[600].0 = "key-3".into();
+        entries[600].2[0].0 = "cz";
+
+        let serial = Translator::from_entries(["en", "pt"], entries.clone()).err();
+        let parallel = Translator::from_entries_par(["en", "pt"], entries).err();
+
+        assert_eq!(serial, Some(Error::DuplicatedKey("key-3".into())));
+        assert_eq!(parallel, serial);
+    }
+}
@@ -0,0 +1,37 @@
+//! Detection of placeholder tokens in messages coming from external catalogs.
+
+/// Finds every `{open}NAME{close}` token in `message`, where `NAME` is made of
+/// alphanumerics, `_` or `-`. Tokens are yielded whole, delimiters included.
+pub(crate) fn placeholders<'a>(
+    message: &'a str,
+    open: &'a str,
+    close: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    message.match_indices(open).filter_map(move |(start, _)| {
+        let name_start = start + open.len();
+        let name_len = message[name_start..].find(close)?;
+        let name = &message[name_start..name_start + name_len];
+
+        let is_valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '-');
+
+        is_valid.then(|| &message[start..name_start + name_len + close.len()])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::placeholders;
+
+    #[test]
+    fn finds_placeholders() {
+        let found: Vec<_> =
+            placeholders("Hi %{NAME}, %{} %{a b} %{PLACE_2}%{", "%{", "}").collect();
+        assert_eq!(found, ["%{NAME}", "%{PLACE_2}"]);
+
+        let found: Vec<_> = placeholders("{A}{{B}} {C", "{", "}").collect();
+        assert_eq!(found, ["{A}", "{B}"]);
+    }
+}
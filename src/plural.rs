@@ -0,0 +1,449 @@
+//! Plural messages, selected through the CLDR plural rules of each language.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use compact_str::{format_compact, CompactString as SmallStr};
+
+use crate::{Error, Map, Translator};
+
+/// A CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// The name CLDR gives to this category.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    /// The cardinal category of `count` in `language`.
+    ///
+    /// Supports `en`, `pt`, `it` and `pl`, including regional variants such as `pt-BR`.
+    /// Every other language always gets [`PluralCategory::Other`].
+    pub fn cardinal(language: &str, count: u64) -> Self {
+        match primary_subtag(language) {
+            "en" | "it" => match count {
+                1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            "pt" => match count {
+                0 | 1 => PluralCategory::One,
+                _ => PluralCategory::Other,
+            },
+            "pl" => match (count, count % 10, count % 100) {
+                (1, _, _) => PluralCategory::One,
+                (_, 2..=4, rem100) if !(12..=14).contains(&rem100) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            _ => PluralCategory::Other,
+        }
+    }
+
+    /// The ordinal category of `ordinal` in `language`, as in `1st` or `2nd`.
+    ///
+    /// Supports `en`, including regional variants such as `en-GB`.
+    /// Every other language always gets [`PluralCategory::Other`].
+    pub fn ordinal(language: &str, ordinal: u64) -> Self {
+        match primary_subtag(language) {
+            "en" => match (ordinal % 10, ordinal % 100) {
+                (1, rem100) if rem100 != 11 => PluralCategory::One,
+                (2, rem100) if rem100 != 12 => PluralCategory::Two,
+                (3, rem100) if rem100 != 13 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The language of a tag such as `pt-BR` or `pt_BR`.
+pub(crate) fn primary_subtag(language: &str) -> &str {
+    language.split(['-', '_']).next().unwrap_or(language)
+}
+
+/// The message of each plural category given for a single language.
+pub(crate) type PluralForms = Map<PluralCategory, SmallStr>;
+
+impl Translator {
+    /// Registers a message that varies with a count, giving the message of each
+    /// [`PluralCategory`] per language.
+    ///
+    /// Plural messages are kept apart from the ones added through [`Translator::add_text`],
+    /// and are translated with [`Translator::translate_plural`]. Just like `add_text`,
+    /// every language must be given.
+    ///
+    /// ```
+    /// use binja::{PluralCategory, Translator};
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_plural(
+    ///     "items",
+    ///     ["COUNT"],
+    ///     [(
+    ///         "en",
+    ///         [
+    ///             (PluralCategory::One, "COUNT item"),
+    ///             (PluralCategory::Other, "COUNT items"),
+    ///         ],
+    ///     )],
+    /// )?;
+    ///
+    /// assert_eq!(tr.translate_plural("items", "en", 1, [("", ""); 0])?, "1 item");
+    /// assert_eq!(tr.translate_plural("items", "en", 5, [("", ""); 0])?, "5 items");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn add_plural<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, I3)>,
+        I3: IntoIterator<Item = (PluralCategory, S2)>,
+    >(
+        &mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+    ) -> Result<(), Error> {
+        let key = key.into();
+        if self.plurals.contains_key(&key) {
+            return Err(Error::DuplicatedKey(key));
+        }
+
+        let translations = translations.into_iter().map(|(language, forms)| {
+            let forms: PluralForms = forms
+                .into_iter()
+                .map(|(category, message)| (category, message.into()))
+                .collect();
+
+            (language, forms)
+        });
+        let mut translation =
+            self.build_translation(arguments, translations, true, |forms, pattern| {
+                forms.values().any(|form| form.contains(pattern))
+            })?;
+        translation.arguments = self.store_arguments(translation.arguments);
+
+        self.plurals.insert(key, translation);
+
+        Ok(())
+    }
+
+    /// Translates the plural message registered under `key`, picking the form
+    /// matching the plural category of `count` in `language`.
+    ///
+    /// Falls back to the `other` form when the category has no message, and fails
+    /// with [`Error::MissingPluralCategory`] if there's no `other` form either.
+    ///
+    /// `count` is the value of the first argument of the key, unless `args` gives one.
+    pub fn translate_plural<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        count: u64,
+        args: I,
+    ) -> Result<String, Error> {
+        self.translate_category(key, language, count, args, PluralCategory::cardinal)
+    }
+
+    /// Translates the plural message registered under `key`, picking the form
+    /// matching the ordinal category of `ordinal` in `language`.
+    ///
+    /// Just like [`Translator::translate_plural`], but for messages such as
+    /// "You finished in 2nd place", with `ordinal` as the value of the first argument.
+    ///
+    /// ```
+    /// use binja::{PluralCategory, Translator};
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_plural(
+    ///     "place",
+    ///     ["N"],
+    ///     [(
+    ///         "en",
+    ///         [
+    ///             (PluralCategory::One, "Nst place"),
+    ///             (PluralCategory::Two, "Nnd place"),
+    ///             (PluralCategory::Few, "Nrd place"),
+    ///             (PluralCategory::Other, "Nth place"),
+    ///         ],
+    ///     )],
+    /// )?;
+    ///
+    /// assert_eq!(tr.translate_ordinal("place", "en", 22, [("", ""); 0])?, "22nd place");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn translate_ordinal<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        ordinal: u64,
+        args: I,
+    ) -> Result<String, Error> {
+        self.translate_category(key, language, ordinal, args, PluralCategory::ordinal)
+    }
+
+    fn translate_category<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        count: u64,
+        args: I,
+        category: fn(&str, u64) -> PluralCategory,
+    ) -> Result<String, Error> {
+        let translation = self
+            .plurals
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let (language_id, pseudo) = self.find_target(language)?;
+        let (found_id, forms) = self.message_in(translation, key, language, language_id)?;
+        let format = self.value_format(language_id, pseudo);
+
+        // Picked by the rules of the language whose forms these are, which differs from
+        // the one asked for when its fallback chain was walked
+        let category = category(&self.languages[found_id], count);
+        let message = forms
+            .get(&category)
+            .or_else(|| forms.get(&PluralCategory::Other))
+            .ok_or_else(|| Error::MissingPluralCategory(key.into(), category))?;
+
+        let mut args: Vec<(SmallStr, SmallStr)> = args
+            .into_iter()
+            .map(|(argument, value)| (argument.into(), value.into()))
+            .collect();
+        if let Some(&first) = translation.declared_order.first() {
+            let argument = translation
+                .arguments
+                .resolve(&self.argument_table)
+                .name(first);
+            if !args.iter().any(|(given, _)| given == argument) {
+                args.push((argument.clone(), format_compact!("{count}")));
+            }
+        }
+
+        translation
+            .replace(&self.argument_table, format, message, args)
+            .map(Cow::into_owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluralCategory::{self, *};
+    use crate::{Error, Translator};
+
+    #[test]
+    fn cardinal_rules() {
+        let categories = |language| {
+            [0, 1, 2, 3, 5, 12, 22, 25, 101, 112]
+                .map(|count| PluralCategory::cardinal(language, count))
+        };
+
+        assert_eq!(
+            categories("en"),
+            [Other, One, Other, Other, Other, Other, Other, Other, Other, Other]
+        );
+        assert_eq!(categories("it"), categories("en"));
+        assert_eq!(
+            categories("pt-BR"),
+            [One, One, Other, Other, Other, Other, Other, Other, Other, Other]
+        );
+        assert_eq!(
+            categories("pl"),
+            [Many, One, Few, Few, Many, Many, Few, Many, Many, Many]
+        );
+        assert_eq!(categories("xx"), [Other; 10]);
+    }
+
+    #[test]
+    fn translate_plural() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it", "pl"]);
+
+        tr.add_plural(
+            "files",
+            ["COUNT"],
+            [
+                ("en", vec![(One, "COUNT file"), (Other, "COUNT files")]),
+                (
+                    "pt",
+                    vec![(One, "COUNT arquivo"), (Other, "COUNT arquivos")],
+                ),
+                ("it", vec![(One, "COUNT file"), (Other, "COUNT file")]),
+                (
+                    "pl",
+                    vec![
+                        (One, "COUNT plik"),
+                        (Few, "COUNT pliki"),
+                        (Many, "COUNT plików"),
+                    ],
+                ),
+            ],
+        )?;
+
+        let translate = |lang, count| tr.translate_plural("files", lang, count, [("", ""); 0]);
+
+        assert_eq!(translate("en", 0)?, "0 files");
+        assert_eq!(translate("en", 1)?, "1 file");
+        assert_eq!(translate("pt", 0)?, "0 arquivo");
+        assert_eq!(translate("pt", 7)?, "7 arquivos");
+        assert_eq!(translate("pl", 1)?, "1 plik");
+        assert_eq!(translate("pl", 22)?, "22 pliki");
+        assert_eq!(translate("pl", 12)?, "12 plików");
+        assert_eq!(
+            tr.translate_plural("files", "en", 3, [("COUNT", "three")])?,
+            "three files"
+        );
+
+        tr.add_plural(
+            "apples",
+            ["COUNT"],
+            [
+                ("en", vec![(One, "an apple")]),
+                ("pt", vec![(One, "uma maçã")]),
+                ("it", vec![(One, "una mela")]),
+                ("pl", vec![(One, "jabłko")]),
+            ],
+        )?;
+        assert_eq!(
+            tr.translate_plural("apples", "en", 2, [("COUNT", "2")]),
+            Err(Error::MissingPluralCategory("apples".into(), Other))
+        );
+
+        assert_eq!(
+            tr.translate_plural("greetings", "en", 2, [("COUNT", "2")]),
+            Err(Error::MissingKey("greetings".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn plural_rules_of_resolved_language() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).case_insensitive_languages(true);
+        tr.add_alias("english", "en")?;
+        tr.add_plural(
+            "items",
+            ["COUNT"],
+            [("en", [(One, "COUNT item"), (Other, "COUNT items")])],
+        )?;
+
+        for lang in ["en", "EN", "english", "English"] {
+            assert_eq!(
+                tr.translate_plural("items", lang, 1, [("COUNT", "1")])?,
+                "1 item"
+            );
+            assert_eq!(
+                tr.translate_plural("items", lang, 2, [("COUNT", "2")])?,
+                "2 items"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn plural_rules_of_fallback_language() -> Result<(), Error> {
+        let mut english = Translator::new(["en"]);
+        english.add_plural(
+            "items",
+            ["COUNT"],
+            [("en", [(One, "COUNT item"), (Other, "COUNT items")])],
+        )?;
+
+        // Merged keys may lack the languages the other translator didn't have
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.merge(english)?;
+        tr.set_fallback_chain("pt", ["en"])?;
+
+        // Zero is `one` in Portuguese, but `other` in English, whose forms are used
+        assert_eq!(
+            tr.translate_plural("items", "pt", 0, [("COUNT", "0")])?,
+            "0 items"
+        );
+        assert_eq!(
+            tr.translate_plural("items", "pt", 1, [("COUNT", "1")])?,
+            "1 item"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn translate_ordinal() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.add_plural(
+            "place",
+            ["N"],
+            [
+                (
+                    "en",
+                    vec![
+                        (One, "You finished in Nst place"),
+                        (Two, "You finished in Nnd place"),
+                        (Few, "You finished in Nrd place"),
+                        (Other, "You finished in Nth place"),
+                    ],
+                ),
+                (
+                    "pt",
+                    vec![(One, "Terminaste em Nº"), (Other, "Terminaste em Nº")],
+                ),
+            ],
+        )?;
+
+        let translate = |lang, ordinal| tr.translate_ordinal("place", lang, ordinal, [("", ""); 0]);
+
+        assert_eq!(translate("en", 1)?, "You finished in 1st place");
+        assert_eq!(translate("en", 2)?, "You finished in 2nd place");
+        assert_eq!(translate("en", 3)?, "You finished in 3rd place");
+        assert_eq!(translate("en", 4)?, "You finished in 4th place");
+        assert_eq!(translate("en", 11)?, "You finished in 11th place");
+        assert_eq!(translate("en", 21)?, "You finished in 21st place");
+        assert_eq!(translate("en", 112)?, "You finished in 112th place");
+        assert_eq!(translate("pt", 1)?, "Terminaste em 1º");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+//! Loading of gettext `.po` catalogs.
+
+use std::io::BufRead;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::placeholder::placeholders;
+use crate::{Error, Translator};
+
+impl Translator {
+    /// Reads the `msgid`/`msgstr` pairs of a `.po` file holding messages for `language`.
+    ///
+    /// Each `msgid` becomes a key, and every `%{NAME}`-style token found in its messages
+    /// becomes an argument, spelled in full (i.e. `%{NAME}`, not `NAME`).
+    ///
+    /// Since `.po` files hold a single language, keys are only registered once every
+    /// known language has been loaded, at which point the usual [`Translator::add_text`]
+    /// validation applies.
+    pub fn load_po<R: BufRead>(&mut self, language: &str, reader: R) -> Result<(), Error> {
+        let language_id = self.find_language(language)?;
+
+        let entries = parse(reader)?;
+
+        self.load_catalog(
+            |translator| &mut translator.po_catalog,
+            language_id,
+            entries,
+            |message| placeholders(message, "%{", "}").collect(),
+        )
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Field {
+    None,
+    Context,
+    Id,
+    IdPlural,
+    Str,
+    /// A `msgstr[n]` of a plural entry. Only the first form is kept.
+    PluralStr(bool),
+}
+
+#[derive(Default)]
+struct Entry {
+    msgid: Option<SmallStr>,
+    msgstr: SmallStr,
+}
+
+/// Reads the translated entries of a `.po` file, skipping its header and untranslated entries.
+fn parse<R: BufRead>(reader: R) -> Result<Vec<(SmallStr, SmallStr)>, Error> {
+    let mut entries = Vec::new();
+    let mut entry = Entry::default();
+    let mut field = Field::None;
+
+    let mut finish = |entry: &mut Entry| {
+        let Entry { msgid, msgstr } = std::mem::take(entry);
+
+        match msgid {
+            Some(msgid) if !msgid.is_empty() && !msgstr.is_empty() => entries.push((msgid, msgstr)),
+            _ => {}
+        }
+    };
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.map_err(|err| Error::Parse(format!("line {line_number}: {err}")))?;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = |message: &str| Error::Parse(format!("line {line_number}: {message}"));
+
+        let (keyword, rest) = if line.starts_with('"') {
+            ("", line)
+        } else {
+            line.split_once(char::is_whitespace)
+                .map(|(keyword, rest)| (keyword, rest.trim_start()))
+                .ok_or_else(|| error("expected a keyword followed by a string"))?
+        };
+
+        let new_field = match keyword {
+            "" => None,
+            "msgctxt" => Some(Field::Context),
+            "msgid" => Some(Field::Id),
+            "msgid_plural" => Some(Field::IdPlural),
+            "msgstr" => Some(Field::Str),
+            "msgstr[0]" => Some(Field::PluralStr(true)),
+            _ if keyword.starts_with("msgstr[") => Some(Field::PluralStr(false)),
+            _ => return Err(error(&format!("unknown keyword `{keyword}`"))),
+        };
+
+        if let Some(new_field) = new_field {
+            // A `msgctxt` or `msgid` after a `msgstr` starts the next entry
+            let starts_entry = matches!(new_field, Field::Context | Field::Id);
+            if starts_entry && matches!(field, Field::Str | Field::PluralStr(_)) {
+                finish(&mut entry);
+            }
+            field = new_field;
+        }
+
+        let string = unquote(rest).ok_or_else(|| error("malformed string"))?;
+
+        match field {
+            Field::None => return Err(error("string continuation without a keyword")),
+            Field::Id => entry
+                .msgid
+                .get_or_insert_with(Default::default)
+                .push_str(&string),
+            Field::Str | Field::PluralStr(true) => entry.msgstr.push_str(&string),
+            Field::Context | Field::IdPlural | Field::PluralStr(false) => {}
+        }
+    }
+
+    finish(&mut entry);
+
+    Ok(entries)
+}
+
+/// Strips the quotes of a `.po` string and resolves its escape sequences.
+fn unquote(string: &str) -> Option<SmallStr> {
+    let inner = string.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut unquoted = SmallStr::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(ch) = chars.next() {
+        let ch = match ch {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                't' => '\t',
+                'r' => '\r',
+                '"' => '"',
+                '\\' => '\\',
+                _ => return None,
+            },
+            '"' => return None,
+            ch => ch,
+        };
+        unquoted.push(ch);
+    }
+
+    Some(unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    const PT: &str = r#"
+# Portuguese translations
+msgid ""
+msgstr ""
+"Language: pt\n"
+
+#: src/main.rs:10
+msgid "greetings"
+msgstr "Bom dia, %{NAME}!"
+
+msgid "farewell"
+msgstr ""
+"Tchau, "
+"%{NAME}!"
+
+msgid "untranslated"
+msgstr ""
+"#;
+
+    const EN: &str = r#"
+msgid "greetings"
+msgstr "Good morning, %{NAME}!"
+
+msgid "farewell"
+msgstr "Goodbye, %{NAME}!"
+"#;
+
+    #[test]
+    fn load_po() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.load_po("pt", PT.as_bytes())?;
+        // Not registered until every language is loaded
+        assert!(!tr.contains_key("greetings"));
+
+        tr.load_po("en", EN.as_bytes())?;
+
+        assert_eq!(
+            tr.translate("greetings", "pt", [("%{NAME}", "Julian")])?,
+            "Bom dia, Julian!"
+        );
+        assert_eq!(
+            tr.translate("farewell", "pt", [("%{NAME}", "Julian")])?,
+            "Tchau, Julian!"
+        );
+        assert_eq!(
+            tr.translate("farewell", "en", [("%{NAME}", "Julian")])?,
+            "Goodbye, Julian!"
+        );
+        assert!(!tr.contains_key("untranslated"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_po_errors() {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        assert_eq!(
+            tr.load_po("cz", EN.as_bytes()),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+        assert!(matches!(
+            tr.load_po("en", "msgid \"greetings\nmsgstr \"\"".as_bytes()),
+            Err(Error::Parse(_))
+        ));
+
+        // Keys are checked in sorted order, so `farewell` always fails first
+        for _ in 0..8 {
+            let mut tr = Translator::new(["en", "pt"]);
+            tr.load_po("en", EN.as_bytes()).unwrap();
+            assert_eq!(
+                tr.load_po("pt", "msgid \"greetings\"\nmsgstr \"Olá\"".as_bytes()),
+                Err(Error::MissingLanguages(vec!["pt".into()]))
+            );
+            assert!(tr.is_empty());
+
+            // The failed language can be loaded again, keeping the ones loaded before
+            tr.load_po("pt", PT.as_bytes()).unwrap();
+            assert_eq!(tr.len(), 2);
+        }
+    }
+}
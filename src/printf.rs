@@ -0,0 +1,106 @@
+//! printf-style format specifiers, as used by Android and Apple catalogs.
+
+use alloc::vec::Vec;
+
+use compact_str::{format_compact, CompactString as SmallStr};
+
+/// The `%x` or `%N$x` format specifier at the beginning of `text`, if any, returning
+/// whether it is numbered along with its length. `x` is either a letter or `@`.
+fn format_specifier(text: &str) -> Option<(bool, usize)> {
+    let rest = text.strip_prefix('%')?;
+
+    let digits = rest
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (is_numbered, rest) = match rest[digits..].strip_prefix('$') {
+        Some(rest) if digits > 0 => (true, rest),
+        _ => (false, rest),
+    };
+
+    let conversion = rest
+        .chars()
+        .next()
+        .filter(|ch| ch.is_ascii_alphabetic() || *ch == '@')?;
+
+    Some((is_numbered, text.len() - rest.len() + conversion.len_utf8()))
+}
+
+/// Numbers every unnumbered format specifier of `message` by its position, so that
+/// e.g. `%s and %s` becomes `%1$s and %2$s`.
+pub(crate) fn number_specifiers(message: &str) -> SmallStr {
+    let mut numbered = SmallStr::with_capacity(message.len());
+    let mut next_position = 1;
+    let mut rest = message;
+
+    while let Some(start) = rest.find('%') {
+        numbered.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(literal) = rest.strip_prefix("%%") {
+            numbered.push_str("%%");
+            rest = literal;
+            continue;
+        }
+
+        match format_specifier(rest) {
+            Some((false, len)) => {
+                numbered.push_str(&format_compact!("%{next_position}$"));
+                numbered.push_str(&rest[1..len]);
+                next_position += 1;
+                rest = &rest[len..];
+            }
+            Some((true, len)) => {
+                numbered.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+            None => {
+                numbered.push('%');
+                rest = &rest[1..];
+            }
+        }
+    }
+    numbered.push_str(rest);
+
+    numbered
+}
+
+/// Every numbered format specifier of `message`, such as `%1$s`.
+pub(crate) fn format_specifiers(message: &str) -> Vec<&str> {
+    let mut specifiers = Vec::new();
+    let mut rest = message;
+    let mut offset = 0;
+
+    while let Some(start) = rest.find('%') {
+        let found = &message[offset + start..];
+
+        let skipped = match format_specifier(found) {
+            _ if found.starts_with("%%") => 2,
+            Some((true, len)) => {
+                specifiers.push(&found[..len]);
+                len
+            }
+            _ => 1,
+        };
+        offset += start + skipped;
+        rest = &message[offset..];
+    }
+
+    specifiers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_specifiers, number_specifiers};
+
+    #[test]
+    fn specifiers() {
+        assert_eq!(
+            number_specifiers("%s and %d, 100%%, %2$s, %@"),
+            "%1$s and %2$d, 100%%, %2$s, %3$@"
+        );
+        assert_eq!(
+            format_specifiers("%1$s and %2$d, 100%%, % 5, %3$@"),
+            ["%1$s", "%2$d", "%3$@"]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Exporting to Java `.properties` files.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::{Error, Translator};
+
+/// Appends `text` to `output`, escaping it as the key or value of a `.properties` entry.
+///
+/// Characters outside of printable ASCII are written as `\uXXXX` escapes, since
+/// `.properties` files were historically read as ISO-8859-1.
+fn write_escaped(output: &mut String, text: &str, is_key: bool) {
+    for (index, ch) in text.chars().enumerate() {
+        match ch {
+            '\\' | '=' | ':' | '#' | '!' => {
+                output.push('\\');
+                output.push(ch);
+            }
+            // Leading spaces of values would otherwise be skipped, and any space ends a key
+            ' ' if is_key || index == 0 => output.push_str("\\ "),
+            '\n' => output.push_str("\\n"),
+            '\t' => output.push_str("\\t"),
+            '\r' => output.push_str("\\r"),
+            '\u{c}' => output.push_str("\\f"),
+            ' '..='~' => output.push(ch),
+            _ => {
+                let mut units = [0; 2];
+                for unit in ch.encode_utf16(&mut units) {
+                    let _ = write!(output, "\\u{unit:04X}");
+                }
+            }
+        }
+    }
+}
+
+impl Translator {
+    /// Serializes the messages of `language` into a Java `.properties` document, with one
+    /// `key=message` line per key, in lexicographic order, or by the
+    /// [collation](Translator::with_collation) if one is set.
+    ///
+    /// Keys with no message for `language` are left out.
+    pub fn to_properties(&self, language: &str) -> Result<String, Error> {
+        let language_id = self.find_language(language)?;
+
+        let mut entries: Vec<_> = self
+            .translations
+            .iter()
+            .filter_map(|(key, translation)| {
+                let message = translation.translations.get(&language_id)?;
+                Some((key, message))
+            })
+            .collect();
+        self.sort_keys(&mut entries);
+
+        let mut properties = String::new();
+        for (key, message) in entries {
+            write_escaped(&mut properties, key, true);
+            properties.push('=');
+            write_escaped(&mut properties, message, false);
+            properties.push('\n');
+        }
+
+        Ok(properties)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn to_properties() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+        tr.add_text(
+            "menu: open file",
+            [""; 0],
+            [("en", "Open = load"), ("pt", "Abrir ficheiro…")],
+        )?;
+        tr.add_text(
+            "multiline",
+            [""; 0],
+            [("en", "  First\nSecond"), ("pt", "Primeira\\nSegunda 😀")],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!")])?;
+
+        assert_eq!(
+            tr.to_properties("en")?,
+            "bye=Bye\\!\n\
+             greetings=Good morning, NAME\\!\n\
+             menu\\:\\ open\\ file=Open \\= load\n\
+             multiline=\\  First\\nSecond\n"
+        );
+        assert_eq!(
+            tr.to_properties("pt")?,
+            "greetings=Bom dia, NAME\\!\n\
+             menu\\:\\ open\\ file=Abrir ficheiro\\u2026\n\
+             multiline=Primeira\\\\nSegunda \\uD83D\\uDE00\n"
+        );
+        assert_eq!(
+            tr.to_properties("cz"),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+}
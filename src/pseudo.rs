@@ -0,0 +1,340 @@
+//! Pseudolocalization, making untranslated or truncated text stand out during QA.
+
+use core::fmt;
+
+use crate::plural::primary_subtag;
+use crate::{Error, LanguageId, Translator};
+
+/// The language translated into when pseudolocalizing, see [`Translator::pseudolocalize`].
+pub(crate) const PSEUDO_LOCALE: &str = "en-XA";
+
+/// Marks the start of a pseudolocalized message.
+pub(crate) const OPENING: &str = "[[";
+/// Marks the end of a pseudolocalized message.
+pub(crate) const CLOSING: &str = "]]";
+
+/// Lengthens pseudolocalized messages, to find layouts that can't fit longer translations.
+const PADDING: char = '~';
+/// How many characters of a pseudolocalized message get one [`PADDING`] each.
+const PADDING_RATIO: usize = 3;
+
+/// How the literal text of a message is altered when translating it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Pseudo {
+    /// Kept as it is, as in every language but the pseudolocale.
+    Off,
+    /// Accented, as in the messages referenced by a pseudolocalized one.
+    Accented,
+    /// Accented, padded and wrapped in [`OPENING`] and [`CLOSING`].
+    Expanded,
+}
+
+/// `ch` with an accent, if it's an ASCII letter.
+fn accent(ch: char) -> char {
+    match ch {
+        'a' => 'å',
+        'b' => 'ƀ',
+        'c' => 'ç',
+        'd' => 'ḓ',
+        'e' => 'é',
+        'f' => 'ƒ',
+        'g' => 'ḡ',
+        'h' => 'ĥ',
+        'i' => 'í',
+        'j' => 'ĵ',
+        'k' => 'ķ',
+        'l' => 'ļ',
+        'm' => 'ṁ',
+        'n' => 'ñ',
+        'o' => 'ö',
+        'p' => 'þ',
+        'q' => 'ǫ',
+        'r' => 'ř',
+        's' => 'š',
+        't' => 'ţ',
+        'u' => 'û',
+        'v' => 'ṽ',
+        'w' => 'ŵ',
+        'x' => 'ẋ',
+        'y' => 'ý',
+        'z' => 'ž',
+        'A' => 'Å',
+        'B' => 'Ɓ',
+        'C' => 'Ç',
+        'D' => 'Ḓ',
+        'E' => 'É',
+        'F' => 'Ƒ',
+        'G' => 'Ḡ',
+        'H' => 'Ĥ',
+        'I' => 'Í',
+        'J' => 'Ĵ',
+        'K' => 'Ķ',
+        'L' => 'Ļ',
+        'M' => 'Ṁ',
+        'N' => 'Ñ',
+        'O' => 'Ö',
+        'P' => 'Þ',
+        'Q' => 'Ǫ',
+        'R' => 'Ř',
+        'S' => 'Š',
+        'T' => 'Ţ',
+        'U' => 'Û',
+        'V' => 'Ṽ',
+        'W' => 'Ŵ',
+        'X' => 'Ẋ',
+        'Y' => 'Ý',
+        'Z' => 'Ž',
+        _ => ch,
+    }
+}
+
+/// Writes `text` into `writer` with its letters accented.
+pub(crate) fn write_accented<W: fmt::Write + ?Sized>(writer: &mut W, text: &str) -> fmt::Result {
+    text.chars()
+        .try_for_each(|ch| writer.write_char(accent(ch)))
+}
+
+/// The padding of a pseudolocalized message `chars` characters long.
+pub(crate) fn padding(chars: usize) -> impl Iterator<Item = char> {
+    core::iter::repeat_n(PADDING, chars.div_ceil(PADDING_RATIO))
+}
+
+/// Counts the characters written through it, to pad them accordingly.
+pub(crate) struct Counting<'w, W: ?Sized> {
+    writer: &'w mut W,
+    chars: usize,
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for Counting<'_, W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.chars += text.chars().count();
+        self.writer.write_str(text)
+    }
+}
+
+/// Writes what `write` writes into `writer`, padded and wrapped in [`OPENING`] and
+/// [`CLOSING`].
+pub(crate) fn write_expanded<W: fmt::Write + ?Sized>(
+    writer: &mut W,
+    write: impl FnOnce(&mut Counting<'_, W>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    writer.write_str(OPENING)?;
+
+    let mut counting = Counting { writer, chars: 0 };
+    write(&mut counting)?;
+    let chars = counting.chars;
+
+    padding(chars).try_for_each(|ch| writer.write_char(ch))?;
+    writer.write_str(CLOSING)?;
+
+    Ok(())
+}
+
+impl Translator {
+    /// When enabled, translating into the reserved `en-XA` language gives the `en`
+    /// message with its letters accented, padded by a `~` for every three characters
+    /// and wrapped in `[[` and `]]`, as in `[[Ḡööḓ ṁöřñíñḡ, Julian!~~~~~~~]]`, so that
+    /// text left untranslated, or cut off by a layout that can't fit longer
+    /// translations, stands out.
+    ///
+    /// Argument values and placeholders are left untouched, and messages referenced by
+    /// other keys are only accented. `en-XA` is accepted wherever a language is
+    /// translated into, e.g. by [`Translator::translate_plural`] or
+    /// [`Translator::language_id`].
+    ///
+    /// Disabled by default.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]).pseudolocalize(true);
+    /// tr.add_text("greetings", ["NAME"], [("en", "Good morning, NAME!")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate("greetings", "en-XA", [("NAME", "Julian")])?,
+    ///     "[[Ḡööḓ ṁöřñíñḡ, Julian!~~~~~~~]]"
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn pseudolocalize(mut self, enabled: bool) -> Self {
+        self.pseudolocalize = enabled;
+        self
+    }
+
+    /// Whether `language` is the pseudolocale, and pseudolocalization is enabled.
+    pub(crate) fn is_pseudolocale(&self, language: &str) -> bool {
+        self.pseudolocalize && language == PSEUDO_LOCALE
+    }
+
+    /// Finds `language` as [`Translator::find_language`] does, to translate into it,
+    /// along with how the text of its messages is altered.
+    ///
+    /// The pseudolocale is found as the language its messages are taken from.
+    pub(crate) fn find_target(&self, language: &str) -> Result<(LanguageId, Pseudo), Error> {
+        match self.is_pseudolocale(language) {
+            true => Ok((
+                self.find_language(primary_subtag(PSEUDO_LOCALE))?,
+                Pseudo::Expanded,
+            )),
+            false => Ok((self.find_language(language)?, Pseudo::Off)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, PluralCategory, Segment, Translator};
+
+    #[test]
+    fn pseudolocalize() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"])
+            .with_delimiters("{", "}")
+            .pseudolocalize(true);
+        tr.add_text(
+            "greetings",
+            ["name", "count:number"],
+            [
+                (
+                    "en",
+                    "Good morning, {name}! You have {count:number} messages.",
+                ),
+                ("pt", "Bom dia, {name}! Tens {count:number} mensagens."),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate(
+                "greetings",
+                "en-XA",
+                [("name", "Julian"), ("count", "1200")]
+            )?,
+            "[[Ḡööḓ ṁöřñíñḡ, Julian! Ýöû ĥåṽé 1,200 ṁéššåḡéš.~~~~~~~~~~~~~~~~]]"
+        );
+        assert_eq!(
+            tr.translate("greetings", "en-XA", [("name", "Julian")])?,
+            "[[Ḡööḓ ṁöřñíñḡ, Julian! Ýöû ĥåṽé {count:number} ṁéššåḡéš.~~~~~~~~~~~~~~~~~~~]]"
+        );
+        // Other languages are left alone
+        assert_eq!(
+            tr.translate("greetings", "en", [("name", "Julian"), ("count", "1")])?,
+            "Good morning, Julian! You have 1 messages."
+        );
+        assert_eq!(
+            tr.translate("farewell", "en-XA", [("name", "Julian")]),
+            Err(Error::MissingKey("farewell".into()))
+        );
+
+        // Disabled by default
+        let mut tr = Translator::new(["en"]);
+        tr.add_text("bye", [""; 0], [("en", "Bye!")])?;
+        assert_eq!(
+            tr.translate("bye", "en-XA", [("", ""); 0]),
+            Err(Error::UnknownLanguage("en-XA".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn pseudolocale_entry_points() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"])
+            .pseudolocalize(true)
+            .resolve_references(true);
+        tr.add_text(
+            "greeting",
+            ["NAME"],
+            [("en", "Hi, NAME"), ("pt", "Olá, NAME")],
+        )?;
+        tr.add_text(
+            "welcome",
+            ["NAME"],
+            [("en", "${greeting}!"), ("pt", "${greeting}!")],
+        )?;
+        tr.add_plural(
+            "items",
+            ["COUNT"],
+            [
+                (
+                    "en",
+                    [
+                        (PluralCategory::One, "COUNT item"),
+                        (PluralCategory::Other, "COUNT items"),
+                    ],
+                ),
+                (
+                    "pt",
+                    [
+                        (PluralCategory::One, "COUNT item"),
+                        (PluralCategory::Other, "COUNT itens"),
+                    ],
+                ),
+            ],
+        )?;
+
+        let greeting = "[[Ĥí, Ana~~~]]";
+        assert_eq!(
+            tr.translate("greeting", "en-XA", [("NAME", "Ana")])?,
+            greeting
+        );
+
+        let mut output = String::new();
+        tr.translate_into(&mut output, "greeting", "en-XA", [("NAME", "Ana")])?;
+        assert_eq!(output, greeting);
+        assert_eq!(
+            tr.translate_resolved("greeting", "en-XA", [("NAME", "Ana")])?,
+            (greeting.into(), "en-XA")
+        );
+        assert_eq!(
+            tr.translate_checked("greeting", "en-XA", [("NAME", "Ana")])?,
+            greeting
+        );
+        assert_eq!(
+            tr.writer("greeting", "en-XA")?
+                .arg("NAME", "Ana")?
+                .finish()?,
+            greeting
+        );
+        assert_eq!(
+            tr.translate_batch("en-XA", &[("greeting", &[("NAME", "Ana")])])?,
+            [greeting]
+        );
+
+        let language = tr.language_id("en-XA").unwrap();
+        assert_eq!(
+            tr.translate_by_id("greeting", language, [("NAME", "Ana")])?,
+            greeting
+        );
+
+        #[cfg(feature = "std")]
+        {
+            let mut output = Vec::new();
+            tr.translate_stream(&mut output, "greeting", "en-XA", [("NAME", "Ana")])?;
+            assert_eq!(output, greeting.as_bytes());
+        }
+
+        assert_eq!(
+            tr.translate_segments("greeting", "en-XA", [("NAME", "Ana")])?,
+            [
+                Segment::Literal("[[".into()),
+                Segment::Literal("Ĥí, ".into()),
+                Segment::Argument {
+                    name: "NAME",
+                    value: "Ana".into()
+                },
+                Segment::Literal("~~~]]".into()),
+            ]
+        );
+
+        // Only the referencing message is padded and bracketed
+        assert_eq!(
+            tr.translate("welcome", "en-XA", [("NAME", "Ana")])?,
+            "[[Ĥí, Ana!~~~]]"
+        );
+        assert_eq!(
+            tr.translate_plural("items", "en-XA", 1, [("COUNT", "1")])?,
+            "[[1 íţéṁ~~]]"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,311 @@
+//! Messages that branch on explicit ranges of a count, such as `2..=4` in languages
+//! whose plural rules aren't covered by [`PluralCategory`](crate::PluralCategory).
+
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translator};
+
+/// The range of counts and message of each entry given for a single language, in the
+/// order they were given.
+pub(crate) type RangedMessages = Box<[(RangeInclusive<u64>, SmallStr)]>;
+
+/// A possible mistake in the ranges of a key, found by [`Translator::validate_ranges`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeIssue {
+    pub key: SmallStr,
+    /// The language whose ranges are at fault.
+    pub language: SmallStr,
+    pub kind: RangeIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeIssueKind {
+    /// Both ranges contain some count, so the second is never picked for it.
+    Overlap(RangeInclusive<u64>, RangeInclusive<u64>),
+    /// No range contains these counts, so translating them fails.
+    Gap(RangeInclusive<u64>),
+}
+
+/// Finds the overlaps and gaps among `ranges`, in order of their start.
+fn range_issues(ranges: &[(RangeInclusive<u64>, SmallStr)]) -> Vec<RangeIssueKind> {
+    let mut sorted: Vec<_> = ranges
+        .iter()
+        .map(|(range, _)| range)
+        .filter(|range| !range.is_empty())
+        .collect();
+    sorted.sort_by_key(|range| (*range.start(), *range.end()));
+
+    let mut issues = Vec::new();
+    // The range reaching the furthest so far, and the first count none has covered yet
+    let mut furthest: Option<&RangeInclusive<u64>> = None;
+    let mut uncovered = Some(0);
+
+    for range in sorted {
+        match uncovered {
+            Some(first) if first < *range.start() => {
+                issues.push(RangeIssueKind::Gap(first..=range.start() - 1));
+            }
+            _ => {}
+        }
+        if let Some(previous) = furthest.filter(|previous| previous.end() >= range.start()) {
+            issues.push(RangeIssueKind::Overlap(previous.clone(), range.clone()));
+        }
+
+        if furthest.is_none_or(|previous| previous.end() < range.end()) {
+            furthest = Some(range);
+            uncovered = range.end().checked_add(1);
+        }
+    }
+
+    if let Some(first) = uncovered {
+        issues.push(RangeIssueKind::Gap(first..=u64::MAX));
+    }
+
+    issues
+}
+
+impl Translator {
+    /// Registers a message that varies with a count, giving per language the message
+    /// of each range of counts.
+    ///
+    /// Ranged messages are kept apart from the ones added through [`Translator::add_text`],
+    /// and are translated with [`Translator::translate_ranged`]. Just like `add_text`,
+    /// every language must be given. Ranges aren't checked here, see
+    /// [`Translator::validate_ranges`].
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_ranged(
+    ///     "apples",
+    ///     ["COUNT"],
+    ///     [(
+    ///         "en",
+    ///         [
+    ///             (0..=0, "No apples"),
+    ///             (1..=9, "A few apples"),
+    ///             (10..=u64::MAX, "COUNT apples"),
+    ///         ],
+    ///     )],
+    /// )?;
+    ///
+    /// assert_eq!(tr.translate_ranged("apples", "en", 3, [("COUNT", "3")])?, "A few apples");
+    /// assert_eq!(tr.translate_ranged("apples", "en", 12, [("COUNT", "12")])?, "12 apples");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn add_ranged<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, I3)>,
+        I3: IntoIterator<Item = (RangeInclusive<u64>, S2)>,
+    >(
+        &mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+    ) -> Result<(), Error> {
+        let key = key.into();
+        if self.ranged.contains_key(&key) {
+            return Err(Error::DuplicatedKey(key));
+        }
+
+        let translations = translations.into_iter().map(|(language, ranges)| {
+            let ranges: RangedMessages = ranges
+                .into_iter()
+                .map(|(range, message)| (range, message.into()))
+                .collect();
+
+            (language, ranges)
+        });
+        let mut translation =
+            self.build_translation(arguments, translations, true, |ranges, pattern| {
+                ranges.iter().any(|(_, message)| message.contains(pattern))
+            })?;
+        translation.arguments = self.store_arguments(translation.arguments);
+
+        self.ranged.insert(key, translation);
+
+        Ok(())
+    }
+
+    /// Translates the ranged message registered under `key`, picking in `language` the
+    /// message of the first range containing `count`.
+    ///
+    /// Fails with [`Error::MissingRange`] if no range contains `count`.
+    pub fn translate_ranged<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        count: u64,
+        args: I,
+    ) -> Result<String, Error> {
+        let translation = self
+            .ranged
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let (ranges, format) = self.message_for(translation, key, language)?;
+
+        let (_, message) = ranges
+            .iter()
+            .find(|(range, _)| range.contains(&count))
+            .ok_or_else(|| Error::MissingRange(key.into(), count))?;
+
+        translation
+            .replace(&self.argument_table, format, message, args)
+            .map(Cow::into_owned)
+    }
+
+    /// Checks the ranges of every ranged key for overlaps and for counts no range
+    /// contains, sorted by key, then language, then the start of the ranges at fault.
+    pub fn validate_ranges(&self) -> Vec<RangeIssue> {
+        let mut keys: Vec<_> = self.ranged.iter().collect();
+        keys.sort_unstable_by_key(|(key, _)| *key);
+
+        let mut issues = Vec::new();
+
+        for (key, translation) in keys {
+            for (language_id, language) in self.languages.iter().enumerate() {
+                let Some(ranges) = translation.translations.get(&language_id) else {
+                    continue;
+                };
+
+                issues.extend(range_issues(ranges).into_iter().map(|kind| RangeIssue {
+                    key: key.clone(),
+                    language: language.clone(),
+                    kind,
+                }));
+            }
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RangeIssue, RangeIssueKind};
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_ranged() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_ranged(
+            "items",
+            ["COUNT"],
+            [
+                (
+                    "en",
+                    [
+                        (0..=0, "No items"),
+                        (1..=1, "One item"),
+                        (2..=9, "COUNT items"),
+                        (10..=u64::MAX, "Lots of items"),
+                    ],
+                ),
+                (
+                    "pt",
+                    [
+                        (0..=1, "COUNT item"),
+                        (1..=9, "COUNT itens"),
+                        (10..=99, "Muitos itens"),
+                        (0..=0, "Nenhum item"),
+                    ],
+                ),
+            ],
+        )?;
+
+        let translate = |lang, count: u64| {
+            tr.translate_ranged("items", lang, count, [("COUNT", count.to_string())])
+        };
+
+        assert_eq!(translate("en", 0)?, "No items");
+        assert_eq!(translate("en", 1)?, "One item");
+        assert_eq!(translate("en", 9)?, "9 items");
+        assert_eq!(translate("en", 10)?, "Lots of items");
+        assert_eq!(translate("en", u64::MAX)?, "Lots of items");
+
+        // The first range containing the count wins
+        assert_eq!(translate("pt", 0)?, "0 item");
+        assert_eq!(translate("pt", 1)?, "1 item");
+        assert_eq!(translate("pt", 9)?, "9 itens");
+        assert_eq!(translate("pt", 10)?, "Muitos itens");
+        assert_eq!(
+            translate("pt", 100),
+            Err(Error::MissingRange("items".into(), 100))
+        );
+
+        assert_eq!(
+            tr.translate_ranged("things", "en", 1, [("COUNT", "1")]),
+            Err(Error::MissingKey("things".into()))
+        );
+        assert_eq!(
+            tr.add_ranged("items", ["COUNT"], [("en", [(0..=0, "COUNT")])]),
+            Err(Error::DuplicatedKey("items".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_ranges() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_ranged(
+            "items",
+            [""; 0],
+            [
+                ("en", vec![(0..=0, "None"), (1..=u64::MAX, "Some")]),
+                (
+                    "pt",
+                    vec![(1..=9, "Alguns"), (0..=1, "Um"), (20..=29, "Muitos")],
+                ),
+            ],
+        )?;
+        tr.add_ranged(
+            "boxes",
+            [""; 0],
+            [
+                ("en", vec![(0..=5, "Few"), (2..=3, "Some")]),
+                ("pt", vec![(0..=u64::MAX, "Caixas")]),
+            ],
+        )?;
+
+        let issue = |key: &str, kind| RangeIssue {
+            key: key.into(),
+            language: "pt".into(),
+            kind,
+        };
+
+        assert_eq!(
+            tr.validate_ranges(),
+            [
+                RangeIssue {
+                    language: "en".into(),
+                    ..issue("boxes", RangeIssueKind::Overlap(0..=5, 2..=3))
+                },
+                RangeIssue {
+                    language: "en".into(),
+                    ..issue("boxes", RangeIssueKind::Gap(6..=u64::MAX))
+                },
+                issue("items", RangeIssueKind::Overlap(0..=1, 1..=9)),
+                issue("items", RangeIssueKind::Gap(10..=19)),
+                issue("items", RangeIssueKind::Gap(30..=u64::MAX)),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+//! Messages embedding other messages through `${key}` references.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::pseudo::{self, Pseudo};
+use crate::{Error, Translation, Translator};
+
+/// Opens a reference to another key, as in `${greeting}`.
+const REFERENCE_OPEN: &str = "${";
+/// Closes a reference to another key.
+const REFERENCE_CLOSE: char = '}';
+
+/// How many references deep a message may go by default, see
+/// [`Translator::with_max_reference_depth`].
+pub(crate) const DEFAULT_MAX_REFERENCE_DEPTH: usize = 16;
+
+/// Finds the translation of a key referenced by a message.
+pub(crate) type FindText<'a> = &'a dyn Fn(&str) -> Option<&'a Translation>;
+
+impl Translator {
+    /// Sets whether messages may embed other messages through references.
+    ///
+    /// A message references another key through `${key}`, which is replaced by the
+    /// translation of `key` into the same language. Each message gets the arguments
+    /// it declares out of the ones given to [`Translator::translate`], so arguments
+    /// declared by none of them are ignored rather than rejected.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]).resolve_references(true);
+    /// tr.add_text("greeting", ["NAME"], [("en", "Hello, NAME")])?;
+    /// tr.add_text("welcome", ["PLACE"], [("en", "${greeting}, welcome to PLACE!")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate("welcome", "en", [("NAME", "Ana"), ("PLACE", "Lisboa")])?,
+    ///     "Hello, Ana, welcome to Lisboa!"
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    ///
+    /// Disabled by default, so that `${` is kept as written, as in `Cost: ${AMOUNT}`.
+    pub fn resolve_references(mut self, enabled: bool) -> Self {
+        self.resolve_references = enabled;
+        self
+    }
+
+    /// Sets how many references deep a message may go before translating it fails
+    /// with [`Error::ReferenceTooDeep`], see [`Translator::resolve_references`].
+    ///
+    /// Defaults to 16.
+    pub fn with_max_reference_depth(mut self, depth: usize) -> Self {
+        self.max_reference_depth = depth;
+        self
+    }
+
+    /// Whether `message` references any other key, and references are resolved.
+    pub(crate) fn has_references(&self, message: &str) -> bool {
+        self.resolve_references && message.contains(REFERENCE_OPEN)
+    }
+
+    /// Writes the translation of `key`, whose message references other keys, into `writer`.
+    pub(crate) fn translate_with_references<
+        W: fmt::Write,
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        writer: &mut W,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<(), Error> {
+        self.translate_with_references_in(writer, key, language, args, &|key| {
+            self.translations.get(key)
+        })
+    }
+
+    /// Like [`Translator::translate_with_references`], but finds `key` and the keys it
+    /// references through `find`.
+    pub(crate) fn translate_with_references_in<
+        W: fmt::Write,
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        writer: &mut W,
+        key: &str,
+        language: &str,
+        args: I,
+        find: FindText<'_>,
+    ) -> Result<(), Error> {
+        let args: Vec<(SmallStr, SmallStr)> = args
+            .into_iter()
+            .map(|(argument, value)| (argument.into(), value.into()))
+            .collect();
+
+        self.write_referencing(writer, key, language, &args, &mut Vec::new(), find)
+    }
+
+    /// Writes the translation of `key`, resolving its references recursively.
+    ///
+    /// `referencing` holds the keys whose messages led to this one.
+    fn write_referencing(
+        &self,
+        writer: &mut dyn fmt::Write,
+        key: &str,
+        language: &str,
+        args: &[(SmallStr, SmallStr)],
+        referencing: &mut Vec<SmallStr>,
+        find: FindText<'_>,
+    ) -> Result<(), Error> {
+        if referencing.iter().any(|other| other == key) {
+            return Err(Error::CyclicReference(key.into()));
+        }
+        if referencing.len() > self.max_reference_depth {
+            return Err(Error::ReferenceTooDeep(key.into()));
+        }
+
+        let translation = find(key).ok_or_else(|| Error::MissingKey(key.into()))?;
+        let (message, mut format) = self.message_for(translation, key, language)?;
+
+        // Only the outermost message is padded and wrapped, its pieces and the messages it
+        // references are only accented
+        let expanded = format.pseudo == Pseudo::Expanded && referencing.is_empty();
+        if format.pseudo == Pseudo::Expanded {
+            format.pseudo = Pseudo::Accented;
+        }
+
+        // Arguments may belong to any of the referenced messages
+        let arguments = translation.arguments.resolve(&self.argument_table);
+        let args_for = || {
+            args.iter()
+                .filter(|(argument, _)| arguments.names().any(|name| name == argument))
+                .map(|(argument, value)| (argument.as_str(), value.as_str()))
+        };
+
+        let mut write_message = |writer: &mut dyn fmt::Write| {
+            let mut rest = message.as_str();
+            while let Some(start) = rest.find(REFERENCE_OPEN) {
+                let reference = &rest[start + REFERENCE_OPEN.len()..];
+                let Some(end) = reference.find(REFERENCE_CLOSE) else {
+                    break;
+                };
+
+                translation.replace_into(
+                    &self.argument_table,
+                    format,
+                    writer,
+                    &rest[..start],
+                    args_for(),
+                )?;
+
+                referencing.push(key.into());
+                let referenced = &reference[..end];
+                self.write_referencing(writer, referenced, language, args, referencing, find)?;
+                referencing.pop();
+
+                rest = &reference[end + REFERENCE_CLOSE.len_utf8()..];
+            }
+
+            translation.replace_into(&self.argument_table, format, writer, rest, args_for())
+        };
+
+        match expanded {
+            true => pseudo::write_expanded(writer, |writer| write_message(writer)),
+            false => write_message(writer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn references() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]).resolve_references(true);
+
+        tr.add_text(
+            "greeting",
+            ["NAME"],
+            [("en", "Good morning, NAME"), ("pt", "Bom dia, NAME")],
+        )?;
+        tr.add_text(
+            "welcome",
+            ["PLACE"],
+            [
+                ("en", "${greeting}! Welcome to PLACE."),
+                ("pt", "${greeting}! Bem-vindo a PLACE."),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.translate("welcome", "pt", [("NAME", "Julian"), ("PLACE", "Lisboa")])?,
+            "Bom dia, Julian! Bem-vindo a Lisboa."
+        );
+        assert_eq!(
+            tr.translate_cow("welcome", "en", [("", ""); 0])?,
+            "Good morning, NAME! Welcome to PLACE."
+        );
+        let mut output = String::new();
+        tr.translate_into(&mut output, "welcome", "en", [("PLACE", "Rome")])?;
+        assert_eq!(output, "Good morning, NAME! Welcome to Rome.");
+        assert_eq!(
+            tr.translate("welcome", "en", [("NAME", "Julian"), ("AGE", "30")])?,
+            "Good morning, Julian! Welcome to PLACE."
+        );
+
+        tr.add_text(
+            "broken",
+            [""; 0],
+            [("en", "${missing}"), ("pt", "${ unclosed")],
+        )?;
+        assert_eq!(
+            tr.translate("broken", "en", [("", ""); 0]),
+            Err(Error::MissingKey("missing".into()))
+        );
+        assert_eq!(tr.translate("broken", "pt", [("", ""); 0])?, "${ unclosed");
+
+        Ok(())
+    }
+
+    #[test]
+    fn cyclic_references() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).resolve_references(true);
+
+        tr.add_text("ping", [""; 0], [("en", "ping ${pong}")])?;
+        tr.add_text("pong", [""; 0], [("en", "pong ${ping}")])?;
+        tr.add_text("self", [""; 0], [("en", "${self}")])?;
+
+        assert_eq!(
+            tr.translate("ping", "en", [("", ""); 0]),
+            Err(Error::CyclicReference("ping".into()))
+        );
+        assert_eq!(
+            tr.translate("self", "en", [("", ""); 0]),
+            Err(Error::CyclicReference("self".into()))
+        );
+
+        let mut tr = Translator::new(["en"])
+            .resolve_references(true)
+            .with_max_reference_depth(2);
+        tr.add_text("a", [""; 0], [("en", "a ${b}")])?;
+        tr.add_text("b", [""; 0], [("en", "b ${c}")])?;
+        tr.add_text("c", [""; 0], [("en", "c")])?;
+        tr.add_text("d", [""; 0], [("en", "d ${a}")])?;
+
+        assert_eq!(tr.translate("a", "en", [("", ""); 0])?, "a b c");
+        assert_eq!(
+            tr.translate("d", "en", [("", ""); 0]),
+            Err(Error::ReferenceTooDeep("c".into()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn references_disabled() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).with_delimiters("{", "}");
+        tr.add_text("cost", ["AMOUNT"], [("en", "Cost: ${AMOUNT}")])?;
+        tr.add_text("greeting", [""; 0], [("en", "Hello")])?;
+        tr.add_text("welcome", [""; 0], [("en", "${greeting}!")])?;
+
+        assert_eq!(tr.translate("cost", "en", [("AMOUNT", "12")])?, "Cost: $12");
+        assert_eq!(
+            tr.translate("welcome", "en", [("", ""); 0])?,
+            "${greeting}!"
+        );
+
+        let mut output = String::new();
+        tr.translate_into(&mut output, "cost", "en", [("AMOUNT", "12")])?;
+        assert_eq!(output, "Cost: $12");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,230 @@
+//! Translators reloaded from their JSON catalog whenever it changes.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use compact_str::CompactString as SmallStr;
+use notify::{Config, Event, PollWatcher, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{Error, Translator};
+
+/// A [`Translator`] loaded from a file in the format of [`Translator::from_json`],
+/// and loaded again every time that file changes.
+///
+/// The file is watched through `notify`, which stops once this is dropped. Versions of
+/// the file that fail to load are ignored, keeping the last good one, and logged as
+/// warnings with the `tracing` feature.
+pub struct ReloadableTranslator {
+    current: Arc<RwLock<Arc<Translator>>>,
+    _watcher: Box<dyn Watcher + Send + Sync>,
+}
+
+fn load(path: &Path) -> Result<Translator, Error> {
+    let json =
+        fs::read_to_string(path).map_err(|err| Error::Io(format!("{}: {err}", path.display())))?;
+
+    Translator::from_json(&json)
+}
+
+/// Loads the translator in `path` again, keeping `current` if that fails.
+fn reload(current: &RwLock<Arc<Translator>>, path: &Path) {
+    match load(path) {
+        Ok(translator) => *current.write().unwrap() = Arc::new(translator),
+        Err(err) => {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(path = %path.display(), error = %err, "failed to reload translations");
+            #[cfg(not(feature = "tracing"))]
+            let _ = err;
+        }
+    }
+}
+
+impl ReloadableTranslator {
+    /// Loads the translator in `path`, reloading it as the file system reports changes.
+    pub fn watch(path: impl Into<PathBuf>) -> Result<Self, Error> {
+        let path = path.into();
+
+        // Editors often save by replacing the file, so its directory is what's watched
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        Self::watch_with::<RecommendedWatcher>(path, &directory, Config::default())
+    }
+
+    /// Like [`ReloadableTranslator::watch`], but checks the file for changes every
+    /// `interval` instead, for file systems that report none such as network mounts.
+    pub fn watch_every(path: impl Into<PathBuf>, interval: Duration) -> Result<Self, Error> {
+        let path = path.into();
+
+        // Modification times may be too coarse to tell quick successive writes apart
+        let config = Config::default()
+            .with_poll_interval(interval)
+            .with_compare_contents(true);
+
+        Self::watch_with::<PollWatcher>(path.clone(), &path, config)
+    }
+
+    /// Loads the translator in `path`, reloading it whenever `watched`, the file itself or
+    /// its directory, reports a change to it through a `W`.
+    fn watch_with<W: Watcher + Send + Sync + 'static>(
+        path: PathBuf,
+        watched: &Path,
+        config: Config,
+    ) -> Result<Self, Error> {
+        let current = Arc::new(RwLock::new(Arc::new(load(&path)?)));
+
+        let mut watcher = W::new(
+            {
+                let current = Arc::clone(&current);
+                let path = path.clone();
+
+                move |event: notify::Result<Event>| match event {
+                    Ok(event) => {
+                        let changed = (event.kind.is_create() || event.kind.is_modify())
+                            && event
+                                .paths
+                                .iter()
+                                .any(|changed| changed.file_name() == path.file_name());
+
+                        if changed {
+                            reload(&current, &path);
+                        }
+                    }
+                    Err(err) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(path = %path.display(), error = %err, "failed to watch translations");
+                        #[cfg(not(feature = "tracing"))]
+                        let _ = err;
+                    }
+                }
+            },
+            config,
+        )
+        .map_err(|err| Error::Io(format!("{}: {err}", path.display())))?;
+
+        watcher
+            .watch(watched, RecursiveMode::NonRecursive)
+            .map_err(|err| Error::Io(format!("{}: {err}", watched.display())))?;
+
+        Ok(Self {
+            current,
+            _watcher: Box::new(watcher),
+        })
+    }
+
+    /// The latest version of the translator, unaffected by later reloads.
+    pub fn snapshot(&self) -> Arc<Translator> {
+        Arc::clone(&self.current.read().unwrap())
+    }
+
+    /// See [`Translator::translate`], done on the latest version of the translator.
+    pub fn translate<S1: Into<SmallStr>, S2: Into<SmallStr>, I: IntoIterator<Item = (S1, S2)>>(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<String, Error> {
+        self.snapshot().translate(key, language, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::{Duration, Instant};
+
+    use crate::{Error, ReloadableTranslator};
+
+    fn catalog(greeting: &str) -> String {
+        format!(
+            r#"{{
+                "languages": ["en"],
+                "keys": {{
+                    "greetings": {{
+                        "arguments": ["NAME"],
+                        "translations": {{ "en": "{greeting}" }}
+                    }}
+                }}
+            }}"#
+        )
+    }
+
+    /// Checks that a translator made by `watch` from a file follows its changes.
+    fn reloads_on_change(
+        name: &str,
+        watch: impl FnOnce(&Path) -> Result<ReloadableTranslator, Error>,
+    ) -> Result<(), Error> {
+        let directory =
+            std::env::temp_dir().join(format!("binja-reload-{name}-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("catalog.json");
+        std::fs::write(&path, catalog("Hi, NAME!")).unwrap();
+
+        let tr = watch(&path)?;
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Hi, Julian!"
+        );
+
+        let wait_for = |expected: &str| {
+            let deadline = Instant::now() + Duration::from_secs(5);
+            while Instant::now() < deadline {
+                if tr
+                    .translate("greetings", "en", [("NAME", "Julian")])
+                    .as_deref()
+                    == Ok(expected)
+                {
+                    return true;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+            false
+        };
+
+        std::fs::write(&path, catalog("Good morning, NAME!")).unwrap();
+        assert!(wait_for("Good morning, Julian!"));
+
+        // Broken versions are skipped, keeping the last good one
+        let snapshot = tr.snapshot();
+        std::fs::write(&path, "{ not json").unwrap();
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(
+            tr.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+
+        std::fs::write(&path, catalog("Hello there, NAME!")).unwrap();
+        assert!(wait_for("Hello there, Julian!"));
+        assert_eq!(
+            snapshot.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian!"
+        );
+
+        // Replaced rather than written to, as many editors do
+        let replacement = directory.join("catalog.json.tmp");
+        std::fs::write(&replacement, catalog("Welcome back, NAME!")).unwrap();
+        std::fs::rename(&replacement, &path).unwrap();
+        assert!(wait_for("Welcome back, Julian!"));
+
+        drop(tr);
+        let _ = std::fs::remove_dir_all(&directory);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reloads_on_event() -> Result<(), Error> {
+        reloads_on_change("event", |path| ReloadableTranslator::watch(path))
+    }
+
+    #[test]
+    fn reloads_on_poll() -> Result<(), Error> {
+        reloads_on_change("poll", |path| {
+            ReloadableTranslator::watch_every(path, Duration::from_millis(10))
+        })
+    }
+}
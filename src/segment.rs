@@ -0,0 +1,188 @@
+//! Translations split into literal text and replaced arguments.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::pseudo::{self, Pseudo};
+use crate::{Error, Translator};
+
+/// A piece of a translated message, see [`Translator::translate_segments`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Segment<'a> {
+    /// Text taken from the stored message, borrowed unless it was
+    /// [pseudolocalized](Translator::pseudolocalize).
+    Literal(Cow<'a, str>),
+    /// An argument of the message, along with the value it was replaced by.
+    ///
+    /// Arguments that were not given keep their placeholder as value.
+    Argument { name: &'a str, value: SmallStr },
+}
+
+/// The literal `text` of a message, accented unless `pseudo` is [`Pseudo::Off`].
+fn literal(text: &str, pseudo: Pseudo) -> Segment<'_> {
+    match pseudo {
+        Pseudo::Off => Segment::Literal(Cow::Borrowed(text)),
+        _ => {
+            let mut accented = String::with_capacity(text.len() * 2);
+            // Writing into a `String` never fails
+            let _ = pseudo::write_accented(&mut accented, text);
+            Segment::Literal(Cow::Owned(accented))
+        }
+    }
+}
+
+impl Translator {
+    /// Like [`Translator::translate`], but keeps the literal text of the message apart
+    /// from the values its arguments were replaced by, so that e.g. a rich-text
+    /// renderer can style them differently.
+    ///
+    /// References to other keys, as in `${key}`, are not resolved and are kept as literals.
+    ///
+    /// ```
+    /// use binja::{Segment, Translator};
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_text("greetings", ["NAME"], [("en", "Hi, NAME!")])?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate_segments("greetings", "en", [("NAME", "Ana")])?,
+    ///     [
+    ///         Segment::Literal("Hi, ".into()),
+    ///         Segment::Argument { name: "NAME", value: "Ana".into() },
+    ///         Segment::Literal("!".into()),
+    ///     ]
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn translate_segments<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<Vec<Segment<'_>>, Error> {
+        let (translation, message, format) = self.lookup(key, language)?;
+
+        let arguments = translation.arguments.resolve(&self.argument_table);
+        let values = translation.values_to_replace(&self.argument_table, format, args)?;
+
+        let mut segments = Vec::new();
+        let mut last_end = 0;
+        for found in translation.automaton.try_find_iter(message.as_str())? {
+            if found.start() > last_end {
+                segments.push(literal(&message[last_end..found.start()], format.pseudo));
+            }
+
+            let pattern = found.pattern().as_usize();
+            match translation.argument_of(pattern) {
+                Some((index, _)) => segments.push(Segment::Argument {
+                    name: arguments.name(index),
+                    value: values[pattern].clone(),
+                }),
+                // The escaped opening delimiter, which is kept without its escape
+                None => segments.push(literal(
+                    &message[found.start() + 1..found.end()],
+                    format.pseudo,
+                )),
+            }
+            last_end = found.end();
+        }
+        if last_end < message.len() {
+            segments.push(literal(&message[last_end..], format.pseudo));
+        }
+
+        if format.pseudo == Pseudo::Expanded {
+            let chars = segments
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Literal(text) => text.chars().count(),
+                    Segment::Argument { value, .. } => value.chars().count(),
+                })
+                .sum();
+
+            let mut closing: String = pseudo::padding(chars).collect();
+            closing.push_str(pseudo::CLOSING);
+
+            segments.insert(0, Segment::Literal(Cow::Borrowed(pseudo::OPENING)));
+            segments.push(Segment::Literal(Cow::Owned(closing)));
+        }
+
+        Ok(segments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Segment::{self, *};
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_segments() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]);
+        tr.add_text("greetings", ["NAME"], [("en", "Good morning, NAME!")])?;
+        tr.add_text("bye", [""; 0], [("en", "Bye!")])?;
+
+        assert_eq!(
+            tr.translate_segments("greetings", "en", [("NAME", "Julian")])?,
+            [
+                Literal("Good morning, ".into()),
+                Argument {
+                    name: "NAME",
+                    value: "Julian".into()
+                },
+                Literal("!".into()),
+            ]
+        );
+        assert_eq!(
+            tr.translate_segments("bye", "en", [("", ""); 0])?,
+            [Literal("Bye!".into())]
+        );
+
+        let mut tr = Translator::new(["en"]).with_delimiters("{", "}");
+        tr.add_text("sum", ["A", "B"], [("en", "{A}+{B} is not \\{A}")])?;
+
+        let segments: Vec<Segment> = tr.translate_segments("sum", "en", [("A", "1")])?;
+        assert_eq!(
+            segments,
+            [
+                Argument {
+                    name: "A",
+                    value: "1".into()
+                },
+                Literal("+".into()),
+                Argument {
+                    name: "B",
+                    value: "{B}".into()
+                },
+                Literal(" is not ".into()),
+                Literal("{".into()),
+                Literal("A}".into()),
+            ]
+        );
+
+        tr.add_text("twice", ["NAME"], [("en", "{NAME}, oh {NAME}!")])?;
+        assert_eq!(
+            tr.translate_segments("twice", "en", [("NAME", "Ana")])?,
+            [
+                Argument {
+                    name: "NAME",
+                    value: "Ana".into()
+                },
+                Literal(", oh ".into()),
+                Argument {
+                    name: "NAME",
+                    value: "Ana".into()
+                },
+                Literal("!".into()),
+            ]
+        );
+
+        Ok(())
+    }
+}
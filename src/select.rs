@@ -0,0 +1,170 @@
+//! Messages that branch on a string value, such as a gender.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Map, Translator};
+
+/// The variant chosen when no other matches the selector value.
+const OTHER: &str = "other";
+
+/// The message of each variant given for a single language.
+pub(crate) type SelectVariants = Map<SmallStr, SmallStr>;
+
+impl Translator {
+    /// Registers a message that varies with a selector value, giving the message
+    /// of each variant per language.
+    ///
+    /// Select messages are kept apart from the ones added through [`Translator::add_text`],
+    /// and are translated with [`Translator::translate_select`]. Just like `add_text`,
+    /// every language must be given.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_select(
+    ///     "invite",
+    ///     ["NAME"],
+    ///     [(
+    ///         "en",
+    ///         [
+    ///             ("she", "NAME invited you to her party"),
+    ///             ("other", "NAME invited you to their party"),
+    ///         ],
+    ///     )],
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     tr.translate_select("invite", "en", "she", [("NAME", "Ana")])?,
+    ///     "Ana invited you to her party"
+    /// );
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn add_select<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        S3: Into<SmallStr>,
+        S4: Into<SmallStr>,
+        I1: IntoIterator<Item = S1>,
+        I2: IntoIterator<Item = (S1, I3)>,
+        I3: IntoIterator<Item = (S4, S2)>,
+    >(
+        &mut self,
+        key: S3,
+        arguments: I1,
+        translations: I2,
+    ) -> Result<(), Error> {
+        let key = key.into();
+        if self.selects.contains_key(&key) {
+            return Err(Error::DuplicatedKey(key));
+        }
+
+        let translations = translations.into_iter().map(|(language, variants)| {
+            let variants: SelectVariants = variants
+                .into_iter()
+                .map(|(variant, message)| (variant.into(), message.into()))
+                .collect();
+
+            (language, variants)
+        });
+        let mut translation =
+            self.build_translation(arguments, translations, true, |variants, pattern| {
+                variants.values().any(|variant| variant.contains(pattern))
+            })?;
+        translation.arguments = self.store_arguments(translation.arguments);
+
+        self.selects.insert(key, translation);
+
+        Ok(())
+    }
+
+    /// Translates the select message registered under `key`, picking the variant
+    /// named `selector` in `language`.
+    ///
+    /// Falls back to the `other` variant when `selector` has no message, and fails
+    /// with [`Error::MissingVariant`] if there's no `other` variant either.
+    pub fn translate_select<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        key: &str,
+        language: &str,
+        selector: &str,
+        args: I,
+    ) -> Result<String, Error> {
+        let translation = self
+            .selects
+            .get(key)
+            .ok_or_else(|| Error::MissingKey(key.into()))?;
+
+        let (variants, format) = self.message_for(translation, key, language)?;
+
+        let message = variants
+            .get(selector)
+            .or_else(|| variants.get(OTHER))
+            .ok_or_else(|| Error::MissingVariant(key.into(), selector.into()))?;
+
+        translation
+            .replace(&self.argument_table, format, message, args)
+            .map(Cow::into_owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_select() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+
+        tr.add_select(
+            "invite",
+            ["NAME"],
+            [
+                (
+                    "en",
+                    vec![
+                        ("he", "NAME invited you to his party"),
+                        ("she", "NAME invited you to her party"),
+                        ("other", "NAME invited you to their party"),
+                    ],
+                ),
+                (
+                    "pt",
+                    vec![
+                        ("he", "NAME convidou-te para a festa dele"),
+                        ("she", "NAME convidou-te para a festa dela"),
+                    ],
+                ),
+            ],
+        )?;
+
+        let translate =
+            |lang, gender| tr.translate_select("invite", lang, gender, [("NAME", "Sam")]);
+
+        assert_eq!(translate("en", "he")?, "Sam invited you to his party");
+        assert_eq!(translate("en", "she")?, "Sam invited you to her party");
+        assert_eq!(translate("en", "they")?, "Sam invited you to their party");
+        assert_eq!(translate("pt", "she")?, "Sam convidou-te para a festa dela");
+        assert_eq!(
+            translate("pt", "they"),
+            Err(Error::MissingVariant("invite".into(), "they".into()))
+        );
+        assert_eq!(
+            tr.translate_select("greetings", "en", "he", [("NAME", "Sam")]),
+            Err(Error::MissingKey("greetings".into()))
+        );
+        assert_eq!(
+            tr.add_select("invite", ["NAME"], [("en", [("other", "NAME")])]),
+            Err(Error::DuplicatedKey("invite".into()))
+        );
+
+        Ok(())
+    }
+}
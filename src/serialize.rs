@@ -0,0 +1,464 @@
+//! Persisting translators through `serde`, in a superset of the document shape of
+//! `Translator::to_json`.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use aho_corasick::MatchKind;
+use compact_str::CompactString as SmallStr;
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Error, Map, PluralCategory, Translation, Translator};
+
+/// A key, with its arguments as declared and its message in each language.
+#[derive(Serialize, Deserialize)]
+struct Entry<S: Ord, M> {
+    #[serde(default)]
+    arguments: Vec<S>,
+    /// Whether some language has no message, see [`Translator::add_text_partial`].
+    #[serde(default, skip_serializing_if = "is_false")]
+    partial: bool,
+    translations: BTreeMap<S, M>,
+}
+
+/// A message of a ranged key, see [`Translator::add_ranged`].
+#[derive(Serialize, Deserialize)]
+struct Range<S> {
+    from: u64,
+    to: u64,
+    message: S,
+}
+
+/// Every setting of a translator that changes how it adds or translates keys.
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+struct Settings {
+    strict_arguments: bool,
+    require_all_arguments: bool,
+    case_insensitive_languages: bool,
+    interned_arguments: bool,
+    interned_messages: bool,
+    match_kind: SmallStr,
+    max_reference_depth: usize,
+    resolve_references: bool,
+    isolate_rtl: bool,
+    pseudolocalize: bool,
+    namespace_separator: SmallStr,
+    #[cfg(feature = "collation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collation: Option<SmallStr>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            strict_arguments: false,
+            require_all_arguments: false,
+            case_insensitive_languages: false,
+            interned_arguments: false,
+            interned_messages: false,
+            match_kind: "leftmost-longest".into(),
+            max_reference_depth: crate::reference::DEFAULT_MAX_REFERENCE_DEPTH,
+            resolve_references: false,
+            isolate_rtl: false,
+            pseudolocalize: false,
+            namespace_separator: crate::namespace::DEFAULT_NAMESPACE_SEPARATOR.into(),
+            #[cfg(feature = "collation")]
+            collation: None,
+        }
+    }
+}
+
+/// Every key of a translator along with its settings, sorted so that the output is stable
+/// across runs.
+#[derive(Serialize, Deserialize)]
+struct Document<S: Ord> {
+    languages: Vec<S>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    delimiters: Option<(S, S)>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    aliases: BTreeMap<S, S>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    fallbacks: BTreeMap<S, Vec<S>>,
+    #[serde(default)]
+    settings: Settings,
+    keys: BTreeMap<S, Entry<S, S>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    plurals: BTreeMap<S, Entry<S, BTreeMap<PluralCategory, S>>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    selects: BTreeMap<S, Entry<S, BTreeMap<S, S>>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    ranged: BTreeMap<S, Entry<S, Vec<Range<S>>>>,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// The names `match_kind` is written as, for every match kind a translator can use.
+const MATCH_KINDS: [(MatchKind, &str); 3] = [
+    (MatchKind::Standard, "standard"),
+    (MatchKind::LeftmostFirst, "leftmost-first"),
+    (MatchKind::LeftmostLongest, "leftmost-longest"),
+];
+
+impl Translator {
+    /// The entries of `keys`, writing the messages of each language through `message`.
+    fn entries<M, T>(
+        &self,
+        keys: &Map<SmallStr, Translation<M>>,
+        message: impl Fn(&M) -> T,
+    ) -> BTreeMap<SmallStr, Entry<SmallStr, T>> {
+        keys.iter()
+            .map(|(key, translation)| {
+                let translations = translation
+                    .translations
+                    .iter()
+                    .map(|(&language_id, messages)| {
+                        (self.languages[language_id].clone(), message(messages))
+                    })
+                    .collect();
+                let entry = Entry {
+                    arguments: translation.declared_arguments(&self.argument_table),
+                    partial: translation.translations.len() < self.languages.len(),
+                    translations,
+                };
+
+                (key.clone(), entry)
+            })
+            .collect()
+    }
+}
+
+/// Written as `{ "languages": [...], "keys": { key: { "arguments": [...], "translations":
+/// { language: message } } } }`, just like [`Translator::to_json`], along with the
+/// `plurals`, `selects` and `ranged` sections when not empty. Delimiters, aliases and
+/// fallback chains are written when set, and every other setting under `settings`.
+///
+/// Fails if formatters were registered through [`Translator::with_formatter`], since
+/// they're code rather than data, or if single-language catalogs are still waiting for
+/// the other languages to be loaded.
+impl Serialize for Translator {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !self.formatters.is_empty() {
+            return Err(S::Error::custom("formatters can't be serialized"));
+        }
+        #[cfg(any(
+            feature = "gettext",
+            feature = "android",
+            feature = "apple",
+            feature = "arb"
+        ))]
+        if self.has_pending_catalogs() {
+            return Err(S::Error::custom(
+                "catalogs still waiting for other languages can't be serialized",
+            ));
+        }
+
+        let language = |language_id: usize| self.languages[language_id].clone();
+        let (_, match_kind) = MATCH_KINDS
+            .into_iter()
+            .find(|&(match_kind, _)| match_kind == self.match_kind)
+            .ok_or_else(|| S::Error::custom("unsupported match kind"))?;
+
+        let settings = Settings {
+            strict_arguments: self.strict_arguments,
+            require_all_arguments: self.require_all_arguments,
+            case_insensitive_languages: self.case_insensitive_languages,
+            interned_arguments: self.intern_arguments,
+            interned_messages: self.intern_messages,
+            match_kind: match_kind.into(),
+            max_reference_depth: self.max_reference_depth,
+            resolve_references: self.resolve_references,
+            isolate_rtl: self.isolate_rtl,
+            pseudolocalize: self.pseudolocalize,
+            namespace_separator: self.namespace_separator.clone(),
+            #[cfg(feature = "collation")]
+            collation: self
+                .collation
+                .as_ref()
+                .map(|collation| collation.language().into()),
+        };
+
+        Document {
+            languages: self.languages.to_vec(),
+            delimiters: self.delimiters.clone(),
+            aliases: self
+                .aliases
+                .iter()
+                .map(|(alias, &language_id)| (alias.clone(), language(language_id)))
+                .collect(),
+            fallbacks: self
+                .fallbacks
+                .iter()
+                .map(|(&language_id, chain)| {
+                    (
+                        language(language_id),
+                        chain.iter().map(|&id| language(id)).collect(),
+                    )
+                })
+                .collect(),
+            settings,
+            keys: self.entries(&self.translations, |message| SmallStr::from(&**message)),
+            plurals: self.entries(&self.plurals, |forms| {
+                forms
+                    .iter()
+                    .map(|(&category, message)| (category, message.clone()))
+                    .collect()
+            }),
+            selects: self.entries(&self.selects, |variants| {
+                variants
+                    .iter()
+                    .map(|(variant, message)| (variant.clone(), message.clone()))
+                    .collect()
+            }),
+            ranged: self.entries(&self.ranged, |ranges| {
+                ranges
+                    .iter()
+                    .map(|(range, message)| Range {
+                        from: *range.start(),
+                        to: *range.end(),
+                        message: message.clone(),
+                    })
+                    .collect()
+            }),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Reads the shape written by `Serialize`, adding every key through [`Translator::add_text`]
+/// and friends, or [`Translator::add_text_partial`] for keys marked `"partial": true`,
+/// so that the usual validation applies: a key missing a language, or given one that isn't
+/// listed, fails with the message of the [`Error`] it raised.
+///
+/// Documents written by [`Translator::to_json`] can be read as well, with default settings.
+impl<'de> Deserialize<'de> for Translator {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let document = Document::<String>::deserialize(deserializer)?;
+        let settings = document.settings;
+
+        let (match_kind, _) = MATCH_KINDS
+            .into_iter()
+            .find(|&(_, name)| name == settings.match_kind)
+            .ok_or_else(|| {
+                D::Error::custom(format_args!("unknown match kind `{}`", settings.match_kind))
+            })?;
+
+        let mut translator = Translator::new(document.languages)
+            .require_all_arguments(settings.require_all_arguments)
+            .case_insensitive_languages(settings.case_insensitive_languages)
+            .with_match_kind(match_kind)
+            .with_max_reference_depth(settings.max_reference_depth)
+            .resolve_references(settings.resolve_references)
+            .isolate_rtl(settings.isolate_rtl)
+            .pseudolocalize(settings.pseudolocalize)
+            .with_namespace_separator(&settings.namespace_separator);
+        if let Some((open, close)) = &document.delimiters {
+            translator = translator.with_delimiters(open, close);
+        }
+        if settings.interned_arguments {
+            translator = translator.with_interned_arguments();
+        }
+        if settings.interned_messages {
+            translator = translator.with_message_interning();
+        }
+        #[cfg(feature = "collation")]
+        if let Some(language) = &settings.collation {
+            translator = translator.with_collation(language);
+        }
+
+        let add = |result: Result<(), Error>| result.map_err(D::Error::custom);
+
+        for (key, entry) in document.keys {
+            add(if entry.partial {
+                translator.add_text_partial(key, entry.arguments, entry.translations)
+            } else {
+                translator.add_text(key, entry.arguments, entry.translations)
+            })?;
+        }
+        for (key, entry) in document.plurals {
+            add(translator.add_plural(key, entry.arguments, entry.translations))?;
+        }
+        for (key, entry) in document.selects {
+            add(translator.add_select(key, entry.arguments, entry.translations))?;
+        }
+        for (key, entry) in document.ranged {
+            let translations = entry.translations.into_iter().map(|(language, ranges)| {
+                let ranges = ranges
+                    .into_iter()
+                    .map(|range| (range.from..=range.to, range.message));
+
+                (language, ranges)
+            });
+            add(translator.add_ranged(key, entry.arguments, translations))?;
+        }
+
+        for (alias, language) in &document.aliases {
+            add(translator.add_alias(alias, language))?;
+        }
+        for (language, chain) in document.fallbacks {
+            add(translator.set_fallback_chain(&language, chain))?;
+        }
+
+        // Only checked when adding keys, which were already checked when first added
+        Ok(translator.with_strict_arguments(settings.strict_arguments))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, PluralCategory, Translator};
+
+    #[test]
+    fn serde_round_trip() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]).with_delimiters("{", "}");
+        tr.add_text(
+            "greetings",
+            ["NAME", "COUNT:number", "TITLE?"],
+            [
+                ("en", "Hi, {TITLE}{NAME}! {COUNT:number} new"),
+                ("pt", "Olá, {TITLE}{NAME}! {COUNT:number} novas"),
+            ],
+        )?;
+        tr.add_text("bye", [""; 0], [("en", "Bye!"), ("pt", "Tchau!")])?;
+
+        let json = serde_json::to_string(&tr).unwrap();
+        let loaded: Translator = serde_json::from_str(&json).unwrap();
+
+        for language in ["en", "pt"] {
+            let args = [("NAME", "Julian"), ("COUNT", "1200")];
+            assert_eq!(
+                loaded.translate("greetings", language, args)?,
+                tr.translate("greetings", language, args)?
+            );
+            assert_eq!(
+                loaded.translate("bye", language, [("", ""); 0])?,
+                tr.translate("bye", language, [("", ""); 0])?
+            );
+        }
+        assert!(loaded == tr);
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_round_trip_everything() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"])
+            .require_all_arguments(true)
+            .resolve_references(true)
+            .with_namespace_separator("/");
+        tr.add_text_partial("colour", ["NAME"], [("en", "NAME's colour")])?;
+        tr.add_text(
+            "welcome",
+            ["NAME"],
+            [("en", "Hi! ${colour}"), ("pt", "Olá!")],
+        )?;
+        tr.add_plural(
+            "items",
+            ["COUNT"],
+            [
+                (
+                    "en",
+                    [
+                        (PluralCategory::One, "COUNT item"),
+                        (PluralCategory::Other, "COUNT items"),
+                    ],
+                ),
+                (
+                    "pt",
+                    [
+                        (PluralCategory::One, "COUNT item"),
+                        (PluralCategory::Other, "COUNT itens"),
+                    ],
+                ),
+            ],
+        )?;
+        tr.add_select(
+            "invite",
+            ["NAME"],
+            [
+                ("en", [("she", "NAME invited you"), ("other", "NAME asked")]),
+                ("pt", [("she", "NAME convidou-te"), ("other", "NAME pediu")]),
+            ],
+        )?;
+        tr.add_ranged(
+            "stock",
+            [""; 0],
+            [
+                ("en", [(0..=0, "Sold out"), (1..=u64::MAX, "In stock")]),
+                ("pt", [(0..=0, "Esgotado"), (1..=u64::MAX, "Em stock")]),
+            ],
+        )?;
+        tr.add_alias("pt-BR", "pt")?;
+        tr.set_fallback_chain("pt", ["en"])?;
+
+        let json = serde_json::to_string(&tr).unwrap();
+        let loaded: Translator = serde_json::from_str(&json).unwrap();
+
+        assert!(loaded == tr);
+        assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+        assert_eq!(
+            loaded.translate("colour", "pt-BR", [("NAME", "Ana")])?,
+            "Ana's colour"
+        );
+        assert_eq!(
+            loaded.translate("welcome", "en", [("NAME", "Ana")])?,
+            "Hi! Ana's colour"
+        );
+        assert_eq!(
+            loaded.translate("welcome", "en", [("", ""); 0]),
+            Err(Error::MissingArgument("NAME".into()))
+        );
+        assert_eq!(
+            loaded.translate_plural("items", "pt", 2, [("COUNT", "2")])?,
+            "2 itens"
+        );
+        assert_eq!(
+            loaded.translate_select("invite", "en", "she", [("NAME", "Ana")])?,
+            "Ana invited you"
+        );
+        assert_eq!(
+            loaded.translate_ranged("stock", "pt", 0, [("", ""); 0])?,
+            "Esgotado"
+        );
+
+        // Documents written by `to_json` are read just as well
+        #[cfg(feature = "json")]
+        assert!(serde_json::from_str::<Translator>(&tr.to_json()).unwrap() == tr);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_rejects_formatters() {
+        let upper = |value: &dyn core::any::Any, _: &str| match value.downcast_ref::<&str>() {
+            Some(value) => value.to_uppercase(),
+            None => String::new(),
+        };
+        let tr = Translator::new(["en"]).with_formatter("upper", upper);
+        let error = serde_json::to_string(&tr).err().unwrap();
+        assert!(error.to_string().contains("formatters"), "{error}");
+    }
+
+    #[test]
+    fn serde_revalidates() {
+        let missing = r#"{
+            "languages": ["en", "pt"],
+            "keys": { "bye": { "arguments": [], "translations": { "en": "Bye!" } } }
+        }"#;
+        let error = serde_json::from_str::<Translator>(missing).err().unwrap();
+        assert!(error.to_string().contains("`pt`"), "{error}");
+
+        let unknown = r#"{
+            "languages": ["en"],
+            "keys": { "bye": { "arguments": [], "translations": { "en": "Bye!", "cz": "Ahoj!" } } }
+        }"#;
+        let error = serde_json::from_str::<Translator>(unknown).err().unwrap();
+        assert!(error.to_string().contains("`cz`"), "{error}");
+    }
+}
@@ -0,0 +1,79 @@
+//! A cheaply clonable handle to a [`Translator`].
+
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+use crate::Translator;
+
+/// A [`Translator`] behind an [`Arc`], to be shared across threads.
+///
+/// [`Translator`] holds no interior mutability (every automaton is built when
+/// its text is added), so it is `Send + Sync` and clones of this handle can
+/// translate concurrently. Every method of [`Translator`] taking `&self` is
+/// available through [`Deref`].
+#[derive(Clone)]
+pub struct SharedTranslator(Arc<Translator>);
+
+impl SharedTranslator {
+    /// Wraps `translator` so that it can be shared.
+    pub fn new(translator: Translator) -> Self {
+        Self(Arc::new(translator))
+    }
+}
+
+impl From<Translator> for SharedTranslator {
+    fn from(translator: Translator) -> Self {
+        Self::new(translator)
+    }
+}
+
+impl Deref for SharedTranslator {
+    type Target = Translator;
+
+    fn deref(&self) -> &Translator {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::{Error, SharedTranslator, Translator};
+
+    #[test]
+    fn shared_translator() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME!"), ("pt", "Bom dia, NAME!")],
+        )?;
+        let shared = SharedTranslator::from(tr);
+
+        let handles: Vec<_> = (0..4)
+            .map(|index| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    let name = format!("Julian {index}");
+                    (
+                        shared.translate("greetings", "en", [("NAME", name.as_str())]),
+                        shared.translate("greetings", "pt", [("NAME", name.as_str())]),
+                    )
+                })
+            })
+            .collect();
+
+        for (index, handle) in handles.into_iter().enumerate() {
+            let (en, pt) = handle.join().unwrap();
+            assert_eq!(en?, format!("Good morning, Julian {index}!"));
+            assert_eq!(pt?, format!("Bom dia, Julian {index}!"));
+        }
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Translator>();
+        assert_send_sync::<SharedTranslator>();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,83 @@
+//! Owned copies of the messages of a language, e.g. for golden-file tests.
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+
+use crate::{Error, Translator};
+
+impl Translator {
+    /// Every key mapped to its message in `language`, with its placeholders untouched,
+    /// sorted by key.
+    ///
+    /// Messages are found as [`Translator::translate`] does, walking the fallback chain of
+    /// `language`, and keys with no message for it are left out.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en", "pt"]);
+    /// tr.add_text("hello", ["NAME"], [("en", "Hello, NAME!"), ("pt", "Olá, NAME!")])?;
+    /// tr.add_text("bye", ["NAME"], [("en", "Bye, NAME!"), ("pt", "Tchau, NAME!")])?;
+    ///
+    /// let snapshot = tr.snapshot("pt")?;
+    /// assert_eq!(snapshot["hello"], "Olá, NAME!");
+    /// assert_eq!(snapshot.keys().collect::<Vec<_>>(), ["bye", "hello"]);
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn snapshot(&self, language: &str) -> Result<BTreeMap<String, String>, Error> {
+        let language_id = self.find_language(language)?;
+
+        Ok(self
+            .translations
+            .iter()
+            .filter_map(|(key, translation)| {
+                let message = self.resolve_message(translation, language_id)?;
+                Some((key.to_string(), message.to_string()))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+
+    use crate::{Error, Translator};
+
+    #[test]
+    fn snapshot() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "pt-BR"]);
+        tr.set_fallback_chain("pt-BR", ["pt"])?;
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Hi, NAME!"),
+                ("pt", "Olá, NAME!"),
+                ("pt-BR", "Oi, NAME!"),
+            ],
+        )?;
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!"), ("pt", "Adeus!")])?;
+        tr.add_text_partial("thanks", [""; 0], [("en", "Thanks!")])?;
+
+        let expected: BTreeMap<_, _> = [
+            ("bye", "Bye!"),
+            ("greetings", "Hi, NAME!"),
+            ("thanks", "Thanks!"),
+        ]
+        .into_iter()
+        .map(|(key, message)| (key.into(), message.into()))
+        .collect();
+        assert_eq!(tr.snapshot("en")?, expected);
+
+        let expected: BTreeMap<_, _> = [("bye", "Adeus!"), ("greetings", "Oi, NAME!")]
+            .into_iter()
+            .map(|(key, message)| (key.into(), message.into()))
+            .collect();
+        assert_eq!(tr.snapshot("pt-BR")?, expected);
+
+        assert_eq!(tr.snapshot("cz"), Err(Error::UnknownLanguage("cz".into())));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+//! Writing translations straight into byte sinks such as files or sockets.
+
+use std::io;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::pseudo::Pseudo;
+use crate::{Error, Translator};
+
+impl Translator {
+    /// Like [`Translator::translate_into`], but writes the translation to an
+    /// [`io::Write`] sink, piece by piece, instead of building it in memory first.
+    ///
+    /// Failures of `writer` are reported as [`Error::Io`]. Messages referencing other
+    /// keys, or translated into the [pseudolocale](Translator::pseudolocalize), are still
+    /// built in memory before being written.
+    ///
+    /// `AhoCorasick::stream_replace_all` isn't used, since it only supports the standard
+    /// match semantics, while arguments are matched preferring the longest one.
+    pub fn translate_stream<
+        W: io::Write,
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        writer: &mut W,
+        key: &str,
+        language: &str,
+        args: I,
+    ) -> Result<(), Error> {
+        let io_error = |err: io::Error| Error::Io(err.to_string());
+        let (translation, message, format) = self.lookup(key, language)?;
+
+        if self.has_references(message) || format.pseudo != Pseudo::Off {
+            let mut output = String::new();
+            self.translate_into(&mut output, key, language, args)?;
+            return writer.write_all(output.as_bytes()).map_err(io_error);
+        }
+
+        let values_to_replace =
+            translation.values_to_replace(&self.argument_table, format, args)?;
+
+        let mut last_end = 0;
+        for found in translation.automaton.try_find_iter(message.as_str())? {
+            writer
+                .write_all(message[last_end..found.start()].as_bytes())
+                .map_err(io_error)?;
+            writer
+                .write_all(values_to_replace[found.pattern().as_usize()].as_bytes())
+                .map_err(io_error)?;
+            last_end = found.end();
+        }
+        writer
+            .write_all(message[last_end..].as_bytes())
+            .map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::{Error, Translator};
+
+    #[test]
+    fn translate_stream() -> Result<(), Error> {
+        let mut tr = Translator::new(["pt", "en"]);
+        tr.add_text(
+            "welcome",
+            ["NAME", "PLACE"],
+            [
+                ("en", "Welcome to PLACE, NAME!"),
+                ("pt", "Bem-vindo a PLACE, NAME!"),
+            ],
+        )?;
+
+        for args in [
+            &[("NAME", "Julian"), ("PLACE", "Lisbon")][..],
+            &[("NAME", "Julian")],
+        ] {
+            let mut output = Vec::new();
+            tr.translate_stream(&mut output, "welcome", "pt", args.iter().copied())?;
+
+            assert_eq!(
+                output,
+                tr.translate("welcome", "pt", args.iter().copied())?
+                    .as_bytes()
+            );
+        }
+
+        assert_eq!(
+            tr.translate_stream(&mut io::sink(), "welcome", "cz", [("NAME", "Julian")]),
+            Err(Error::UnknownLanguage("cz".into()))
+        );
+
+        let mut full = [0; 8];
+        assert!(matches!(
+            tr.translate_stream(&mut &mut full[..], "welcome", "en", [("NAME", "Julian")]),
+            Err(Error::Io(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+//! Copying part of a translator, e.g. to ship only the keys a page needs.
+
+use compact_str::CompactString as SmallStr;
+
+use crate::intern::{ArgumentTable, Arguments};
+use crate::{Map, Translation, Translator};
+
+impl<M: Clone> Translation<M> {
+    /// A copy of this translation, holding `arguments` instead of its own.
+    fn copy_with(&self, arguments: Arguments) -> Self {
+        Translation {
+            arguments,
+            automaton: self.automaton.clone(),
+            patterns: self.patterns.clone(),
+            unescaped_delimiter: self.unescaped_delimiter.clone(),
+            cased_placeholders: self.cased_placeholders.clone(),
+            number_arguments: self.number_arguments.clone(),
+            list_arguments: self.list_arguments.clone(),
+            formatted_arguments: self.formatted_arguments.clone(),
+            optional_arguments: self.optional_arguments.clone(),
+            declared_order: self.declared_order.clone(),
+            arguments_by_name: self.arguments_by_name.clone(),
+            translations: self.translations.clone(),
+        }
+    }
+}
+
+impl Translator {
+    /// A new translator with the same languages and settings as this one, but only the
+    /// keys for which `predicate` returns `true`, e.g. to ship a catalog per page.
+    ///
+    /// Fallback chains, aliases and formatters are kept, while messages still pending
+    /// from loading files, as through [`Translator::load_po`], are not.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_text("checkout.pay", [""; 0], [("en", "Pay")])?;
+    /// tr.add_text("profile.title", [""; 0], [("en", "Profile")])?;
+    ///
+    /// let checkout = tr.subset(|key| key.starts_with("checkout."));
+    /// assert_eq!(checkout.keys().collect::<Vec<_>>(), ["checkout.pay"]);
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn subset<F: Fn(&str) -> bool>(&self, predicate: F) -> Translator {
+        let mut subset = Translator::new(self.languages.iter().cloned());
+
+        subset.fallbacks = self.fallbacks.clone();
+        subset.language_generation = self.language_generation;
+        subset.aliases = self.aliases.clone();
+        subset.strict_arguments = self.strict_arguments;
+        subset.require_all_arguments = self.require_all_arguments;
+        subset.case_insensitive_languages = self.case_insensitive_languages;
+        subset.intern_arguments = self.intern_arguments;
+        subset.intern_messages = self.intern_messages;
+        subset.delimiters = self.delimiters.clone();
+        subset.match_kind = self.match_kind;
+        subset.max_reference_depth = self.max_reference_depth;
+        subset.resolve_references = self.resolve_references;
+        subset.grouping_separators = self.grouping_separators.clone();
+        subset.list_patterns = self.list_patterns.clone();
+        subset.isolate_rtl = self.isolate_rtl;
+        subset.pseudolocalize = self.pseudolocalize;
+        subset.formatters = self.formatters.clone();
+        subset.namespace_separator = self.namespace_separator.clone();
+        #[cfg(feature = "collation")]
+        {
+            subset.collation = self.collation.clone();
+        }
+
+        let table = &self.argument_table;
+        let mut translations = subset.copy_keys(&self.translations, table, &predicate);
+        for translation in translations.values_mut() {
+            // Interned messages are shared through the table of the subset instead
+            subset.store_messages(&mut translation.translations);
+        }
+        subset.translations = translations;
+        subset.plurals = subset.copy_keys(&self.plurals, table, &predicate);
+        subset.selects = subset.copy_keys(&self.selects, table, &predicate);
+        subset.ranged = subset.copy_keys(&self.ranged, table, &predicate);
+
+        subset
+    }
+
+    fn copy_keys<M: Clone>(
+        &mut self,
+        source: &Map<SmallStr, Translation<M>>,
+        source_table: &ArgumentTable,
+        predicate: impl Fn(&str) -> bool,
+    ) -> Map<SmallStr, Translation<M>> {
+        source
+            .iter()
+            .filter(|(key, _)| predicate(key))
+            .map(|(key, translation)| {
+                // Interned arguments refer to the table of the translator being copied
+                let arguments = translation.arguments.resolve(source_table).to_owned();
+                let arguments = self.store_arguments(arguments);
+
+                (key.clone(), translation.copy_with(arguments))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::PluralCategory::{One, Other};
+    use crate::{Error, Translator};
+
+    #[test]
+    fn subset() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"])
+            .with_delimiters("{", "}")
+            .with_interned_arguments()
+            .with_message_interning();
+        tr.add_text(
+            "checkout.pay",
+            ["AMOUNT:number"],
+            [
+                ("en", "Pay {AMOUNT:number}"),
+                ("pt", "Pagar {AMOUNT:number}"),
+            ],
+        )?;
+        tr.add_text_partial("checkout.cancel", [""; 0], [("en", "Cancel")])?;
+        tr.add_text(
+            "profile.title",
+            ["NAME"],
+            [("en", "{NAME}'s profile"), ("pt", "Perfil de {NAME}")],
+        )?;
+        tr.add_plural(
+            "checkout.items",
+            ["COUNT"],
+            [
+                ("en", [(One, "{COUNT} item"), (Other, "{COUNT} items")]),
+                ("pt", [(One, "{COUNT} item"), (Other, "{COUNT} itens")]),
+            ],
+        )?;
+        tr.set_fallback_chain("pt", ["en"])?;
+
+        let checkout = tr.subset(|key| key.starts_with("checkout."));
+
+        assert_eq!(checkout.len(), 2);
+        assert!(!checkout.contains_key("profile.title"));
+        assert_eq!(
+            checkout.translate("profile.title", "en", [("NAME", "Julian")]),
+            Err(Error::MissingKey("profile.title".into()))
+        );
+        assert_eq!(
+            checkout.translate("checkout.pay", "pt", [("AMOUNT", "1200")])?,
+            "Pagar 1.200"
+        );
+        assert_eq!(
+            checkout.translate("checkout.cancel", "pt", [("", ""); 0])?,
+            "Cancel"
+        );
+        assert_eq!(
+            checkout.translate_plural("checkout.items", "en", 3, [("COUNT", "3")])?,
+            "3 items"
+        );
+
+        // The original translator is left untouched
+        assert_eq!(tr.len(), 3);
+        assert_eq!(
+            tr.translate("profile.title", "pt", [("NAME", "Julian")])?,
+            "Perfil de Julian"
+        );
+
+        Ok(())
+    }
+}
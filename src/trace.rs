@@ -0,0 +1,226 @@
+//! `tracing` events emitted while translating, see the `tracing` feature.
+
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Translation, Translator};
+
+impl Translator {
+    /// Warns about every argument of `args` that is declared by `key` but does not occur
+    /// in `message`, its message for `language`, handing `args` back to be replaced.
+    pub(crate) fn trace_unused_arguments<
+        S1: Into<SmallStr>,
+        S2: Into<SmallStr>,
+        I: IntoIterator<Item = (S1, S2)>,
+    >(
+        &self,
+        translation: &Translation,
+        key: &str,
+        language: &str,
+        message: &str,
+        args: I,
+    ) -> Vec<(SmallStr, SmallStr)> {
+        let args: Vec<(SmallStr, SmallStr)> = args
+            .into_iter()
+            .map(|(argument, value)| (argument.into(), value.into()))
+            .collect();
+
+        let arguments = translation.arguments.resolve(&self.argument_table);
+        for (argument, _) in &args {
+            // Unknown arguments are reported as errors when replacing
+            let Some(&index) = translation.arguments_by_name.get(argument) else {
+                continue;
+            };
+
+            if !translation.occurs_in(message, arguments.placeholder(index), index) {
+                tracing::warn!(
+                    key,
+                    lang = language,
+                    argument = argument.as_str(),
+                    "argument given but unused"
+                );
+            }
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Level, Metadata, Subscriber};
+
+    use crate::{Error, Translator};
+
+    /// An event as recorded by [`Recorder`], with its fields formatted.
+    #[derive(Debug, PartialEq)]
+    struct Recorded {
+        level: Level,
+        fields: Vec<(&'static str, String)>,
+    }
+
+    /// Keeps every event emitted while it is the default subscriber.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Recorded>>>);
+
+    impl Visit for Recorded {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields.push((field.name(), value.into()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.fields.push((field.name(), format!("{value:?}")));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut recorded = Recorded {
+                level: *event.metadata().level(),
+                fields: Vec::new(),
+            };
+            event.record(&mut recorded);
+
+            self.0.lock().unwrap().push(recorded);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn fields(fields: &[(&'static str, &str)]) -> Vec<(&'static str, String)> {
+        fields
+            .iter()
+            .map(|&(name, value)| (name, value.into()))
+            .collect()
+    }
+
+    #[test]
+    fn traced_events() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_text(
+            "greetings",
+            ["NAME", "TITLE?"],
+            [("en", "Hello, TITLENAME!"), ("pt", "Olá, NAME!")],
+        )?;
+        tr.add_text_partial("bye", ["NAME"], [("en", "Bye, NAME!")])?;
+        tr.set_fallback_chain("pt", ["en"])?;
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            tr.translate("greetings", "pt", [("NAME", "Ana"), ("TITLE", "Dr. ")])?;
+            tr.translate("bye", "pt", [("NAME", "Ana")])?;
+            tr.translate("missing", "en", [("", ""); 0]).unwrap_err();
+
+            Ok::<_, Error>(())
+        })?;
+
+        let events = recorder.0.lock().unwrap();
+        assert_eq!(
+            *events,
+            [
+                Recorded {
+                    level: Level::DEBUG,
+                    fields: fields(&[
+                        ("message", "key found"),
+                        ("key", "greetings"),
+                        ("lang", "pt")
+                    ]),
+                },
+                Recorded {
+                    level: Level::WARN,
+                    fields: fields(&[
+                        ("message", "argument given but unused"),
+                        ("key", "greetings"),
+                        ("lang", "pt"),
+                        ("argument", "TITLE")
+                    ]),
+                },
+                Recorded {
+                    level: Level::DEBUG,
+                    fields: fields(&[("message", "key found"), ("key", "bye"), ("lang", "pt")]),
+                },
+                Recorded {
+                    level: Level::WARN,
+                    fields: fields(&[
+                        ("message", "translated through a fallback language"),
+                        ("key", "bye"),
+                        ("lang", "pt"),
+                        ("fallback", "en")
+                    ]),
+                },
+                Recorded {
+                    level: Level::DEBUG,
+                    fields: fields(&[
+                        ("message", "key not found"),
+                        ("key", "missing"),
+                        ("lang", "en")
+                    ]),
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn traced_defaults() -> Result<(), Error> {
+        let mut tr = Translator::new(["en"]).require_all_arguments(true);
+        tr.add_text("greetings", ["NAME"], [("en", "Hello, NAME!")])?;
+
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            assert_eq!(
+                tr.translate_or_key("missing", "en", [("", ""); 0]),
+                "missing"
+            );
+            assert_eq!(
+                tr.translate_or_key("greetings", "cz", [("", ""); 0]),
+                "greetings"
+            );
+            assert_eq!(
+                tr.translate_or("greetings", "en", "Hi!", [("", ""); 0]),
+                "Hi!"
+            );
+        });
+
+        let events = recorder.0.lock().unwrap();
+        let warnings: Vec<_> = events
+            .iter()
+            .filter(|event| event.level == Level::WARN)
+            .collect();
+        assert_eq!(
+            warnings,
+            [&Recorded {
+                level: Level::WARN,
+                fields: fields(&[
+                    ("message", "translated to the default"),
+                    ("key", "greetings"),
+                    ("lang", "en"),
+                    ("error", "Argument `NAME` was not given")
+                ]),
+            }]
+        );
+
+        Ok(())
+    }
+}
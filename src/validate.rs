@@ -0,0 +1,147 @@
+//! Checking that a translator is internally consistent before it's relied upon.
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Map, Translation, Translator};
+
+impl Translator {
+    /// Checks that every key has a message for each language, and declares no empty
+    /// or repeated argument, collecting every problem found rather than the first.
+    ///
+    /// Problems are reported with [`Error::UntranslatedForLanguage`], [`Error::EmptyArgument`]
+    /// and [`Error::RepeatedArgument`], sorted by key, for text keys first, then plural,
+    /// select and ranged ones.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en", "pt"]);
+    /// tr.add_text("hello", ["NAME"], [("en", "Hello, NAME!"), ("pt", "Olá, NAME!")])?;
+    /// assert!(tr.validate().is_ok());
+    ///
+    /// tr.add_text_partial("bye", [""; 0], [("en", "Bye!")])?;
+    /// assert_eq!(tr.validate().unwrap_err().len(), 1);
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<Error>> {
+        let mut errors = Vec::new();
+
+        self.validate_keys(&self.translations, &mut errors);
+        self.validate_keys(&self.plurals, &mut errors);
+        self.validate_keys(&self.selects, &mut errors);
+        self.validate_keys(&self.ranged, &mut errors);
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+
+    fn validate_keys<M>(&self, keys: &Map<SmallStr, Translation<M>>, errors: &mut Vec<Error>) {
+        let mut keys: Vec<_> = keys.iter().collect();
+        keys.sort_unstable_by_key(|(key, _)| *key);
+
+        for (key, translation) in keys {
+            errors.extend(
+                self.languages
+                    .iter()
+                    .enumerate()
+                    .filter(|(language_id, _)| !translation.translations.contains_key(language_id))
+                    .map(|(_, language)| {
+                        Error::UntranslatedForLanguage(key.clone(), language.clone())
+                    }),
+            );
+
+            let arguments = translation.arguments.resolve(&self.argument_table);
+            let mut seen = BTreeSet::new();
+            let mut repeated = BTreeSet::new();
+            let mut has_empty = false;
+
+            for &index in translation.declared_order.iter() {
+                let name = arguments.name(index);
+                has_empty |= name.is_empty();
+                if !seen.insert(name) {
+                    repeated.insert(name);
+                }
+            }
+
+            if has_empty {
+                errors.push(Error::EmptyArgument(key.clone()));
+            }
+            errors.extend(
+                repeated
+                    .into_iter()
+                    .map(|argument| Error::RepeatedArgument {
+                        key: key.clone(),
+                        argument: argument.clone(),
+                    }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn validate() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt", "it"]);
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [
+                ("en", "Hi, NAME!"),
+                ("pt", "Olá, NAME!"),
+                ("it", "Ciao, NAME!"),
+            ],
+        )?;
+        assert_eq!(tr.validate(), Ok(()));
+
+        tr.add_text_partial("bye", [""; 0], [("en", "Bye!")])?;
+        tr.add_text_partial(
+            "welcome",
+            ["NAME", "", "PLACE", "NAME", "PLACE:number"],
+            [
+                ("en", "Welcome to PLACE, NAME!"),
+                ("it", "Benvenuto a PLACE, NAME!"),
+            ],
+        )?;
+        tr.add_select(
+            "invite",
+            ["NAME", "NAME?"],
+            [
+                ("en", [("other", "NAME invited you")]),
+                ("pt", [("other", "NAME convidou-te")]),
+                ("it", [("other", "NAME ti ha invitato")]),
+            ],
+        )?;
+
+        assert_eq!(
+            tr.validate(),
+            Err(vec![
+                Error::UntranslatedForLanguage("bye".into(), "it".into()),
+                Error::UntranslatedForLanguage("bye".into(), "pt".into()),
+                Error::UntranslatedForLanguage("welcome".into(), "pt".into()),
+                Error::EmptyArgument("welcome".into()),
+                Error::RepeatedArgument {
+                    key: "welcome".into(),
+                    argument: "NAME".into()
+                },
+                Error::RepeatedArgument {
+                    key: "welcome".into(),
+                    argument: "PLACE".into()
+                },
+                Error::RepeatedArgument {
+                    key: "invite".into(),
+                    argument: "NAME".into()
+                },
+            ])
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+//! Building the arguments of a single message one call at a time.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::{Error, Translation, Translator};
+
+/// The translation of a key into a language, whose arguments are given one by one,
+/// made through [`Translator::writer`].
+///
+/// Argument names are checked as they're given, so that templates interleaving
+/// translation with other rendering fail at the offending argument.
+pub struct MessageWriter<'a> {
+    translator: &'a Translator,
+    translation: &'a Translation,
+    key: &'a str,
+    language: &'a str,
+    args: Vec<(SmallStr, SmallStr)>,
+}
+
+impl Translator {
+    /// Starts translating `key` into `language`, with arguments given through
+    /// [`MessageWriter::arg`].
+    ///
+    /// Fails right away if `key` or `language` are unknown, or `key` has no message for
+    /// `language`, as [`Translator::translate`] would.
+    ///
+    /// ```
+    /// use binja::Translator;
+    ///
+    /// let mut tr = Translator::new(["en"]);
+    /// tr.add_text("welcome", ["NAME", "PLACE"], [("en", "Welcome to PLACE, NAME!")])?;
+    ///
+    /// let welcome = tr
+    ///     .writer("welcome", "en")?
+    ///     .arg("NAME", "Julian")?
+    ///     .arg("PLACE", "Lisbon")?
+    ///     .finish()?;
+    /// assert_eq!(welcome, "Welcome to Lisbon, Julian!");
+    /// # Ok::<(), binja::Error>(())
+    /// ```
+    pub fn writer<'a>(
+        &'a self,
+        key: &'a str,
+        language: &'a str,
+    ) -> Result<MessageWriter<'a>, Error> {
+        let (translation, ..) = self.lookup(key, language)?;
+
+        Ok(MessageWriter {
+            translator: self,
+            translation,
+            key,
+            language,
+            args: Vec::new(),
+        })
+    }
+}
+
+impl MessageWriter<'_> {
+    /// Gives `value` to the argument `name`.
+    ///
+    /// Fails with [`Error::UnknownArgument`] if `key` declares no such argument, and with
+    /// [`Error::DuplicatedArgument`] if it was already given.
+    pub fn arg(mut self, name: &str, value: impl Into<SmallStr>) -> Result<Self, Error> {
+        if !self.translation.arguments_by_name.contains_key(name) {
+            return Err(Error::UnknownArgument(name.into()));
+        }
+        if self.args.iter().any(|(given, _)| given == name) {
+            return Err(Error::DuplicatedArgument(name.into()));
+        }
+
+        self.args.push((name.into(), value.into()));
+        Ok(self)
+    }
+
+    /// Translates the message with the arguments given so far, see [`Translator::translate`].
+    pub fn finish(self) -> Result<String, Error> {
+        self.translator
+            .translate(self.key, self.language, self.args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    #[test]
+    fn message_writer() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"])
+            .with_delimiters("{", "}")
+            .require_all_arguments(true);
+        tr.add_text(
+            "greetings",
+            ["NAME", "COUNT:number", "TITLE?"],
+            [
+                ("en", "Hi, {TITLE}{NAME}! {COUNT:number} new"),
+                ("pt", "Olá, {TITLE}{NAME}! {COUNT:number} novas"),
+            ],
+        )?;
+
+        let writer = tr.writer("greetings", "pt")?.arg("NAME", "Julian")?;
+        // Anything else may be rendered in between
+        let count = 1200.to_string();
+        assert_eq!(
+            writer.arg("COUNT", count)?.finish()?,
+            "Olá, Julian! 1.200 novas"
+        );
+
+        assert_eq!(
+            tr.writer("greetings", "en")?
+                .arg("NAME", "Julian")?
+                .finish()
+                .unwrap_err(),
+            Error::MissingArgument("COUNT".into())
+        );
+        assert_eq!(
+            tr.writer("greetings", "en")?.arg("SURNAME", "Doe").err(),
+            Some(Error::UnknownArgument("SURNAME".into()))
+        );
+        assert_eq!(
+            tr.writer("greetings", "en")?
+                .arg("NAME", "Julian")?
+                .arg("NAME", "Kyle")
+                .err(),
+            Some(Error::DuplicatedArgument("NAME".into()))
+        );
+        assert_eq!(
+            tr.writer("farewell", "en").err(),
+            Some(Error::MissingKey("farewell".into()))
+        );
+        assert_eq!(
+            tr.writer("greetings", "cz").err(),
+            Some(Error::UnknownLanguage("cz".into()))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+//! Exchanging translations through XLIFF 1.2 documents.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::BufRead;
+
+use compact_str::CompactString as SmallStr;
+
+use crate::xml::{attribute, decode_entities, write_escaped, Event, Reader};
+use crate::{Error, Translation, Translator};
+
+/// Opens the placeholders of the translators built by [`Translator::load_xliff`].
+const OPEN: &str = "{";
+/// Closes the placeholders of the translators built by [`Translator::load_xliff`].
+const CLOSE: &str = "}";
+
+/// Appends the literal `text` to `message`, escaping the opening delimiter.
+fn push_escaped(message: &mut SmallStr, text: &str) {
+    for (index, part) in text.split(OPEN).enumerate() {
+        if index > 0 {
+            message.push('\\');
+            message.push_str(OPEN);
+        }
+        message.push_str(part);
+    }
+}
+
+/// Which message of a `<trans-unit>` is being read.
+#[derive(Clone, Copy, PartialEq)]
+enum Message {
+    Source,
+    Target,
+}
+
+impl Translator {
+    /// Builds a translator out of the `<trans-unit>`s of an XLIFF 1.2 document.
+    ///
+    /// The `id` of each unit becomes a key, and its `<source>` and `<target>` become its
+    /// messages in the `source-language` and `target-language` of the enclosing `<file>`.
+    /// The translator uses `{` and `}` as [delimiters](Translator::with_delimiters): each
+    /// `<x id="NAME"/>` placeholder becomes the argument `NAME`, written as `{NAME}` into
+    /// the message, and literal `{`s are escaped. `<g>` tags are dropped, keeping their
+    /// contents.
+    ///
+    /// Units without a `<target>` are kept, leaving the key untranslated for that language.
+    pub fn load_xliff<R: BufRead>(mut reader: R) -> Result<Translator, Error> {
+        let mut xml = String::new();
+        reader
+            .read_to_string(&mut xml)
+            .map_err(|err| Error::Io(err.to_string()))?;
+
+        // Key -> language -> message, sorted so that errors are deterministic
+        let mut catalog: BTreeMap<SmallStr, BTreeMap<SmallStr, SmallStr>> = BTreeMap::new();
+        let mut arguments: BTreeMap<SmallStr, BTreeSet<SmallStr>> = BTreeMap::new();
+
+        let mut languages: [Option<SmallStr>; 2] = [None, None];
+        let mut unit: Option<SmallStr> = None;
+        let mut reading: Option<Message> = None;
+        let mut message = SmallStr::default();
+
+        let missing = |attribute: &str, tag: &str| {
+            Error::Parse(format!("<{tag}> has no `{attribute}` attribute"))
+        };
+
+        let mut reader = Reader::new(&xml);
+        while let Some(event) = reader.next_event()? {
+            match event {
+                Event::Start {
+                    name: "file",
+                    attributes,
+                    ..
+                } => {
+                    let source = attribute(attributes, "source-language")
+                        .ok_or_else(|| missing("source-language", "file"))?;
+                    languages = [
+                        Some(source.into()),
+                        attribute(attributes, "target-language").map(Into::into),
+                    ];
+                }
+                Event::Start {
+                    name: "trans-unit",
+                    attributes,
+                    ..
+                } => {
+                    let id =
+                        attribute(attributes, "id").ok_or_else(|| missing("id", "trans-unit"))?;
+                    unit = Some(decode_entities(id)?);
+                }
+                Event::Start {
+                    name: name @ ("source" | "target"),
+                    is_empty,
+                    ..
+                } if unit.is_some() => {
+                    message.clear();
+                    reading = Some(match name {
+                        "source" => Message::Source,
+                        _ => Message::Target,
+                    });
+                    if is_empty {
+                        reading = None;
+                    }
+                }
+                Event::Start {
+                    name: "x",
+                    attributes,
+                    ..
+                } if reading.is_some() => {
+                    let id = attribute(attributes, "id").ok_or_else(|| missing("id", "x"))?;
+                    let id = decode_entities(id)?;
+                    message.push_str(OPEN);
+                    message.push_str(&id);
+                    message.push_str(CLOSE);
+
+                    let key = unit.clone().unwrap_or_default();
+                    arguments.entry(key).or_default().insert(id);
+                }
+                Event::Text(text) if reading.is_some() => {
+                    push_escaped(&mut message, &decode_entities(text)?)
+                }
+                Event::Data(data) if reading.is_some() => push_escaped(&mut message, data),
+                Event::End(name @ ("source" | "target")) if reading.is_some() => {
+                    let index = match name {
+                        "source" => 0,
+                        _ => 1,
+                    };
+                    let language = languages[index]
+                        .clone()
+                        .ok_or_else(|| missing("target-language", "file"))?;
+                    let key = unit.clone().unwrap_or_default();
+
+                    let messages = catalog.entry(key).or_default();
+                    match messages.get(&language) {
+                        Some(previous) if *previous != message => {
+                            return Err(Error::DuplicatedLanguage(language));
+                        }
+                        _ => {
+                            messages.insert(language, std::mem::take(&mut message));
+                        }
+                    }
+                    reading = None;
+                }
+                Event::End("trans-unit") => unit = None,
+                _ => {}
+            }
+        }
+
+        let mut all_languages: Vec<&SmallStr> = catalog.values().flat_map(BTreeMap::keys).collect();
+        all_languages.sort();
+        all_languages.dedup();
+
+        let mut translator =
+            Translator::new(all_languages.into_iter().cloned()).with_delimiters(OPEN, CLOSE);
+        for (key, messages) in catalog {
+            let arguments = arguments.remove(&key).unwrap_or_default();
+            translator.add_text_partial(key, arguments, messages)?;
+        }
+
+        Ok(translator)
+    }
+
+    /// Serializes every key with a message in `source_language` into an XLIFF 1.2 document,
+    /// along with its message in `target_language`, if any.
+    ///
+    /// Keys are written in lexicographic order, or by the [collation](Translator::with_collation)
+    /// if one is set, and arguments as `<x id="NAME"/>`
+    /// placeholders, which [`Translator::load_xliff`] reads back.
+    pub fn to_xliff(&self, source_language: &str, target_language: &str) -> Result<String, Error> {
+        let source_id = self.find_language(source_language)?;
+        let target_id = self.find_language(target_language)?;
+
+        let mut keys: Vec<_> = self.translations.iter().collect();
+        self.sort_keys(&mut keys);
+
+        let mut xliff = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n  \
+             <file original=\"binja\" datatype=\"plaintext\" source-language=\"",
+        );
+        write_escaped(&mut xliff, &self.languages[source_id]);
+        xliff.push_str("\" target-language=\"");
+        write_escaped(&mut xliff, &self.languages[target_id]);
+        xliff.push_str("\">\n    <body>\n");
+
+        for (key, translation) in keys {
+            let Some(source) = translation.translations.get(&source_id) else {
+                continue;
+            };
+
+            xliff.push_str("      <trans-unit id=\"");
+            write_escaped(&mut xliff, key);
+            xliff.push_str("\">\n        <source>");
+            self.write_xliff_message(&mut xliff, translation, source)?;
+            xliff.push_str("</source>\n");
+
+            if let Some(target) = translation.translations.get(&target_id) {
+                xliff.push_str("        <target>");
+                self.write_xliff_message(&mut xliff, translation, target)?;
+                xliff.push_str("</target>\n");
+            }
+            xliff.push_str("      </trans-unit>\n");
+        }
+
+        xliff.push_str("    </body>\n  </file>\n</xliff>\n");
+
+        Ok(xliff)
+    }
+
+    /// Writes `message`, one of the messages of `translation`, turning its arguments
+    /// into `<x/>` placeholders.
+    fn write_xliff_message(
+        &self,
+        xliff: &mut String,
+        translation: &Translation,
+        message: &str,
+    ) -> Result<(), Error> {
+        let arguments = translation.arguments.resolve(&self.argument_table);
+
+        let mut last_end = 0;
+        for found in translation.automaton.try_find_iter(message)? {
+            write_escaped(xliff, &message[last_end..found.start()]);
+
+            match translation.argument_of(found.pattern().as_usize()) {
+                Some((index, None)) => {
+                    xliff.push_str("<x id=\"");
+                    write_escaped(xliff, arguments.name(index));
+                    xliff.push_str("\"/>");
+                }
+                // The escaped opening delimiter, which is kept without its escape
+                None => write_escaped(xliff, &message[found.start() + 1..found.end()]),
+                // Cased placeholders, kept as-is
+                Some(_) => write_escaped(xliff, &message[found.start()..found.end()]),
+            }
+            last_end = found.end();
+        }
+        write_escaped(xliff, &message[last_end..]);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Translator};
+
+    const XLIFF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="binja" datatype="plaintext" source-language="en" target-language="pt">
+    <body>
+      <trans-unit id="greetings">
+        <source>Good morning, <x id="NAME"/> &amp; <g id="1">welcome</g>!</source>
+        <target>Bom dia, <x id="NAME"/> &amp; <g id="1">bem-vindo</g>!</target>
+      </trans-unit>
+      <trans-unit id="new">
+        <source>Not yet translated</source>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>
+"#;
+
+    #[test]
+    fn load_xliff() -> Result<(), Error> {
+        let tr = Translator::load_xliff(XLIFF.as_bytes())?;
+
+        assert_eq!(tr.languages().collect::<Vec<_>>(), ["en", "pt"]);
+        assert_eq!(
+            tr.translate("greetings", "pt", [("NAME", "Julian")])?,
+            "Bom dia, Julian & bem-vindo!"
+        );
+        assert_eq!(tr.missing_languages("new")?, ["pt"]);
+
+        assert!(matches!(
+            Translator::load_xliff("<file><trans-unit id=\"a\">".as_bytes()),
+            Err(Error::Parse(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn xliff_round_trip() -> Result<(), Error> {
+        let mut tr = Translator::new(["en", "pt"]);
+        tr.add_text(
+            "greetings",
+            ["NAME"],
+            [("en", "Good morning, NAME <3"), ("pt", "Bom dia, NAME <3")],
+        )?;
+
+        let xliff = tr.to_xliff("en", "pt")?;
+        assert_eq!(
+            xliff,
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="binja" datatype="plaintext" source-language="en" target-language="pt">
+    <body>
+      <trans-unit id="greetings">
+        <source>Good morning, <x id="NAME"/> &lt;3</source>
+        <target>Bom dia, <x id="NAME"/> &lt;3</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>
+"#
+        );
+
+        let loaded = Translator::load_xliff(xliff.as_bytes())?;
+        assert_eq!(loaded.to_xliff("en", "pt")?, xliff);
+        assert_eq!(
+            loaded.translate("greetings", "en", [("NAME", "Julian")])?,
+            "Good morning, Julian <3"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn xliff_placeholders() -> Result<(), Error> {
+        let xliff = r#"<?xml version="1.0" encoding="UTF-8"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file original="binja" datatype="plaintext" source-language="en" target-language="pt">
+    <body>
+      <trans-unit id="pages">
+        <source>Page 1 of <x id="1"/>, not {1}</source>
+        <target>Página 1 de <x id="1"/>, não {1}</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>
+"#;
+
+        let tr = Translator::load_xliff(xliff.as_bytes())?;
+        assert_eq!(
+            tr.translate("pages", "pt", [("1", "7")])?,
+            "Página 1 de 7, não {1}"
+        );
+        assert_eq!(tr.to_xliff("en", "pt")?, xliff);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+//! Just enough XML to read and write the catalogs of Android and XLIFF.
+
+use compact_str::CompactString as SmallStr;
+
+use crate::Error;
+
+/// Finds the value of the `name="value"` attribute among `attributes`.
+pub(crate) fn attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    attributes.match_indices(name).find_map(|(start, _)| {
+        let preceded_by_space = attributes[..start].ends_with(char::is_whitespace);
+        let value = attributes[start + name.len()..]
+            .trim_start()
+            .strip_prefix('=')?;
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|&ch| ch == '"' || ch == '\'')?;
+        let value = &value[1..];
+
+        preceded_by_space.then(|| &value[..value.find(quote).unwrap_or(value.len())])
+    })
+}
+
+/// Resolves the XML entities of `text`.
+pub(crate) fn decode_entities(text: &str) -> Result<SmallStr, Error> {
+    let mut decoded = SmallStr::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find(';')
+            .ok_or_else(|| Error::Parse(format!("unterminated entity in `{text}`")))?;
+        let entity = &rest[start + 1..start + end];
+
+        let ch = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32)
+                .ok_or_else(|| Error::Parse(format!("unknown entity `&{entity};`")))?,
+        };
+        decoded.push(ch);
+        rest = &rest[start + end + 1..];
+    }
+    decoded.push_str(rest);
+
+    Ok(decoded)
+}
+
+/// Appends `text` to `output`, escaping the characters XML reserves.
+#[cfg(feature = "xliff")]
+pub(crate) fn write_escaped(output: &mut String, text: &str) {
+    for ch in text.chars() {
+        match ch {
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '&' => output.push_str("&amp;"),
+            '"' => output.push_str("&quot;"),
+            ch => output.push(ch),
+        }
+    }
+}
+
+/// A piece of an XML document, see [`Reader`].
+#[cfg(feature = "xliff")]
+#[derive(Debug, PartialEq)]
+pub(crate) enum Event<'a> {
+    /// An opening tag, along with its unparsed attributes and whether it closes itself.
+    Start {
+        name: &'a str,
+        attributes: &'a str,
+        is_empty: bool,
+    },
+    End(&'a str),
+    /// Text between tags, with its entities still unresolved.
+    Text(&'a str),
+    /// The contents of a `<![CDATA[...]]>` section.
+    Data(&'a str),
+}
+
+/// Reads an XML document one [`Event`] at a time, skipping comments, declarations
+/// and processing instructions.
+#[cfg(feature = "xliff")]
+pub(crate) struct Reader<'a> {
+    rest: &'a str,
+}
+
+#[cfg(feature = "xliff")]
+impl<'a> Reader<'a> {
+    pub(crate) fn new(xml: &'a str) -> Self {
+        Self { rest: xml }
+    }
+
+    /// Skips past `terminator`, failing with `error` if it never shows up.
+    fn skip_past(&mut self, terminator: &str, error: &str) -> Result<&'a str, Error> {
+        let end = self
+            .rest
+            .find(terminator)
+            .ok_or_else(|| Error::Parse(error.into()))?;
+        let skipped = &self.rest[..end];
+        self.rest = &self.rest[end + terminator.len()..];
+
+        Ok(skipped)
+    }
+
+    pub(crate) fn next_event(&mut self) -> Result<Option<Event<'a>>, Error> {
+        loop {
+            if self.rest.is_empty() {
+                return Ok(None);
+            }
+
+            if !self.rest.starts_with('<') {
+                let end = self.rest.find('<').unwrap_or(self.rest.len());
+                let text = &self.rest[..end];
+                self.rest = &self.rest[end..];
+                return Ok(Some(Event::Text(text)));
+            }
+
+            if let Some(rest) = self.rest.strip_prefix("<!--") {
+                self.rest = rest;
+                self.skip_past("-->", "unterminated comment")?;
+            } else if let Some(rest) = self.rest.strip_prefix("<![CDATA[") {
+                self.rest = rest;
+                return Ok(Some(Event::Data(
+                    self.skip_past("]]>", "unterminated CDATA")?,
+                )));
+            } else if self.rest.starts_with("<?") || self.rest.starts_with("<!") {
+                self.skip_past(">", "unterminated declaration")?;
+            } else {
+                self.rest = &self.rest[1..];
+                let tag = self.skip_past(">", "unterminated tag")?;
+
+                if let Some(name) = tag.strip_prefix('/') {
+                    return Ok(Some(Event::End(name.trim())));
+                }
+
+                let (tag, is_empty) = match tag.strip_suffix('/') {
+                    Some(tag) => (tag, true),
+                    None => (tag, false),
+                };
+                let name_end = tag.find(char::is_whitespace).unwrap_or(tag.len());
+
+                return Ok(Some(Event::Start {
+                    name: &tag[..name_end],
+                    attributes: &tag[name_end..],
+                    is_empty,
+                }));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::attribute;
+    #[cfg(feature = "xliff")]
+    use super::{Event, Reader};
+
+    #[test]
+    fn finds_attributes() {
+        assert_eq!(attribute(r#" x="1" yx='2'"#, "x"), Some("1"));
+        assert_eq!(attribute(r#" yx='2'"#, "x"), None);
+    }
+
+    #[cfg(feature = "xliff")]
+    #[test]
+    fn reads_events() {
+        let mut reader = Reader::new(
+            r#"<?xml version="1.0"?><!-- hi --><a x="1">t &amp; <b/><![CDATA[<c>]]></a>"#,
+        );
+
+        let mut events = Vec::new();
+        while let Some(event) = reader.next_event().unwrap() {
+            events.push(event);
+        }
+
+        assert_eq!(
+            events,
+            [
+                Event::Start {
+                    name: "a",
+                    attributes: r#" x="1""#,
+                    is_empty: false
+                },
+                Event::Text("t &amp; "),
+                Event::Start {
+                    name: "b",
+                    attributes: "",
+                    is_empty: true
+                },
+                Event::Data("<c>"),
+                Event::End("a"),
+            ]
+        );
+    }
+}